@@ -1,15 +1,61 @@
 use crate::{
-    commands::messages::Message,
-    controllers::file::file_picker::{
-        save_folder_location, save_toml_file_location, select_file_to_load,
-        select_json_file_to_load, select_toml_file_to_load, select_toml_files_to_load,
+    commands::{messages::Message, tab_identifiers::TabIdentifier},
+    controllers::file::{
+        file_io::{
+            convert_profile_file_to_folder, convert_profile_folder_to_file, get_recovery_file_path,
+        },
+        file_picker::{
+            save_database_file_location, save_folder_location, save_telemetry_replay_file_location,
+            save_text_file_location, save_toml_file_location, select_csv_file_to_load,
+            select_database_file_to_load, select_file_to_load, select_folder_to_load,
+            select_gps_file_to_load, select_json_file_to_load,
+            select_telemetry_replay_file_to_load, select_toml_file_to_load,
+            select_toml_files_to_load,
+        },
+        recent_profiles::is_database_file,
     },
-    models::application::karting_time::KartingTime,
+    models::application::{
+        karting_time::KartingTime, settings::FileFolder, time_format::TimeFormat,
+    },
+    views::application::input_parser::parse_input_u32,
 };
 use iced::Task;
 
 impl KartingTime {
     pub fn update(&mut self, message: Message) -> Task<Message> {
+        let is_settings_change = message.is_settings_change();
+
+        if let Some((file_folder, file_path)) = message.picked_path() {
+            self.remember_folder(file_folder, file_path);
+        }
+
+        let task = self.handle_profile_edit(message);
+
+        if is_settings_change {
+            self.save_settings();
+        }
+
+        task
+    }
+
+    fn handle_profile_edit(&mut self, message: Message) -> Task<Message> {
+        if !message.is_profile_edit() {
+            return self.handle_message(message);
+        }
+
+        let typed_edit = message.typed_edit();
+        let snapshot = match typed_edit {
+            Some(_) => self.typed_edit_snapshot(),
+            None => self.profile_snapshot(),
+        };
+
+        let task = self.handle_message(message);
+        self.record_profile_edit(snapshot, typed_edit);
+
+        task
+    }
+
+    fn handle_message(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::MenuBar => Task::none(),
             Message::SelectedTabChanged(tab_identifier) => {
@@ -17,105 +63,645 @@ impl KartingTime {
                 Task::none()
             }
             Message::FileNew => {
+                if self
+                    .application_state
+                    .unsaved_changes
+                    .hold_if_unsaved(Message::FileNew)
+                {
+                    return Task::none();
+                }
+
                 self.file_new();
                 Task::none()
             }
+            Message::CloseRequested => {
+                if self
+                    .application_state
+                    .unsaved_changes
+                    .hold_if_unsaved(Message::CloseRequested)
+                {
+                    return Task::none();
+                }
+
+                self.clear_unsaved_changes();
+                self.save_settings();
+                iced::exit()
+            }
+            Message::AutosaveTick => {
+                if let Some(recovery_file_path) = get_recovery_file_path() {
+                    let result = self.autosave(&recovery_file_path);
+                    self.report_file_result(result);
+                }
+                Task::none()
+            }
+            Message::DiscardChangesPressed => {
+                let pending_message = self.application_state.unsaved_changes.discard_changes();
+                self.clear_unsaved_changes();
+
+                match pending_message {
+                    Some(pending_message) => self.update(pending_message),
+                    None => Task::none(),
+                }
+            }
+            Message::KeepChangesPressed => {
+                self.application_state.unsaved_changes.keep_changes();
+                Task::none()
+            }
+            Message::RestoreRecoveryPressed => {
+                if let Some(recovery_file_path) = self
+                    .application_state
+                    .unsaved_changes
+                    .recovery_file_path
+                    .clone()
+                {
+                    let result = self.restore_recovery_file(&recovery_file_path);
+
+                    if self.report_file_result(result) {
+                        self.driver_profile.sort_races();
+                        self.update_race_filtering();
+                    }
+                }
+                Task::none()
+            }
+            Message::DiscardRecoveryPressed => {
+                if let Some(recovery_file_path) = self
+                    .application_state
+                    .unsaved_changes
+                    .recovery_file_path
+                    .clone()
+                {
+                    self.discard_recovery_file(&recovery_file_path);
+                }
+                Task::none()
+            }
+            Message::Undo => {
+                self.undo();
+                Task::none()
+            }
+            Message::Redo => {
+                self.redo();
+                Task::none()
+            }
             Message::SaveApplicationRequested => {
-                save_toml_file_location().map(Message::SaveApplicationCompleted)
+                save_toml_file_location(self.file_folder(FileFolder::Profiles))
+                    .map(Message::SaveApplicationCompleted)
             }
             Message::SaveApplicationCompleted(file_path) => {
                 if let Some(file_path) = file_path {
-                    self.save_application(&file_path);
+                    let result = self.save_application(&file_path);
+
+                    if self.report_file_result(result) {
+                        self.clear_unsaved_changes();
+                        self.add_recent_profile(&file_path);
+                    }
                 }
                 Task::none()
             }
             Message::LoadApplicationRequested => {
-                select_toml_file_to_load().map(Message::LoadApplicationCompleted)
+                if self
+                    .application_state
+                    .unsaved_changes
+                    .hold_if_unsaved(Message::LoadApplicationRequested)
+                {
+                    return Task::none();
+                }
+
+                select_toml_file_to_load(self.file_folder(FileFolder::Profiles))
+                    .map(Message::LoadApplicationCompleted)
             }
             Message::LoadApplicationCompleted(file_path) => {
                 if let Some(file_path) = file_path {
-                    self.load_application(&file_path);
-                    self.driver_profile.sort_races();
-                    self.driver_profile.update_filtering();
-                    self.driver_profile.filter.update_pagination();
+                    let result = self.load_application(&file_path);
+
+                    if self.report_file_result(result) {
+                        self.add_recent_profile(&file_path);
+                        self.driver_profile.sort_races();
+                        self.update_race_filtering();
+                    }
+                }
+                Task::none()
+            }
+            Message::OpenRecentProfilePressed(profile_path) => {
+                if self
+                    .application_state
+                    .unsaved_changes
+                    .hold_if_unsaved(Message::OpenRecentProfilePressed(profile_path.clone()))
+                {
+                    return Task::none();
+                }
+
+                if !self.find_recent_profile(&profile_path) {
+                    return Task::none();
+                }
+
+                match is_database_file(&profile_path) {
+                    true => self.update(Message::OpenDatabaseCompleted(Some(profile_path))),
+                    false => self.update(Message::LoadApplicationCompleted(Some(profile_path))),
+                }
+            }
+            Message::ClearRecentProfilesPressed => {
+                self.clear_recent_profiles();
+                Task::none()
+            }
+            Message::ReopenLastProfileToggled => {
+                self.toggle_reopen_last_profile();
+                Task::none()
+            }
+            Message::RestoreBackupRequested => {
+                if self
+                    .application_state
+                    .unsaved_changes
+                    .hold_if_unsaved(Message::RestoreBackupRequested)
+                {
+                    return Task::none();
+                }
+
+                select_toml_file_to_load(self.backup_folder()).map(Message::RestoreBackupCompleted)
+            }
+            Message::RestoreBackupCompleted(file_path) => {
+                if let Some(file_path) = file_path {
+                    let result = self.restore_backup_file(&file_path);
+
+                    if self.report_file_result(result) {
+                        self.driver_profile.sort_races();
+                        self.update_race_filtering();
+                    }
+                }
+                Task::none()
+            }
+            Message::SaveProfileFolderRequested => {
+                save_folder_location(self.file_folder(FileFolder::Profiles))
+                    .map(Message::SaveProfileFolderCompleted)
+            }
+            Message::SaveProfileFolderCompleted(folder_path) => {
+                if let Some(folder_path) = folder_path {
+                    let result = self.save_profile_folder(&folder_path);
+
+                    if self.report_file_result(result) {
+                        self.clear_unsaved_changes();
+                        self.add_recent_profile(&folder_path);
+                    }
+                }
+                Task::none()
+            }
+            Message::LoadProfileFolderRequested => {
+                if self
+                    .application_state
+                    .unsaved_changes
+                    .hold_if_unsaved(Message::LoadProfileFolderRequested)
+                {
+                    return Task::none();
+                }
+
+                select_folder_to_load(self.file_folder(FileFolder::Profiles))
+                    .map(Message::LoadApplicationCompleted)
+            }
+            Message::MergeProfileFolderRequested => {
+                select_folder_to_load(self.file_folder(FileFolder::Profiles))
+                    .map(Message::MergeProfileCompleted)
+            }
+            Message::ConvertProfileToFolderRequested => {
+                select_toml_file_to_load(self.file_folder(FileFolder::Profiles))
+                    .map(Message::ConvertProfileToFolderFileSelected)
+            }
+            Message::ConvertProfileToFolderFileSelected(file_path) => match file_path {
+                Some(file_path) => save_folder_location(self.file_folder(FileFolder::Profiles))
+                    .map(move |folder_path| {
+                        Message::ConvertProfileToFolderCompleted(file_path.clone(), folder_path)
+                    }),
+                None => Task::none(),
+            },
+            Message::ConvertProfileToFolderCompleted(file_path, folder_path) => {
+                if let Some(folder_path) = folder_path {
+                    let result = convert_profile_file_to_folder(&file_path, &folder_path);
+                    self.report_file_result(result);
+                }
+                Task::none()
+            }
+            Message::ConvertFolderToProfileRequested => {
+                select_folder_to_load(self.file_folder(FileFolder::Profiles))
+                    .map(Message::ConvertFolderToProfileFolderSelected)
+            }
+            Message::ConvertFolderToProfileFolderSelected(folder_path) => match folder_path {
+                Some(folder_path) => save_toml_file_location(
+                    self.file_folder(FileFolder::Profiles),
+                )
+                .map(move |file_path| {
+                    Message::ConvertFolderToProfileCompleted(folder_path.clone(), file_path)
+                }),
+                None => Task::none(),
+            },
+            Message::ConvertFolderToProfileCompleted(folder_path, file_path) => {
+                if let Some(file_path) = file_path {
+                    let result = convert_profile_folder_to_file(&folder_path, &file_path);
+                    self.report_file_result(result);
+                }
+                Task::none()
+            }
+            Message::SaveDatabaseRequested => {
+                save_database_file_location(self.file_folder(FileFolder::Profiles))
+                    .map(Message::SaveDatabaseCompleted)
+            }
+            Message::SaveDatabaseCompleted(file_path) => {
+                if let Some(file_path) = file_path {
+                    let result = self.save_database(&file_path);
+
+                    if self.report_file_result(result) {
+                        self.clear_unsaved_changes();
+                        self.add_recent_profile(&file_path);
+                    }
+                }
+                Task::none()
+            }
+            Message::OpenDatabaseRequested => {
+                if self
+                    .application_state
+                    .unsaved_changes
+                    .hold_if_unsaved(Message::OpenDatabaseRequested)
+                {
+                    return Task::none();
+                }
+
+                select_database_file_to_load(self.file_folder(FileFolder::Profiles))
+                    .map(Message::OpenDatabaseCompleted)
+            }
+            Message::OpenDatabaseCompleted(file_path) => {
+                if let Some(file_path) = file_path {
+                    let result = self.open_database(&file_path);
+
+                    if self.report_file_result(result) {
+                        self.add_recent_profile(&file_path);
+                        self.update_race_filtering();
+                    }
+                }
+                Task::none()
+            }
+            Message::MergeProfileRequested => {
+                select_toml_file_to_load(self.file_folder(FileFolder::Profiles))
+                    .map(Message::MergeProfileCompleted)
+            }
+            Message::MergeProfileCompleted(file_path) => {
+                if let Some(file_path) = file_path {
+                    let result = self.open_profile_merge(&file_path);
+
+                    if self.report_file_result(result) {
+                        self.switch_tab(TabIdentifier::Race);
+                    }
                 }
                 Task::none()
             }
             Message::ImportRacesRequested => {
-                select_toml_files_to_load().map(Message::ImportRacesCompleted)
+                select_toml_files_to_load(self.file_folder(FileFolder::Imports))
+                    .map(Message::ImportRacesCompleted)
             }
             Message::ImportRacesCompleted(file_paths) => {
                 if let Some(file_paths) = file_paths {
                     self.import_races(file_paths);
                     self.driver_profile.sort_races();
-                    self.driver_profile.update_filtering();
-                    self.driver_profile.filter.update_pagination();
+                    self.update_races();
                 }
                 Task::none()
             }
             Message::ImportAccLaptimesFileRequested => {
-                select_json_file_to_load().map(Message::ImportAccLaptimesFileCompleted)
+                select_json_file_to_load(self.file_folder(FileFolder::Imports))
+                    .map(Message::ImportAccLaptimesFileCompleted)
             }
             Message::ImportAccLaptimesFileCompleted(file_path) => {
                 if let Some(file_path) = file_path {
                     self.import_acc_laptimes(&file_path);
                     self.driver_profile.sort_races();
-                    self.driver_profile.update_filtering();
-                    self.driver_profile.filter.update_pagination();
+                    self.update_races();
+                }
+                Task::none()
+            }
+            Message::ImportTimingSystemFileRequested => {
+                select_csv_file_to_load(self.file_folder(FileFolder::Imports))
+                    .map(Message::ImportTimingSystemFileCompleted)
+            }
+            Message::ImportTimingSystemFileCompleted(file_path) => {
+                if let Some(file_path) = file_path {
+                    self.import_timing_system_laptimes(&file_path);
+                    self.driver_profile.sort_races();
+                    self.update_races();
+                }
+                Task::none()
+            }
+            Message::ImportDataLoggerFileRequested => {
+                select_csv_file_to_load(self.file_folder(FileFolder::Imports))
+                    .map(Message::ImportDataLoggerFileCompleted)
+            }
+            Message::ImportDataLoggerFileCompleted(file_path) => {
+                if let Some(file_path) = file_path {
+                    self.import_data_logger_laptimes(&file_path);
+                    self.driver_profile.sort_races();
+                    self.update_races();
+                }
+                Task::none()
+            }
+            Message::ImportGpsFileRequested => {
+                select_gps_file_to_load(self.file_folder(FileFolder::Imports))
+                    .map(Message::ImportGpsFileCompleted)
+            }
+            Message::ImportGpsFileCompleted(file_path) => {
+                if let Some(file_path) = file_path {
+                    self.import_gps_laptimes(&file_path);
+                    self.driver_profile.sort_races();
+                    self.update_races();
+                }
+                Task::none()
+            }
+            Message::ImportCsvFileRequested => {
+                select_csv_file_to_load(self.file_folder(FileFolder::Imports))
+                    .map(Message::ImportCsvFileCompleted)
+            }
+            Message::ImportCsvFileCompleted(file_path) => {
+                if let Some(file_path) = file_path {
+                    let result = self.open_csv_import(&file_path);
+
+                    if self.report_file_result(result) {
+                        self.switch_tab(TabIdentifier::Race);
+                    }
                 }
                 Task::none()
             }
             Message::ImportLaptimesFileRequested => {
-                select_file_to_load().map(Message::ImportLaptimesFileCompleted)
+                select_file_to_load(self.file_folder(FileFolder::Imports))
+                    .map(Message::ImportLaptimesFileCompleted)
             }
             Message::ImportLaptimesFileCompleted(file_path) => {
                 if let Some(file_path) = file_path {
                     self.import_laptimes(&file_path);
                     self.driver_profile.sort_races();
-                    self.driver_profile.update_filtering();
-                    self.driver_profile.filter.update_pagination();
+                    self.update_races();
+                }
+                Task::none()
+            }
+            Message::ImportTimingSheetFileRequested => {
+                select_file_to_load(self.file_folder(FileFolder::Imports))
+                    .map(Message::ImportTimingSheetFileCompleted)
+            }
+            Message::ImportTimingSheetFileCompleted(file_path) => {
+                if let Some(file_path) = file_path {
+                    let result = self.open_timing_sheet(&file_path);
+
+                    if self.report_file_result(result) {
+                        self.switch_tab(TabIdentifier::Race);
+                    }
+                }
+                Task::none()
+            }
+            Message::AddWatchedFolderRequested => {
+                save_folder_location(self.file_folder(FileFolder::Imports))
+                    .map(Message::AddWatchedFolderCompleted)
+            }
+            Message::AddWatchedFolderCompleted(folder_location) => {
+                if let Some(folder_location) = folder_location {
+                    self.application_state
+                        .watch_folders
+                        .add_folder(&folder_location);
+                    self.import_watched_folders();
+                    self.driver_profile.sort_races();
+                    self.update_races();
+                }
+                Task::none()
+            }
+            Message::RemoveWatchedFolderPressed(folder_location) => {
+                self.application_state
+                    .watch_folders
+                    .remove_folder(&folder_location);
+                self.application_state.unsaved_changes.mark_changed();
+                Task::none()
+            }
+            Message::WatchedFoldersPolled => {
+                let changed_files = self.application_state.watch_folders.find_changed_files();
+
+                // Polls run every few seconds so the profile is only snapshotted for undo when
+                // there are files to import
+                if changed_files.is_empty() {
+                    return Task::none();
+                }
+
+                let snapshot = self.profile_snapshot();
+                let race_count = self.driver_profile.races.len();
+                self.import_watched_files(changed_files);
+
+                if self.driver_profile.races.len() != race_count {
+                    self.driver_profile.sort_races();
+                    self.update_races();
+                }
+
+                self.record_profile_edit(snapshot, None);
+                Task::none()
+            }
+            Message::TelemetryListenFailed(reason) => {
+                self.fail_telemetry_listening(&reason);
+                Task::none()
+            }
+            Message::ClearWatchedFolderAnnouncementsPressed => {
+                self.application_state.watch_folders.announcements.clear();
+                Task::none()
+            }
+            Message::ReplayTelemetryRequested => {
+                select_telemetry_replay_file_to_load(self.file_folder(FileFolder::Imports))
+                    .map(Message::ReplayTelemetryCompleted)
+            }
+            Message::ReplayTelemetryCompleted(file_path) => {
+                if let Some(file_path) = file_path {
+                    self.replay_telemetry(&file_path);
+                    self.driver_profile.sort_races();
+                    self.update_races();
+                }
+                Task::none()
+            }
+            Message::SaveTelemetryReplayRequested => {
+                save_telemetry_replay_file_location(self.file_folder(FileFolder::Exports))
+                    .map(Message::SaveTelemetryReplayCompleted)
+            }
+            Message::SaveTelemetryReplayCompleted(file_path) => {
+                if let Some(file_path) = file_path {
+                    let result = self.save_telemetry_replay(&file_path);
+                    self.report_file_result(result);
                 }
                 Task::none()
             }
             Message::ExportRacesRequested => {
-                save_folder_location().map(Message::ExportRacesCompleted)
+                save_folder_location(self.file_folder(FileFolder::Exports))
+                    .map(Message::ExportRacesCompleted)
             }
             Message::ExportRacesCompleted(folder_location) => {
                 if let Some(folder_location) = folder_location {
-                    self.export_races(&folder_location)
+                    let result = self.export_races(&folder_location);
+                    self.report_file_result(result);
                 }
                 Task::none()
             }
             Message::ExportHtmlRacesRequested => {
-                save_folder_location().map(Message::ExportHtmlRacesCompleted)
+                save_folder_location(self.file_folder(FileFolder::Exports))
+                    .map(Message::ExportHtmlRacesCompleted)
             }
             Message::ExportHtmlRacesCompleted(folder_location) => {
                 if let Some(folder_location) = folder_location {
-                    self.export_html_races(&folder_location)
+                    let result = self.export_html_races(&folder_location);
+                    self.report_file_result(result);
                 }
                 Task::none()
             }
-
+            Message::CloseImportReportPressed => {
+                self.application_state.import_report.close();
+                Task::none()
+            }
+            Message::SaveImportReportRequested => {
+                save_text_file_location(self.file_folder(FileFolder::Exports))
+                    .map(Message::SaveImportReportCompleted)
+            }
+            Message::SaveImportReportCompleted(file_path) => {
+                if let Some(file_path) = file_path {
+                    let result = self.save_import_report(&file_path);
+                    self.report_file_result(result);
+                }
+                Task::none()
+            }
+            Message::RepairDataIssuePressed(index) => {
+                self.repair_data_issue(index);
+                self.update_races();
+                Task::none()
+            }
+            Message::KeepDataIssuePressed(index) => {
+                self.application_state.data_repair.keep(index);
+                Task::none()
+            }
+            Message::RepairAllDataIssuesPressed => {
+                self.repair_all_data_issues();
+                self.update_races();
+                Task::none()
+            }
+            Message::KeepAllDataIssuesPressed => {
+                self.application_state.data_repair.keep_all();
+                Task::none()
+            }
+            Message::DismissFileErrorPressed(index) => {
+                if index < self.application_state.file_errors.len() {
+                    self.application_state.file_errors.remove(index);
+                }
+                Task::none()
+            }
+            Message::ClearFileErrorsPressed => {
+                self.application_state.file_errors.clear();
+                Task::none()
+            }
             Message::ViewToggleTheme => {
                 self.switch_theme();
                 Task::none()
             }
             Message::ViewToggleFilter => {
                 self.toggle_filter();
-                self.driver_profile.update_filtering();
-                self.driver_profile.filter.update_pagination();
+                self.update_race_filtering();
+                Task::none()
+            }
+            Message::WindowResized(window_size) => {
+                self.application_state.settings.window_size = Some(window_size);
+                Task::none()
+            }
+            Message::PageSizeSelected(page_size) => {
+                self.driver_profile.filter.change_page_size(page_size);
+                self.update_race_filtering();
+                Task::none()
+            }
+            Message::TimeStyleSelected(time_style) => {
+                self.change_time_format(TimeFormat {
+                    time_style,
+                    ..self.application_state.time_format
+                });
+                Task::none()
+            }
+            Message::DecimalPlacesSelected(decimal_places) => {
+                self.change_time_format(TimeFormat {
+                    decimal_places,
+                    ..self.application_state.time_format
+                });
                 Task::none()
             }
             Message::PaginationNext => {
-                self.driver_profile.filter.next_page();
+                self.next_page();
                 Task::none()
             }
             Message::PaginationPrevious => {
-                self.driver_profile.filter.previous_page();
+                self.previous_page();
                 Task::none()
             }
             Message::DriverNameChanged(name) => {
                 self.driver_profile.name = name;
+                self.application_state.unsaved_changes.mark_changed();
+                Task::none()
+            }
+            Message::BucketSizeChanged(bucket_size) => {
+                self.driver_profile
+                    .statistics_settings
+                    .statistics_windows
+                    .set_bucket_size(bucket_size);
+                self.update_races();
+                Task::none()
+            }
+            Message::AverageCutoffChanged(average_cutoff_percentage) => {
+                self.driver_profile
+                    .statistics_settings
+                    .statistics_windows
+                    .set_average_cutoff_percentage(average_cutoff_percentage);
+                self.update_races();
+                Task::none()
+            }
+            Message::SessionTypeWindowsAdded => {
+                self.driver_profile
+                    .statistics_settings
+                    .add_session_type_windows();
+                self.update_races();
+                Task::none()
+            }
+            Message::SessionTypeWindowsRemoved(index) => {
+                self.driver_profile
+                    .statistics_settings
+                    .remove_session_type_windows(index);
+                self.update_races();
+                Task::none()
+            }
+            Message::SessionTypeWindowsSessionTypeChanged(index, session_type) => {
+                if let Some(session_type_windows) = self
+                    .driver_profile
+                    .statistics_settings
+                    .session_type_windows
+                    .get_mut(index)
+                {
+                    session_type_windows.session_type = session_type;
+                }
+                self.update_races();
+                Task::none()
+            }
+            Message::SessionTypeWindowsBucketSizeChanged(index, bucket_size) => {
+                if let Some(session_type_windows) = self
+                    .driver_profile
+                    .statistics_settings
+                    .session_type_windows
+                    .get_mut(index)
+                {
+                    session_type_windows
+                        .statistics_windows
+                        .set_bucket_size(bucket_size);
+                }
+                self.update_races();
+                Task::none()
+            }
+            Message::SessionTypeWindowsAverageCutoffChanged(index, average_cutoff_percentage) => {
+                if let Some(session_type_windows) = self
+                    .driver_profile
+                    .statistics_settings
+                    .session_type_windows
+                    .get_mut(index)
+                {
+                    session_type_windows
+                        .statistics_windows
+                        .set_average_cutoff_percentage(average_cutoff_percentage);
+                }
+                self.update_races();
                 Task::none()
             }
             Message::TrackNameChanged(track_name) => {
@@ -212,54 +798,157 @@ impl KartingTime {
                     .race_editor
                     .text_editor
                     .perform(action);
+                self.application_state.race_editor.update_timing_warnings();
+                Task::none()
+            }
+            Message::LapEntryModeSelected(lap_entry_mode) => {
+                self.application_state
+                    .race_editor
+                    .select_lap_entry_mode(lap_entry_mode);
                 Task::none()
             }
             Message::TrackFilterChanged(track_query) => {
                 self.driver_profile.filter.track_query = track_query;
 
-                self.driver_profile.update_filtering();
-                self.driver_profile.filter.update_pagination();
+                self.update_race_filtering();
                 Task::none()
             }
             Message::DateFilterChanged(date_query) => {
                 self.driver_profile.filter.date_query = date_query;
 
-                self.driver_profile.update_filtering();
-                self.driver_profile.filter.update_pagination();
+                self.update_race_filtering();
                 Task::none()
             }
             Message::CarUsedFilterChanged(car_used_query) => {
                 self.driver_profile.filter.car_used_query = car_used_query;
 
-                self.driver_profile.update_filtering();
-                self.driver_profile.filter.update_pagination();
+                self.update_race_filtering();
                 Task::none()
             }
             Message::ChampionshipFilterChanged(championship_query) => {
                 self.driver_profile.filter.championship_query = championship_query;
 
-                self.driver_profile.update_filtering();
-                self.driver_profile.filter.update_pagination();
+                self.update_race_filtering();
                 Task::none()
             }
             Message::SessionTypeFilterChanged(session_type_query) => {
                 self.driver_profile.filter.session_type_query = session_type_query;
 
-                self.driver_profile.update_filtering();
-                self.driver_profile.filter.update_pagination();
+                self.update_race_filtering();
+                Task::none()
+            }
+            Message::TelemetryGameSelected(game) => {
+                self.select_telemetry_game(game);
+                self.driver_profile.sort_races();
+                self.update_races();
+                Task::none()
+            }
+            Message::TelemetryPortChanged(port) => {
+                self.application_state.telemetry.port =
+                    parse_input_u32(port, 0, u16::MAX as u32) as u16;
+                Task::none()
+            }
+            Message::TelemetryListenToggled => {
+                self.toggle_telemetry_listening();
+                self.driver_profile.sort_races();
+                self.update_races();
+                Task::none()
+            }
+            Message::TelemetryRecordPacketsToggled(is_recording_packets) => {
+                self.application_state.telemetry.is_recording_packets = is_recording_packets;
+                Task::none()
+            }
+            Message::TelemetryPacketReceived(packet) => {
+                let races = self.receive_telemetry_packet(packet);
+
+                if !races.is_empty() {
+                    let snapshot = self.profile_snapshot();
+                    self.driver_profile.races.extend(races);
+                    self.driver_profile.sort_races();
+                    self.update_races();
+                    self.record_profile_edit(snapshot, None);
+                }
+                Task::none()
+            }
+            Message::CsvSourceNameChanged(source_name) => {
+                self.application_state.csv_import.mapping.source_name = source_name;
+                Task::none()
+            }
+            Message::CsvSavedMappingSelected(source_name) => {
+                self.application_state
+                    .csv_import
+                    .select_saved_mapping(&source_name);
+                Task::none()
+            }
+            Message::CsvDelimiterSelected(delimiter) => {
+                self.application_state.csv_import.mapping.delimiter = delimiter;
+                Task::none()
+            }
+            Message::CsvDecimalSeparatorSelected(decimal_separator) => {
+                self.application_state.csv_import.mapping.decimal_separator = decimal_separator;
+                Task::none()
+            }
+            Message::CsvHeaderRowToggled(has_header_row) => {
+                self.application_state.csv_import.mapping.has_header_row = has_header_row;
+                Task::none()
+            }
+            Message::CsvLapNumberColumnChanged(column) => {
+                self.application_state.csv_import.mapping.lap_number_column =
+                    parse_input_u32(column, 0, u32::MAX);
+                Task::none()
+            }
+            Message::CsvLaptimeColumnChanged(column) => {
+                self.application_state.csv_import.mapping.laptime_column =
+                    parse_input_u32(column, 0, u32::MAX);
+                Task::none()
+            }
+            Message::CsvSectorColumnsChanged(columns) => {
+                self.application_state
+                    .csv_import
+                    .set_sector_columns(columns);
+                Task::none()
+            }
+            Message::CsvValidityColumnChanged(column) => {
+                self.application_state.csv_import.mapping.validity_column =
+                    parse_input_u32(column, 0, u32::MAX);
+                Task::none()
+            }
+            Message::CsvImportPressed => {
+                self.import_csv_laptimes();
+                self.driver_profile.sort_races();
+                self.update_races();
+                Task::none()
+            }
+            Message::CsvImportCancelledPressed => {
+                self.application_state.csv_import.close();
+                Task::none()
+            }
+            Message::MergeChoiceSelected(identifier, choice) => {
+                self.application_state
+                    .profile_merge
+                    .select_choice(&identifier, choice);
+                Task::none()
+            }
+            Message::MergeProfilePressed => {
+                self.merge_profile();
+                self.driver_profile.sort_races();
+                self.update_races();
+                Task::none()
+            }
+            Message::MergeProfileCancelledPressed => {
+                self.application_state.profile_merge.close();
                 Task::none()
             }
             Message::UpdateRacesPressed => {
                 self.driver_profile.new_race.convert_to_laps(
                     self.application_state
                         .race_editor
-                        .get_text_from_text_editor(),
+                        .get_laptimes_from_text_editor(),
                 );
 
                 self.driver_profile.upsert_race();
                 self.driver_profile.sort_races();
-                self.driver_profile.update_filtering();
-                self.driver_profile.filter.update_pagination();
+                self.update_races();
                 Task::none()
             }
             Message::ClearRaceEditorPressed => {
@@ -276,8 +965,7 @@ impl KartingTime {
                     self.driver_profile.new_race = race.clone();
                     self.application_state.race_editor.clear_text_editor();
                     self.application_state.race_editor.paste_laptimes(race);
-                    self.driver_profile.update_filtering();
-                    self.driver_profile.filter.update_pagination();
+                    self.update_race_filtering();
                 }
                 Task::none()
             }
@@ -289,8 +977,7 @@ impl KartingTime {
                     .find(|race| race.race_information.unique_race_identifier == identifier)
                 {
                     race.is_deleting = true;
-                    self.driver_profile.update_filtering();
-                    self.driver_profile.filter.update_pagination();
+                    self.update_race_filtering();
                 }
                 Task::none()
             }
@@ -302,8 +989,7 @@ impl KartingTime {
                     .position(|race| race.race_information.unique_race_identifier == identifier)
                 {
                     self.driver_profile.races.remove(index);
-                    self.driver_profile.update_filtering();
-                    self.driver_profile.filter.update_pagination();
+                    self.update_races();
                 }
                 Task::none()
             }
@@ -315,8 +1001,7 @@ impl KartingTime {
                     .find(|race| race.race_information.unique_race_identifier == identifier)
                 {
                     race.is_deleting = false;
-                    self.driver_profile.update_filtering();
-                    self.driver_profile.filter.update_pagination();
+                    self.update_race_filtering();
                 }
                 Task::none()
             }
@@ -1,30 +1,107 @@
 use super::tab_identifiers::TabIdentifier;
-use iced::widget::text_editor;
+use crate::models::application::{
+    csv_import::{CsvDelimiter, DecimalSeparator},
+    profile_merge::MergeChoice,
+    race_editor::LapEntryMode,
+    settings::FileFolder,
+    telemetry::TelemetryGame,
+    time_format::{DecimalPlaces, TimeStyle},
+    undo_history::TypedEdit,
+};
+use iced::{Size, widget::text_editor};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Message {
     MenuBar,
     FileNew,
+    CloseRequested,
+    AutosaveTick,
+    DiscardChangesPressed,
+    KeepChangesPressed,
+    RestoreRecoveryPressed,
+    DiscardRecoveryPressed,
+    Undo,
+    Redo,
     SaveApplicationRequested,
     SaveApplicationCompleted(Option<String>),
     LoadApplicationRequested,
     LoadApplicationCompleted(Option<String>),
+    OpenRecentProfilePressed(String),
+    ClearRecentProfilesPressed,
+    ReopenLastProfileToggled,
+    RestoreBackupRequested,
+    RestoreBackupCompleted(Option<String>),
+    SaveProfileFolderRequested,
+    SaveProfileFolderCompleted(Option<String>),
+    LoadProfileFolderRequested,
+    MergeProfileFolderRequested,
+    ConvertProfileToFolderRequested,
+    ConvertProfileToFolderFileSelected(Option<String>),
+    ConvertProfileToFolderCompleted(String, Option<String>),
+    ConvertFolderToProfileRequested,
+    ConvertFolderToProfileFolderSelected(Option<String>),
+    ConvertFolderToProfileCompleted(String, Option<String>),
+    SaveDatabaseRequested,
+    SaveDatabaseCompleted(Option<String>),
+    OpenDatabaseRequested,
+    OpenDatabaseCompleted(Option<String>),
+    MergeProfileRequested,
+    MergeProfileCompleted(Option<String>),
     ImportRacesRequested,
     ImportRacesCompleted(Option<Vec<String>>),
     ImportAccLaptimesFileRequested,
     ImportAccLaptimesFileCompleted(Option<String>),
+    ImportTimingSystemFileRequested,
+    ImportTimingSystemFileCompleted(Option<String>),
+    ImportDataLoggerFileRequested,
+    ImportDataLoggerFileCompleted(Option<String>),
+    ImportGpsFileRequested,
+    ImportGpsFileCompleted(Option<String>),
+    ImportCsvFileRequested,
+    ImportCsvFileCompleted(Option<String>),
     ImportLaptimesFileRequested,
     ImportLaptimesFileCompleted(Option<String>),
+    ImportTimingSheetFileRequested,
+    ImportTimingSheetFileCompleted(Option<String>),
+    AddWatchedFolderRequested,
+    AddWatchedFolderCompleted(Option<String>),
+    RemoveWatchedFolderPressed(String),
+    WatchedFoldersPolled,
+    ClearWatchedFolderAnnouncementsPressed,
+    ReplayTelemetryRequested,
+    ReplayTelemetryCompleted(Option<String>),
+    SaveTelemetryReplayRequested,
+    SaveTelemetryReplayCompleted(Option<String>),
     ExportRacesRequested,
     ExportRacesCompleted(Option<String>),
     ExportHtmlRacesRequested,
     ExportHtmlRacesCompleted(Option<String>),
+    CloseImportReportPressed,
+    SaveImportReportRequested,
+    SaveImportReportCompleted(Option<String>),
+    RepairDataIssuePressed(usize),
+    KeepDataIssuePressed(usize),
+    RepairAllDataIssuesPressed,
+    KeepAllDataIssuesPressed,
+    DismissFileErrorPressed(usize),
+    ClearFileErrorsPressed,
     ViewToggleTheme,
     ViewToggleFilter,
+    WindowResized(Size),
+    PageSizeSelected(usize),
+    TimeStyleSelected(TimeStyle),
+    DecimalPlacesSelected(DecimalPlaces),
     SelectedTabChanged(TabIdentifier),
     PaginationPrevious,
     PaginationNext,
     DriverNameChanged(String),
+    BucketSizeChanged(String),
+    AverageCutoffChanged(String),
+    SessionTypeWindowsAdded,
+    SessionTypeWindowsRemoved(usize),
+    SessionTypeWindowsSessionTypeChanged(usize, String),
+    SessionTypeWindowsBucketSizeChanged(usize, String),
+    SessionTypeWindowsAverageCutoffChanged(usize, String),
     TrackNameChanged(String),
     DayChanged(String),
     MonthChanged(String),
@@ -37,6 +114,7 @@ pub enum Message {
     ChampionshipChanged(String),
     NotesChanged(String),
     LaptimeEditor(text_editor::Action),
+    LapEntryModeSelected(LapEntryMode),
     TrackFilterChanged(String),
     DateFilterChanged(String),
     CarUsedFilterChanged(String),
@@ -46,6 +124,128 @@ pub enum Message {
     DeletePressed(String),
     DeleteConfirmedPressed(String),
     DeleteCancelledPressed(String),
+    TelemetryGameSelected(TelemetryGame),
+    TelemetryPortChanged(String),
+    TelemetryListenToggled,
+    TelemetryRecordPacketsToggled(bool),
+    TelemetryPacketReceived(Vec<u8>),
+    TelemetryListenFailed(String),
+    CsvSourceNameChanged(String),
+    CsvSavedMappingSelected(String),
+    CsvDelimiterSelected(CsvDelimiter),
+    CsvDecimalSeparatorSelected(DecimalSeparator),
+    CsvHeaderRowToggled(bool),
+    CsvLapNumberColumnChanged(String),
+    CsvLaptimeColumnChanged(String),
+    CsvSectorColumnsChanged(String),
+    CsvValidityColumnChanged(String),
+    CsvImportPressed,
+    CsvImportCancelledPressed,
+    MergeChoiceSelected(String, MergeChoice),
+    MergeProfilePressed,
+    MergeProfileCancelledPressed,
     UpdateRacesPressed,
     ClearRaceEditorPressed,
 }
+
+impl Message {
+    // Live telemetry packets arrive too often to snapshot so only the ones that finish a race are
+    pub fn is_profile_edit(&self) -> bool {
+        self.typed_edit().is_some()
+            || matches!(
+                self,
+                Message::ImportRacesCompleted(_)
+                    | Message::ImportAccLaptimesFileCompleted(_)
+                    | Message::ImportTimingSystemFileCompleted(_)
+                    | Message::ImportDataLoggerFileCompleted(_)
+                    | Message::ImportGpsFileCompleted(_)
+                    | Message::ImportLaptimesFileCompleted(_)
+                    | Message::AddWatchedFolderCompleted(_)
+                    | Message::ReplayTelemetryCompleted(_)
+                    | Message::TelemetryGameSelected(_)
+                    | Message::TelemetryListenToggled
+                    | Message::CsvImportPressed
+                    | Message::MergeProfilePressed
+                    | Message::UpdateRacesPressed
+                    | Message::DeleteConfirmedPressed(_)
+                    | Message::RepairDataIssuePressed(_)
+                    | Message::RepairAllDataIssuesPressed
+                    | Message::SessionTypeWindowsAdded
+                    | Message::SessionTypeWindowsRemoved(_)
+                    | Message::RestoreBackupCompleted(_)
+                    | Message::RestoreRecoveryPressed
+            )
+    }
+
+    pub fn typed_edit(&self) -> Option<TypedEdit> {
+        match self {
+            Message::DriverNameChanged(_) => Some(TypedEdit::DriverName),
+            Message::BucketSizeChanged(_)
+            | Message::AverageCutoffChanged(_)
+            | Message::SessionTypeWindowsSessionTypeChanged(_, _)
+            | Message::SessionTypeWindowsBucketSizeChanged(_, _)
+            | Message::SessionTypeWindowsAverageCutoffChanged(_, _) => {
+                Some(TypedEdit::StatisticsSettings)
+            }
+            _ => None,
+        }
+    }
+
+    // Messages that can change what's kept in the settings file, resizing sends a message every
+    // frame so the window size is written with the next settings change or on close
+    pub fn is_settings_change(&self) -> bool {
+        self.picked_path().is_some()
+            || matches!(
+                self,
+                Message::FileNew
+                    | Message::OpenRecentProfilePressed(_)
+                    | Message::ClearRecentProfilesPressed
+                    | Message::ReopenLastProfileToggled
+                    | Message::ViewToggleTheme
+                    | Message::PageSizeSelected(_)
+                    | Message::TimeStyleSelected(_)
+                    | Message::DecimalPlacesSelected(_)
+                    | Message::CsvImportPressed
+            )
+    }
+
+    // The folder of a picked file is where the next picker of the same kind opens
+    pub fn picked_path(&self) -> Option<(FileFolder, &str)> {
+        let (file_folder, file_path) = match self {
+            Message::SaveApplicationCompleted(file_path)
+            | Message::LoadApplicationCompleted(file_path)
+            | Message::SaveProfileFolderCompleted(file_path)
+            | Message::ConvertProfileToFolderFileSelected(file_path)
+            | Message::ConvertProfileToFolderCompleted(_, file_path)
+            | Message::ConvertFolderToProfileFolderSelected(file_path)
+            | Message::ConvertFolderToProfileCompleted(_, file_path)
+            | Message::SaveDatabaseCompleted(file_path)
+            | Message::OpenDatabaseCompleted(file_path)
+            | Message::MergeProfileCompleted(file_path) => (FileFolder::Profiles, file_path),
+            Message::ImportAccLaptimesFileCompleted(file_path)
+            | Message::ImportTimingSystemFileCompleted(file_path)
+            | Message::ImportDataLoggerFileCompleted(file_path)
+            | Message::ImportGpsFileCompleted(file_path)
+            | Message::ImportCsvFileCompleted(file_path)
+            | Message::ImportLaptimesFileCompleted(file_path)
+            | Message::ImportTimingSheetFileCompleted(file_path)
+            | Message::AddWatchedFolderCompleted(file_path)
+            | Message::ReplayTelemetryCompleted(file_path) => (FileFolder::Imports, file_path),
+            Message::ImportRacesCompleted(file_paths) => {
+                return file_paths
+                    .as_ref()
+                    .and_then(|file_paths| file_paths.first())
+                    .map(|file_path| (FileFolder::Imports, file_path.as_str()));
+            }
+            Message::SaveTelemetryReplayCompleted(file_path)
+            | Message::ExportRacesCompleted(file_path)
+            | Message::ExportHtmlRacesCompleted(file_path)
+            | Message::SaveImportReportCompleted(file_path) => (FileFolder::Exports, file_path),
+            _ => return None,
+        };
+
+        file_path
+            .as_deref()
+            .map(|file_path| (file_folder, file_path))
+    }
+}
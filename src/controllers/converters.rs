@@ -1,2 +1,7 @@
+pub mod csv_column_mapper;
+pub mod csv_parser;
+pub mod cumulative_time_converter;
+pub mod gps_trace_parser;
 pub mod laptime_converter;
+pub mod laptime_parser;
 pub mod time_parser;
@@ -0,0 +1,298 @@
+use crate::{
+    controllers::converters::{csv_parser::parse_csv_line, laptime_parser::parse_laptime},
+    data_models::race_result_file::RaceResultFile,
+    models::application::csv_import::{CsvColumnMapping, CsvImport, DecimalSeparator},
+    views::application::input_parser::parse_input_u32,
+};
+
+const PREVIEW_LINES: usize = 5;
+
+impl CsvColumnMapping {
+    pub fn convert_to_race_file(&self, contents: &str) -> Option<RaceResultFile> {
+        let rows = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .skip(match self.has_header_row {
+                true => 1,
+                false => 0,
+            })
+            .map(|line| parse_csv_line(line, self.delimiter.to_char()));
+
+        let mut laps: Vec<(u32, String, Vec<String>)> = vec![];
+
+        for (row_number, cells) in (1..).zip(rows) {
+            let cell = |column: u32| -> Option<&String> {
+                match column {
+                    0 => None,
+                    _ => cells.get(column as usize - 1),
+                }
+            };
+
+            let Some(laptime) = cell(self.laptime_column).map(|cell| self.normalise_time(cell))
+            else {
+                continue;
+            };
+
+            if laptime.is_empty() || !Self::is_valid_lap(cell(self.validity_column)) {
+                continue;
+            }
+
+            let lap_number = cell(self.lap_number_column)
+                .and_then(|lap_number| lap_number.parse::<u32>().ok())
+                .unwrap_or(row_number);
+
+            let sectors = self
+                .sector_columns
+                .iter()
+                .filter_map(|column| cell(*column))
+                .map(|sector| self.normalise_time(sector))
+                .filter(|sector| !sector.is_empty())
+                .collect();
+
+            laps.push((lap_number, laptime, sectors));
+        }
+
+        if laps.is_empty() {
+            return None;
+        }
+
+        laps.sort_by_key(|(lap_number, _, _)| *lap_number);
+
+        let mut race_file = RaceResultFile::new_from_laptime_file(
+            laps.iter().map(|(_, laptime, _)| laptime.clone()).collect(),
+        );
+
+        if laps.iter().any(|(_, _, sectors)| !sectors.is_empty()) {
+            race_file.sector_times = laps.into_iter().map(|(_, _, sectors)| sectors).collect();
+        }
+
+        Some(race_file)
+    }
+
+    // Cells that aren't a time with the chosen decimal separator are left out
+    fn normalise_time(&self, cell: &str) -> String {
+        match parse_laptime(cell, Some(self.decimal_separator)) {
+            Ok(_) => cell.trim().replace(',', "."),
+            Err(_) => String::new(),
+        }
+    }
+
+    fn is_valid_lap(validity: Option<&String>) -> bool {
+        let Some(validity) = validity else {
+            return true;
+        };
+
+        !matches!(
+            validity.trim().to_lowercase().as_str(),
+            "0" | "false" | "no" | "n" | "invalid" | "x"
+        )
+    }
+}
+
+impl CsvImport {
+    pub fn open(&mut self, file_path: &str, contents: &str) {
+        self.file_path = Some(file_path.to_string());
+        self.preview = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .take(PREVIEW_LINES)
+            .map(|line| line.to_string())
+            .collect();
+
+        let header = self.preview.first().cloned().unwrap_or_default();
+
+        if let Some(saved_mapping) = self
+            .saved_mappings
+            .iter()
+            .find(|saved_mapping| saved_mapping.has_header_row && saved_mapping.header == header)
+        {
+            self.mapping = saved_mapping.clone();
+        }
+
+        self.update_sector_columns_query();
+    }
+
+    pub fn close(&mut self) {
+        self.file_path = None;
+        self.preview.clear();
+    }
+
+    pub fn select_saved_mapping(&mut self, source_name: &str) {
+        if let Some(saved_mapping) = self
+            .saved_mappings
+            .iter()
+            .find(|saved_mapping| saved_mapping.source_name == source_name)
+        {
+            self.mapping = saved_mapping.clone();
+            self.update_sector_columns_query();
+        }
+    }
+
+    pub fn save_mapping(&mut self) {
+        if self.mapping.source_name.trim().is_empty() {
+            return;
+        }
+
+        self.mapping.header = match self.mapping.has_header_row {
+            true => self.preview.first().cloned().unwrap_or_default(),
+            false => String::new(),
+        };
+
+        match self
+            .saved_mappings
+            .iter_mut()
+            .find(|saved_mapping| saved_mapping.source_name == self.mapping.source_name)
+        {
+            Some(saved_mapping) => *saved_mapping = self.mapping.clone(),
+            None => self.saved_mappings.push(self.mapping.clone()),
+        }
+    }
+
+    // A mapping already saved with the same source name is kept
+    pub fn add_saved_mappings(&mut self, mappings: Vec<CsvColumnMapping>) {
+        for mapping in mappings {
+            if !self
+                .saved_mappings
+                .iter()
+                .any(|saved_mapping| saved_mapping.source_name == mapping.source_name)
+            {
+                self.saved_mappings.push(mapping);
+            }
+        }
+    }
+
+    pub fn set_sector_columns(&mut self, sector_columns_query: String) {
+        self.mapping.sector_columns = sector_columns_query
+            .split([',', ' '])
+            .filter(|column| !column.trim().is_empty())
+            .map(|column| parse_input_u32(column.trim().to_string(), 0, u32::MAX))
+            .filter(|column| *column != 0)
+            .collect();
+
+        self.sector_columns_query = sector_columns_query;
+    }
+
+    fn update_sector_columns_query(&mut self) {
+        self.sector_columns_query = self
+            .mapping
+            .sector_columns
+            .iter()
+            .map(|column| column.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+    }
+}
+
+#[cfg(test)]
+mod csv_column_mapper_should {
+    use super::*;
+    use crate::models::application::csv_import::CsvDelimiter;
+
+    #[test]
+    fn test_convert_to_race_file() {
+        // Given
+        let contents = "Lap;Time;S1;S2;Valid\n2;1:02,500;30,1;32,4;1\n1;62,345;30,0;32,345;1\n3;59,999;29,9;30,099;0\n";
+        let mapping = CsvColumnMapping {
+            delimiter: CsvDelimiter::Semicolon,
+            decimal_separator: DecimalSeparator::Comma,
+            has_header_row: true,
+            lap_number_column: 1,
+            laptime_column: 2,
+            sector_columns: vec![3, 4],
+            validity_column: 5,
+            ..Default::default()
+        };
+        let mut expected_race_file = RaceResultFile::new_from_laptime_file(vec![
+            "62.345".to_string(),
+            "1:02.500".to_string(),
+        ]);
+        expected_race_file.sector_times = vec![
+            vec!["30.0".to_string(), "32.345".to_string()],
+            vec!["30.1".to_string(), "32.4".to_string()],
+        ];
+
+        // When
+        let race_file = mapping.convert_to_race_file(contents);
+
+        // Then
+        pretty_assertions::assert_eq!(Some(expected_race_file), race_file);
+    }
+
+    #[test]
+    fn test_convert_to_race_file_without_header_or_lap_numbers() {
+        // Given
+        let contents = "3,48.667,S1\n3,43.434,S1\n";
+        let mapping = CsvColumnMapping {
+            has_header_row: false,
+            laptime_column: 2,
+            ..Default::default()
+        };
+        let expected_race_file =
+            RaceResultFile::new_from_laptime_file(vec!["48.667".to_string(), "43.434".to_string()]);
+
+        // When
+        let race_file = mapping.convert_to_race_file(contents);
+
+        // Then
+        pretty_assertions::assert_eq!(Some(expected_race_file), race_file);
+    }
+
+    #[test]
+    fn test_convert_to_race_file_no_laps() {
+        // Given
+        let mapping = CsvColumnMapping {
+            laptime_column: 4,
+            ..Default::default()
+        };
+
+        // When
+        let race_file = mapping.convert_to_race_file("Lap,Time\n1,48.667\n");
+
+        // Then
+        assert!(race_file.is_none());
+    }
+
+    #[test]
+    fn test_save_and_reapply_mapping() {
+        // Given
+        let contents = "Lap,Time\n1,48.667\n";
+        let mut csv_import = CsvImport::default();
+        csv_import.open("laps.csv", contents);
+        csv_import.mapping.source_name = "Club Timing".to_string();
+        csv_import.mapping.laptime_column = 2;
+        csv_import.set_sector_columns("3, 4,".to_string());
+
+        // When
+        csv_import.save_mapping();
+        csv_import.close();
+        csv_import.mapping = CsvColumnMapping::default();
+        csv_import.open("other_laps.csv", contents);
+
+        // Then
+        pretty_assertions::assert_eq!(1, csv_import.saved_mappings.len());
+        pretty_assertions::assert_eq!("Club Timing", csv_import.mapping.source_name);
+        pretty_assertions::assert_eq!("Lap,Time", csv_import.mapping.header);
+        pretty_assertions::assert_eq!(2, csv_import.mapping.laptime_column);
+        pretty_assertions::assert_eq!(vec![3, 4], csv_import.mapping.sector_columns);
+        pretty_assertions::assert_eq!("3, 4", csv_import.sector_columns_query);
+    }
+
+    #[test]
+    fn test_select_saved_mapping() {
+        // Given
+        let mut csv_import = CsvImport {
+            saved_mappings: vec![CsvColumnMapping {
+                source_name: "Club Timing".to_string(),
+                laptime_column: 3,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        // When
+        csv_import.select_saved_mapping("Club Timing");
+
+        // Then
+        pretty_assertions::assert_eq!(3, csv_import.mapping.laptime_column);
+    }
+}
@@ -0,0 +1,98 @@
+pub fn detect_delimiter(line: &str) -> char {
+    let mut detected_delimiter = ',';
+    let mut highest_count = 0;
+
+    for delimiter in [',', ';', '\t'] {
+        let count = line.matches(delimiter).count();
+
+        if count > highest_count {
+            detected_delimiter = delimiter;
+            highest_count = count;
+        }
+    }
+
+    detected_delimiter
+}
+
+pub fn parse_csv_line(line: &str, delimiter: char) -> Vec<String> {
+    let mut cells = vec![];
+    let mut cell = String::new();
+    let mut is_quoted = false;
+    let mut chars = line.trim_end_matches(['\r', '\n']).chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if is_quoted && chars.peek() == Some(&'"') => {
+                cell.push('"');
+                chars.next();
+            }
+            '"' => is_quoted = !is_quoted,
+            _ if char == delimiter && !is_quoted => {
+                cells.push(cell.trim().to_string());
+                cell.clear();
+            }
+            _ => cell.push(char),
+        }
+    }
+
+    cells.push(cell.trim().to_string());
+
+    cells
+}
+
+pub fn find_column(headers: &[String], column_name: &str) -> Option<usize> {
+    headers
+        .iter()
+        .position(|header| header.eq_ignore_ascii_case(column_name))
+}
+
+#[cfg(test)]
+mod csv_parser_should {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("Pos,Kart,Driver", ',')]
+    #[case("Rank;Kart;Driver", ';')]
+    #[case("Rank\tKart\tDriver", '\t')]
+    #[case("62.345", ',')]
+    fn test_detect_delimiter(#[case] line: &str, #[case] expected_delimiter: char) {
+        // When
+        let delimiter = detect_delimiter(line);
+
+        // Then
+        pretty_assertions::assert_eq!(expected_delimiter, delimiter);
+    }
+
+    #[rstest]
+    #[case("1,12,Jack Jackson,1:02.345", ',', vec!["1", "12", "Jack Jackson", "1:02.345"])]
+    #[case("1;12;Jack Jackson;62,345", ';', vec!["1", "12", "Jack Jackson", "62,345"])]
+    #[case("\"1\",\"Jackson, Jack\",\"62.345\"", ',', vec!["1", "Jackson, Jack", "62.345"])]
+    #[case("\"Jack \"\"JJ\"\" Jackson\",1", ',', vec!["Jack \"JJ\" Jackson", "1"])]
+    #[case(" 1 , 2 ,\r", ',', vec!["1", "2", ""])]
+    fn test_parse_csv_line(
+        #[case] line: &str,
+        #[case] delimiter: char,
+        #[case] expected_cells: Vec<&str>,
+    ) {
+        // When
+        let cells = parse_csv_line(line, delimiter);
+
+        // Then
+        pretty_assertions::assert_eq!(expected_cells, cells);
+    }
+
+    #[test]
+    fn test_find_column() {
+        // Given
+        let headers = vec![
+            "Pos".to_string(),
+            "Kart".to_string(),
+            "Lap Time".to_string(),
+        ];
+
+        // Then
+        pretty_assertions::assert_eq!(Some(2), find_column(&headers, "lap time"));
+        pretty_assertions::assert_eq!(None, find_column(&headers, "Driver"));
+    }
+}
@@ -0,0 +1,261 @@
+use crate::{
+    controllers::converters::laptime_parser::parse_laptime,
+    models::application::race_editor::LapEntryMode,
+};
+use std::fmt::Display;
+
+const SECONDS_IN_DAY: f64 = 86_400.0;
+const MISSING_CROSSING_RATIO: f32 = 1.6;
+const EXTRA_CROSSING_RATIO: f32 = 0.5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CrossingIssue {
+    OutOfOrder { lap_number: u32 },
+    MissingCrossing { lap_number: u32, laptime: f32 },
+    ExtraCrossing { lap_number: u32, laptime: f32 },
+}
+
+impl Display for CrossingIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrossingIssue::OutOfOrder { lap_number } => write!(
+                f,
+                "Lap {lap_number}: crossing is not after the previous one and was skipped"
+            ),
+            CrossingIssue::MissingCrossing {
+                lap_number,
+                laptime,
+            } => write!(
+                f,
+                "Lap {lap_number}: {laptime:.3}s is far slower than the median lap, a crossing may be missing"
+            ),
+            CrossingIssue::ExtraCrossing {
+                lap_number,
+                laptime,
+            } => write!(
+                f,
+                "Lap {lap_number}: {laptime:.3}s is far quicker than the median lap, a crossing may be doubled"
+            ),
+        }
+    }
+}
+
+pub fn detect_lap_entry_mode(times: &[String]) -> LapEntryMode {
+    if times.iter().any(|time| time.matches(':').count() == 2) {
+        return LapEntryMode::CrossingTimestamps;
+    }
+
+    let parsed_times: Vec<f64> = times
+        .iter()
+        .filter_map(|time| parse_laptime(time, None).ok())
+        .collect();
+
+    let is_increasing = parsed_times.windows(2).all(|pair| pair[1] > pair[0]);
+
+    match parsed_times.as_slice() {
+        [first, .., last] if is_increasing && *last > first * 2.0 => LapEntryMode::CumulativeTimes,
+        _ => LapEntryMode::Laptimes,
+    }
+}
+
+pub fn convert_to_laptimes(
+    times: &[String],
+    lap_entry_mode: LapEntryMode,
+) -> (Vec<f32>, Vec<CrossingIssue>) {
+    // Time of day crossings need more precision than a lap time to keep the milliseconds
+    let parsed_times: Vec<f64> = times
+        .iter()
+        .filter_map(|time| parse_laptime(time, None).ok())
+        .collect();
+
+    let (mut previous_time, crossings) = match lap_entry_mode {
+        LapEntryMode::Laptimes => {
+            return (
+                parsed_times.iter().map(|time| *time as f32).collect(),
+                vec![],
+            );
+        }
+        LapEntryMode::CumulativeTimes => (0.0, parsed_times.as_slice()),
+        LapEntryMode::CrossingTimestamps => match parsed_times.split_first() {
+            Some((start, crossings)) => (*start, crossings),
+            None => return (vec![], vec![]),
+        },
+    };
+
+    let mut laptimes = vec![];
+    let mut crossing_issues = vec![];
+
+    for crossing in crossings {
+        let mut laptime = crossing - previous_time;
+
+        // A transponder log that runs past midnight starts counting from zero again
+        if lap_entry_mode == LapEntryMode::CrossingTimestamps && laptime < 0.0 {
+            laptime += SECONDS_IN_DAY;
+        }
+
+        if laptime <= 0.0 {
+            crossing_issues.push(CrossingIssue::OutOfOrder {
+                lap_number: laptimes.len() as u32 + 1,
+            });
+            continue;
+        }
+
+        laptimes.push(laptime as f32);
+        previous_time = *crossing;
+    }
+
+    crossing_issues.append(&mut detect_crossing_issues(&laptimes));
+
+    (laptimes, crossing_issues)
+}
+
+pub fn detect_crossing_issues(laptimes: &[f32]) -> Vec<CrossingIssue> {
+    let Some(median) = median(laptimes) else {
+        return vec![];
+    };
+
+    let mut crossing_issues = vec![];
+
+    for (lap_number, laptime) in (1..).zip(laptimes.iter().copied()) {
+        if laptime > median * MISSING_CROSSING_RATIO {
+            crossing_issues.push(CrossingIssue::MissingCrossing {
+                lap_number,
+                laptime,
+            });
+        } else if laptime < median * EXTRA_CROSSING_RATIO {
+            crossing_issues.push(CrossingIssue::ExtraCrossing {
+                lap_number,
+                laptime,
+            });
+        }
+    }
+
+    crossing_issues
+}
+
+fn median(laptimes: &[f32]) -> Option<f32> {
+    if laptimes.is_empty() {
+        return None;
+    }
+
+    let mut sorted_laptimes = laptimes.to_vec();
+    sorted_laptimes.sort_by(|a, b| a.total_cmp(b));
+
+    let middle = sorted_laptimes.len() / 2;
+
+    match sorted_laptimes.len() % 2 {
+        0 => Some((sorted_laptimes[middle - 1] + sorted_laptimes[middle]) / 2.0),
+        _ => Some(sorted_laptimes[middle]),
+    }
+}
+
+#[cfg(test)]
+mod cumulative_time_converter_should {
+    use super::*;
+    use rstest::rstest;
+
+    fn times(times: &[&str]) -> Vec<String> {
+        times.iter().map(|time| time.to_string()).collect()
+    }
+
+    #[rstest]
+    #[case(times(&["48.667", "43.434", "44.1"]), LapEntryMode::Laptimes)]
+    #[case(times(&["48.5", "1:32.0", "2:16.0"]), LapEntryMode::CumulativeTimes)]
+    #[case(times(&["14:02:11.5", "14:02:55.0"]), LapEntryMode::CrossingTimestamps)]
+    #[case(times(&[]), LapEntryMode::Laptimes)]
+    fn test_detect_lap_entry_mode(
+        #[case] times: Vec<String>,
+        #[case] expected_lap_entry_mode: LapEntryMode,
+    ) {
+        // When
+        let lap_entry_mode = detect_lap_entry_mode(&times);
+
+        // Then
+        pretty_assertions::assert_eq!(expected_lap_entry_mode, lap_entry_mode);
+    }
+
+    #[test]
+    fn test_convert_cumulative_times_to_laptimes() {
+        // Given
+        let times = times(&["48.5", "1:32.0", "2:16.0", "3:00.5"]);
+
+        // When
+        let (laptimes, crossing_issues) =
+            convert_to_laptimes(&times, LapEntryMode::CumulativeTimes);
+
+        // Then
+        pretty_assertions::assert_eq!(vec![48.5, 43.5, 44.0, 44.5], laptimes);
+        assert!(crossing_issues.is_empty());
+    }
+
+    #[test]
+    fn test_convert_crossing_timestamps_to_laptimes_over_midnight() {
+        // Given
+        let times = times(&["23:59:00.0", "23:59:45.0", "0:00:30.5"]);
+
+        // When
+        let (laptimes, crossing_issues) =
+            convert_to_laptimes(&times, LapEntryMode::CrossingTimestamps);
+
+        // Then
+        pretty_assertions::assert_eq!(vec![45.0, 45.5], laptimes);
+        assert!(crossing_issues.is_empty());
+    }
+
+    #[test]
+    fn test_convert_to_laptimes_reports_crossing_issues() {
+        // Given
+        let times = times(&[
+            "45.0", "1:30.0", "1:29.0", "3:00.0", "3:45.0", "3:50.0", "4:35.0",
+        ]);
+
+        // When
+        let (laptimes, crossing_issues) =
+            convert_to_laptimes(&times, LapEntryMode::CumulativeTimes);
+
+        // Then
+        pretty_assertions::assert_eq!(vec![45.0, 45.0, 90.0, 45.0, 5.0, 45.0], laptimes);
+        pretty_assertions::assert_eq!(
+            vec![
+                CrossingIssue::OutOfOrder { lap_number: 3 },
+                CrossingIssue::MissingCrossing {
+                    lap_number: 3,
+                    laptime: 90.0
+                },
+                CrossingIssue::ExtraCrossing {
+                    lap_number: 5,
+                    laptime: 5.0
+                },
+            ],
+            crossing_issues
+        );
+    }
+
+    #[test]
+    fn test_convert_laptimes_unchanged() {
+        // Given
+        let times = times(&["48.667", "1:02.5"]);
+
+        // When
+        let (laptimes, crossing_issues) = convert_to_laptimes(&times, LapEntryMode::Laptimes);
+
+        // Then
+        pretty_assertions::assert_eq!(vec![48.667, 62.5], laptimes);
+        assert!(crossing_issues.is_empty());
+    }
+
+    #[test]
+    fn test_display_crossing_issue() {
+        // Given
+        let crossing_issue = CrossingIssue::MissingCrossing {
+            lap_number: 3,
+            laptime: 90.0,
+        };
+
+        // Then
+        pretty_assertions::assert_eq!(
+            "Lap 3: 90.000s is far slower than the median lap, a crossing may be missing",
+            crossing_issue.to_string()
+        );
+    }
+}
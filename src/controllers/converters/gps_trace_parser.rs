@@ -0,0 +1,536 @@
+use crate::{
+    controllers::{
+        converters::{
+            csv_parser::{detect_delimiter, parse_csv_line},
+            laptime_parser::parse_laptime,
+            time_parser::convert_session_time_to_session_id,
+        },
+        race_results::gps_statistics::distance_between,
+    },
+    models::{
+        date::RaceDate,
+        driver::session_information::{
+            gps_session::{GpsPoint, GpsSession, GpsSource},
+            timing_system_session::parse_date,
+        },
+    },
+};
+use chrono::{Datelike, NaiveDateTime, Timelike};
+
+const START_FINISH_RADIUS: f64 = 25.0;
+const LEAVE_START_FINISH_DISTANCE: f64 = 100.0;
+const KPH_PER_MPS: f32 = 3.6;
+const KPH_PER_MPH: f32 = 1.609_344;
+
+impl GpsSession {
+    pub fn parse(contents: &str) -> Option<GpsSession> {
+        let lowercase_contents = contents.to_lowercase();
+
+        let mut gps_session = if lowercase_contents.contains("<gpx") {
+            Self::parse_gpx(contents)?
+        } else if lowercase_contents.contains("[data]") {
+            Self::parse_vbo(contents)?
+        } else if lowercase_contents.contains("racechrono") {
+            Self::parse_csv(GpsSource::RaceChrono, contents)?
+        } else if lowercase_contents.contains("harry") {
+            Self::parse_csv(GpsSource::HarrysLapTimer, contents)?
+        } else {
+            return None;
+        };
+
+        if gps_session.points.is_empty() {
+            return None;
+        }
+
+        let start_elapsed = gps_session.points[0].elapsed;
+        for point in &mut gps_session.points {
+            point.elapsed -= start_elapsed;
+        }
+
+        if gps_session.points.iter().all(|point| point.lap_number == 0) {
+            gps_session.assign_laps_by_start_finish();
+        }
+
+        Some(gps_session)
+    }
+
+    pub fn session_id(&self) -> u32 {
+        convert_session_time_to_session_id(&self.session_time)
+    }
+
+    pub fn convert_to_laptimes(&self) -> Vec<String> {
+        let mut lap_starts: Vec<(u32, f64)> = vec![];
+
+        for point in &self.points {
+            if lap_starts
+                .last()
+                .is_none_or(|(lap_number, _)| *lap_number != point.lap_number)
+            {
+                lap_starts.push((point.lap_number, point.elapsed));
+            }
+        }
+
+        // The out lap is lap 0 and the last lap is only complete once the next one starts
+        lap_starts
+            .windows(2)
+            .filter(|pair| pair[0].0 != 0)
+            .map(|pair| format!("{:.3}", pair[1].1 - pair[0].1))
+            .collect()
+    }
+
+    pub fn simplify_trace(&self, maximum_points: usize) -> Vec<GpsPoint> {
+        let step = self.points.len().div_ceil(maximum_points.max(1)).max(1);
+
+        let mut points: Vec<GpsPoint> = self.points.iter().step_by(step).copied().collect();
+
+        if let (Some(last_point), Some(last_kept_point)) = (self.points.last(), points.last())
+            && last_point != last_kept_point
+        {
+            points.push(*last_point);
+        }
+
+        points
+    }
+
+    fn assign_laps_by_start_finish(&mut self) {
+        let Some(start_finish) = self.points.first().copied() else {
+            return;
+        };
+
+        let mut lap_number = 1;
+        let mut has_left_start_finish = false;
+
+        for point in &mut self.points {
+            let distance = distance_between(&start_finish, point);
+
+            if distance > LEAVE_START_FINISH_DISTANCE {
+                has_left_start_finish = true;
+            } else if has_left_start_finish && distance < START_FINISH_RADIUS {
+                lap_number += 1;
+                has_left_start_finish = false;
+            }
+
+            point.lap_number = lap_number;
+        }
+    }
+
+    fn parse_csv(gps_source: GpsSource, contents: &str) -> Option<GpsSession> {
+        let lines: Vec<&str> = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+
+        let mut track_name = String::new();
+        let mut date = None;
+        let mut session_time = String::new();
+
+        for (index, line) in lines.iter().enumerate() {
+            let delimiter = detect_delimiter(line);
+            let cells = parse_csv_line(line, delimiter);
+
+            if find_column_starting_with(&cells, "lat").is_some()
+                && find_column_starting_with(&cells, "lon").is_some()
+            {
+                return Some(GpsSession {
+                    gps_source,
+                    track_name,
+                    date,
+                    session_time,
+                    points: Self::parse_csv_points(&cells, &lines[index + 1..], delimiter),
+                });
+            }
+
+            let key = cells.first().cloned().unwrap_or_default().to_lowercase();
+            let values: Vec<&str> = cells
+                .iter()
+                .skip(1)
+                .flat_map(|cell| cell.split_whitespace())
+                .collect();
+
+            match key.trim_end_matches(':') {
+                "track" | "track name" => track_name = cells.get(1).cloned().unwrap_or_default(),
+                "date" | "created" => {
+                    date = values.first().and_then(|value| parse_date(value));
+                    if let Some(time) = values.get(1) {
+                        session_time = time.to_string();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    fn parse_csv_points(headers: &[String], rows: &[&str], delimiter: char) -> Vec<GpsPoint> {
+        let (Some(latitude_column), Some(longitude_column)) = (
+            find_column_starting_with(headers, "lat"),
+            find_column_starting_with(headers, "lon"),
+        ) else {
+            return vec![];
+        };
+
+        let time_column = find_column_starting_with(headers, "time");
+        let lap_column = headers.iter().position(|header| {
+            matches!(
+                strip_unit(header).as_str(),
+                "lap" | "lap #" | "lap number" | "lapnumber"
+            )
+        });
+        let speed_column = find_column_starting_with(headers, "speed");
+        let speed_multiplier = speed_column
+            .and_then(|column| headers.get(column))
+            .map(|header| speed_multiplier(header))
+            .unwrap_or(1.0);
+
+        let mut points = vec![];
+
+        for (row_number, row) in (0..).zip(rows) {
+            let cells = parse_csv_line(row, delimiter);
+            let cell = |column: Option<usize>| {
+                column
+                    .and_then(|column| cells.get(column))
+                    .cloned()
+                    .unwrap_or_default()
+            };
+
+            let (Ok(latitude), Ok(longitude)) = (
+                cell(Some(latitude_column)).parse::<f64>(),
+                cell(Some(longitude_column)).parse::<f64>(),
+            ) else {
+                continue;
+            };
+
+            points.push(GpsPoint {
+                elapsed: parse_laptime(&cell(time_column), None).unwrap_or(row_number as f64),
+                latitude,
+                longitude,
+                speed: cell(speed_column)
+                    .parse::<f32>()
+                    .ok()
+                    .map(|speed| speed * speed_multiplier),
+                lap_number: cell(lap_column).parse::<u32>().unwrap_or_default(),
+            });
+        }
+
+        points
+    }
+
+    fn parse_vbo(contents: &str) -> Option<GpsSession> {
+        let mut section = String::new();
+        let mut column_names: Vec<String> = vec![];
+        let mut date = None;
+        let mut session_time = String::new();
+        let mut points = vec![];
+
+        for line in contents.lines().map(|line| line.trim()) {
+            if line.is_empty() {
+                continue;
+            }
+
+            if line.starts_with('[') {
+                section = line.to_lowercase();
+                continue;
+            }
+
+            if let Some(created) = line.strip_prefix("File created on ") {
+                let mut parts = created.split(" at ");
+                date = parts.next().and_then(parse_date);
+                session_time = parts.next().unwrap_or_default().trim().to_string();
+                continue;
+            }
+
+            match section.as_str() {
+                "[column names]" => {
+                    column_names = line
+                        .split_whitespace()
+                        .map(|name| name.to_lowercase())
+                        .collect()
+                }
+                "[data]" => {
+                    if let Some(point) = Self::parse_vbo_point(&column_names, line) {
+                        points.push(point);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Some(GpsSession {
+            gps_source: GpsSource::Vbo,
+            track_name: Default::default(),
+            date,
+            session_time,
+            points,
+        })
+    }
+
+    fn parse_vbo_point(column_names: &[String], line: &str) -> Option<GpsPoint> {
+        let values: Vec<&str> = line.split_whitespace().collect();
+        let value = |name: &str| -> Option<f64> {
+            let column = column_names.iter().position(|column| column == name)?;
+            values
+                .get(column)?
+                .trim_start_matches('+')
+                .parse::<f64>()
+                .ok()
+        };
+
+        // VBOX writes HHMMSS.SS, latitude and longitude in minutes and longitude positive to the west
+        let time = value("time")?;
+        let hours = (time / 10_000.0).floor();
+        let minutes = ((time - hours * 10_000.0) / 100.0).floor();
+        let seconds = time - hours * 10_000.0 - minutes * 100.0;
+
+        Some(GpsPoint {
+            elapsed: hours * 3600.0 + minutes * 60.0 + seconds,
+            latitude: value("lat")? / 60.0,
+            longitude: -value("long")? / 60.0,
+            speed: value("velocity").map(|speed| speed as f32),
+            lap_number: value("lapnumber").unwrap_or_default() as u32,
+        })
+    }
+
+    fn parse_gpx(contents: &str) -> Option<GpsSession> {
+        let document = roxmltree::Document::parse(contents).ok()?;
+
+        let track_name = document
+            .descendants()
+            .find(|node| node.has_tag_name("trk"))
+            .and_then(|track| track.children().find(|node| node.has_tag_name("name")))
+            .and_then(|name| name.text())
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+
+        let mut start_time = None;
+        let mut points = vec![];
+
+        for track_point in document
+            .descendants()
+            .filter(|node| node.has_tag_name("trkpt"))
+        {
+            let (Some(Ok(latitude)), Some(Ok(longitude))) = (
+                track_point.attribute("lat").map(str::parse::<f64>),
+                track_point.attribute("lon").map(str::parse::<f64>),
+            ) else {
+                continue;
+            };
+
+            let child_text = |tag_name: &str| {
+                track_point
+                    .children()
+                    .find(|node| node.has_tag_name(tag_name))
+                    .and_then(|node| node.text())
+                    .map(|text| text.trim().to_string())
+            };
+
+            let time = child_text("time").and_then(|time| parse_gpx_time(&time));
+            if start_time.is_none() {
+                start_time = time;
+            }
+
+            let elapsed = match (time, start_time) {
+                (Some(time), Some(start_time)) => {
+                    (time - start_time).num_milliseconds() as f64 / 1000.0
+                }
+                _ => points.len() as f64,
+            };
+
+            points.push(GpsPoint {
+                elapsed,
+                latitude,
+                longitude,
+                speed: child_text("speed")
+                    .and_then(|speed| speed.parse::<f32>().ok())
+                    .map(|speed| speed * KPH_PER_MPS),
+                lap_number: 0,
+            });
+        }
+
+        Some(GpsSession {
+            gps_source: GpsSource::Gpx,
+            track_name,
+            date: start_time.map(|start_time| {
+                RaceDate::new(start_time.day(), start_time.month(), start_time.year())
+            }),
+            session_time: start_time
+                .map(|start_time| format!("{:02}:{:02}", start_time.hour(), start_time.minute()))
+                .unwrap_or_default(),
+            points,
+        })
+    }
+}
+
+fn parse_gpx_time(time: &str) -> Option<NaiveDateTime> {
+    [
+        "%Y-%m-%dT%H:%M:%S%.fZ",
+        "%Y-%m-%dT%H:%M:%SZ",
+        "%Y-%m-%dT%H:%M:%S%.f%:z",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(time, format).ok())
+}
+
+fn strip_unit(header: &str) -> String {
+    header
+        .split('(')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase()
+}
+
+fn find_column_starting_with(headers: &[String], prefix: &str) -> Option<usize> {
+    headers
+        .iter()
+        .position(|header| strip_unit(header).starts_with(prefix))
+}
+
+fn speed_multiplier(header: &str) -> f32 {
+    let header = header.to_lowercase();
+
+    if header.contains("m/s") {
+        KPH_PER_MPS
+    } else if header.contains("mph") {
+        KPH_PER_MPH
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod gps_trace_parser_should {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_parse_racechrono_csv() {
+        // Given
+        let contents = "This file is created using RaceChrono v8\nSession title,Track Day\nTrack name,Snetterton 300\nCreated,15/03/2025,10:32\n\nTime (s),Lap #,Latitude (deg),Longitude (deg),Speed (km/h)\n100.0,0,52.4660,0.9450,72.0\n110.5,1,52.4670,0.9460,90.0\n200.25,2,52.4660,0.9450,108.0\n290.0,3,52.4670,0.9460,36.0\n";
+
+        // When
+        let gps_session = GpsSession::parse(contents).unwrap();
+
+        // Then
+        pretty_assertions::assert_eq!(GpsSource::RaceChrono, gps_session.gps_source);
+        pretty_assertions::assert_eq!("Snetterton 300", gps_session.track_name);
+        pretty_assertions::assert_eq!(Some(RaceDate::new(15, 3, 2025)), gps_session.date);
+        pretty_assertions::assert_eq!("10:32", gps_session.session_time);
+        pretty_assertions::assert_eq!(
+            GpsPoint {
+                elapsed: 10.5,
+                latitude: 52.467,
+                longitude: 0.946,
+                speed: Some(90.0),
+                lap_number: 1,
+            },
+            gps_session.points[1]
+        );
+        pretty_assertions::assert_eq!(
+            vec!["89.750".to_string(), "89.750".to_string()],
+            gps_session.convert_to_laptimes()
+        );
+    }
+
+    #[test]
+    fn test_parse_vbo() {
+        // Given
+        let contents = "File created on 15/03/2025 at 10:32:05\n\n[header]\nsatellites\ntime\nlatitude\nlongitude\nvelocity kmh\n\n[column names]\nsats time lat long velocity\n\n[data]\n008 103205.00 +3147.96000 +00056.70000 050.000\n008 103206.50 +3147.97000 +00056.71000 051.500\n";
+
+        // When
+        let gps_session = GpsSession::parse(contents).unwrap();
+
+        // Then
+        pretty_assertions::assert_eq!(GpsSource::Vbo, gps_session.gps_source);
+        pretty_assertions::assert_eq!(Some(RaceDate::new(15, 3, 2025)), gps_session.date);
+        pretty_assertions::assert_eq!(2, gps_session.points.len());
+        pretty_assertions::assert_eq!(1.5, gps_session.points[1].elapsed);
+        assert!((gps_session.points[0].latitude - 52.466).abs() < 0.000_001);
+        assert!((gps_session.points[0].longitude + 0.945).abs() < 0.000_001);
+        pretty_assertions::assert_eq!(Some(51.5), gps_session.points[1].speed);
+    }
+
+    #[test]
+    fn test_parse_gpx_and_detect_laps() {
+        // Given
+        let contents = r#"<?xml version="1.0"?>
+<gpx version="1.1" creator="Test"><trk><name>Lydd</name><trkseg>
+<trkpt lat="51.0000" lon="0.9000"><time>2025-03-15T10:32:00Z</time></trkpt>
+<trkpt lat="51.0020" lon="0.9000"><time>2025-03-15T10:32:20Z</time></trkpt>
+<trkpt lat="51.0000" lon="0.9001"><time>2025-03-15T10:32:45.5Z</time></trkpt>
+<trkpt lat="51.0020" lon="0.9000"><time>2025-03-15T10:33:05Z</time></trkpt>
+<trkpt lat="51.0000" lon="0.9000"><time>2025-03-15T10:33:30Z</time></trkpt>
+</trkseg></trk></gpx>"#;
+
+        // When
+        let gps_session = GpsSession::parse(contents).unwrap();
+
+        // Then
+        pretty_assertions::assert_eq!(GpsSource::Gpx, gps_session.gps_source);
+        pretty_assertions::assert_eq!("Lydd", gps_session.track_name);
+        pretty_assertions::assert_eq!("10:32", gps_session.session_time);
+        pretty_assertions::assert_eq!(
+            vec![1, 1, 2, 2, 3],
+            gps_session
+                .points
+                .iter()
+                .map(|point| point.lap_number)
+                .collect::<Vec<u32>>()
+        );
+        pretty_assertions::assert_eq!(
+            vec!["45.500".to_string(), "44.500".to_string()],
+            gps_session.convert_to_laptimes()
+        );
+    }
+
+    #[rstest]
+    #[case("Speed (m/s)", KPH_PER_MPS)]
+    #[case("Speed (mph)", KPH_PER_MPH)]
+    #[case("Speed (km/h)", 1.0)]
+    fn test_speed_multiplier(#[case] header: &str, #[case] expected_speed_multiplier: f32) {
+        // When
+        let multiplier = speed_multiplier(header);
+
+        // Then
+        pretty_assertions::assert_eq!(expected_speed_multiplier, multiplier);
+    }
+
+    #[test]
+    fn test_parse_unknown_file() {
+        // When
+        let gps_session = GpsSession::parse("Lap,Lap Time\n1,48.667\n");
+
+        // Then
+        assert!(gps_session.is_none());
+    }
+
+    #[test]
+    fn test_simplify_trace() {
+        // Given
+        let gps_session = GpsSession {
+            gps_source: GpsSource::Gpx,
+            track_name: Default::default(),
+            date: None,
+            session_time: Default::default(),
+            points: (0..10)
+                .map(|elapsed| GpsPoint {
+                    elapsed: elapsed as f64,
+                    ..Default::default()
+                })
+                .collect(),
+        };
+
+        // When
+        let points = gps_session.simplify_trace(4);
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![0.0, 3.0, 6.0, 9.0],
+            points
+                .iter()
+                .map(|point| point.elapsed)
+                .collect::<Vec<f64>>()
+        );
+    }
+}
@@ -1,19 +1,30 @@
 use crate::{
-    controllers::converters::time_parser::format_laptime,
+    controllers::converters::laptime_parser::parse_laptime,
     models::driver::session_information::{lap::Lap, race_result::RaceResult},
 };
 use std::collections::HashMap;
 
 impl RaceResult {
+    // Only the times can be edited so the sectors and channels of each lap are kept by lap number
     pub fn convert_to_laps(&mut self, laptime_editor_string: String) {
         let laptimes = Self::convert_laptimes_string_to_laps(laptime_editor_string);
 
         let mut converted_laptimes = Vec::new();
 
         for (lap_number, &time) in laptimes.iter().enumerate() {
+            let lap_number = (lap_number + 1) as u32;
+            let existing_lap = self
+                .laptimes
+                .iter()
+                .find(|lap| lap.lap_number == lap_number);
+
             converted_laptimes.push(Lap {
-                lap_number: (lap_number + 1) as u32,
+                lap_number,
                 time,
+                sectors: existing_lap
+                    .map(|lap| lap.sectors.clone())
+                    .unwrap_or_default(),
+                channels: existing_lap.map(|lap| lap.channels).unwrap_or_default(),
             });
         }
 
@@ -21,16 +32,12 @@ impl RaceResult {
     }
 
     // TODO investigate if this can be removed
-    pub fn convert_hash_map(hash_map: HashMap<usize, f32>) -> Vec<(usize, String)> {
+    pub fn convert_hash_map(hash_map: HashMap<usize, f32>) -> Vec<(usize, f32)> {
         // order by key
         let mut sorted: Vec<(usize, f32)> = hash_map.into_iter().collect();
         sorted.sort_by_key(|(k, _)| *k);
 
-        // format laptime
         sorted
-            .into_iter()
-            .map(|(k, v)| (k, format_laptime(v)))
-            .collect()
     }
 
     pub fn convert_laps_to_string(&self) -> String {
@@ -46,36 +53,17 @@ impl RaceResult {
     fn convert_laptimes_string_to_laps(laptime_string: String) -> Vec<f32> {
         laptime_string
             .lines()
-            .filter_map(|lap| {
-                let trimmed_lap = lap.trim();
-
-                if trimmed_lap.contains(':') {
-                    let parts: Vec<&str> = trimmed_lap.split(':').collect();
-
-                    let minutes = parts[0].parse::<f32>();
-
-                    match minutes {
-                        Ok(minutes) => {
-                            let seconds = parts[1].parse::<f32>();
-
-                            match seconds {
-                                Ok(seconds) => Some(minutes * 60.0 + seconds),
-                                Err(_) => None,
-                            }
-                        }
-                        Err(_) => None,
-                    }
-                } else {
-                    lap.trim().parse::<f32>().ok()
-                }
-            })
+            .filter_map(|lap| parse_laptime(lap, None).ok())
+            .map(|time| time as f32)
             .collect()
     }
 }
 
 #[cfg(test)]
 mod laptime_converter_should {
-    use crate::models::driver::session_information::{lap::Lap, race_result::RaceResult};
+    use crate::models::driver::session_information::{
+        lap::Lap, lap_channels::LapChannels, race_result::RaceResult,
+    };
     use std::collections::HashMap;
 
     #[test]
@@ -97,15 +85,82 @@ mod laptime_converter_should {
         pretty_assertions::assert_eq!(expected_laps, race.laptimes)
     }
 
+    #[test]
+    fn test_convert_to_laps_keeps_channels() {
+        // Given
+        let channels = LapChannels {
+            max_rpm: Some(14250.0),
+            water_temperature: Some(52.5),
+            ..Default::default()
+        };
+        let mut race = RaceResult::new(
+            Default::default(),
+            Default::default(),
+            vec![Lap {
+                channels,
+                ..Lap::new(1, 53.1)
+            }],
+        );
+
+        // When
+        race.convert_to_laps("53.0\n54.0".to_string());
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![
+                Lap {
+                    channels,
+                    ..Lap::new(1, 53.0)
+                },
+                Lap::new(2, 54.0),
+            ],
+            race.laptimes
+        )
+    }
+
+    #[test]
+    fn test_convert_to_laps_keeps_sectors() {
+        // Given
+        let mut race = RaceResult::new(
+            Default::default(),
+            Default::default(),
+            vec![
+                Lap {
+                    sectors: vec![20.1, 33.0],
+                    ..Lap::new(1, 53.1)
+                },
+                Lap {
+                    sectors: vec![20.4, 32.8],
+                    ..Lap::new(2, 53.2)
+                },
+            ],
+        );
+
+        // When
+        race.convert_to_laps("53.0\n53.2\n54.0".to_string());
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![
+                Lap {
+                    sectors: vec![20.1, 33.0],
+                    ..Lap::new(1, 53.0)
+                },
+                Lap {
+                    sectors: vec![20.4, 32.8],
+                    ..Lap::new(2, 53.2)
+                },
+                Lap::new(3, 54.0),
+            ],
+            race.laptimes
+        )
+    }
+
     // TODO see if this can be removed
     #[test]
     fn test_convert_hash_map() {
         // Given
-        let expected_sorted_races = vec![
-            (5, "3:50.00".to_string()),
-            (10, "9:10.00".to_string()),
-            (15, "12:50.00".to_string()),
-        ];
+        let expected_sorted_races = vec![(5, 230.0), (10, 550.0), (15, 770.0)];
         let mut races_hash_map = HashMap::new();
         races_hash_map.insert(15, 770.0);
         races_hash_map.insert(5, 230.0);
@@ -0,0 +1,231 @@
+use crate::models::application::csv_import::DecimalSeparator;
+use std::fmt::Display;
+
+const SECONDS_IN_MINUTE: f64 = 60.0;
+const MAXIMUM_SEPARATORS: usize = 2;
+const UNITS: [&str; 2] = ["sec", "s"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaptimeError {
+    pub position: usize,
+    pub reason: String,
+}
+
+impl LaptimeError {
+    fn new(position: usize, reason: &str) -> Self {
+        Self {
+            position,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl Display for LaptimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at character {}", self.reason, self.position)
+    }
+}
+
+// Every lap time is read with this grammar, whitespace is allowed around the time:
+//   laptime   = [[hours separator] minutes separator] seconds [unit]
+//   separator = ":" | "'"
+//   seconds   = digits [decimal digits]
+//   decimal   = "." | "," when no decimal separator is given, otherwise only that one
+//   unit      = "s" | "sec"
+// Minutes and seconds that follow a separator have to be under 60. Error positions count
+// characters from 1.
+pub fn parse_laptime(
+    laptime: &str,
+    decimal_separator: Option<DecimalSeparator>,
+) -> Result<f64, LaptimeError> {
+    let chars: Vec<char> = laptime.chars().collect();
+
+    let Some(start) = chars.iter().position(|char| !char.is_whitespace()) else {
+        return Err(LaptimeError::new(1, "no time was given"));
+    };
+
+    let end = find_end_of_time(&chars, start);
+
+    let mut total_seconds = 0.0;
+    let mut separators = 0;
+    let mut digits = String::new();
+    let mut fraction: Option<String> = None;
+    let mut field_position = start + 1;
+
+    for (position, char) in (start + 1..).zip(chars[start..end].iter().copied()) {
+        if char.is_ascii_digit() {
+            match &mut fraction {
+                Some(fraction) => fraction.push(char),
+                None => digits.push(char),
+            }
+        } else if is_decimal_mark(char, decimal_separator) {
+            if digits.is_empty() {
+                return Err(LaptimeError::new(
+                    position,
+                    "expected a digit before the decimal",
+                ));
+            }
+
+            if fraction.is_some() {
+                return Err(LaptimeError::new(position, "unexpected second decimal"));
+            }
+
+            fraction = Some(String::new());
+        } else if char == ':' || char == '\'' {
+            if digits.is_empty() {
+                return Err(LaptimeError::new(
+                    position,
+                    &format!("expected a digit before '{char}'"),
+                ));
+            }
+
+            if fraction.is_some() {
+                return Err(LaptimeError::new(
+                    position,
+                    "only the seconds can have a decimal",
+                ));
+            }
+
+            if separators == MAXIMUM_SEPARATORS {
+                return Err(LaptimeError::new(
+                    position,
+                    "expected at most hours, minutes and seconds",
+                ));
+            }
+
+            let value = parse_digits(&digits);
+
+            if separators > 0 && value >= SECONDS_IN_MINUTE {
+                return Err(LaptimeError::new(
+                    field_position,
+                    "minutes must be under 60",
+                ));
+            }
+
+            total_seconds = (total_seconds + value) * SECONDS_IN_MINUTE;
+            separators += 1;
+            digits.clear();
+            field_position = position + 1;
+        } else {
+            return Err(LaptimeError::new(position, &format!("unexpected '{char}'")));
+        }
+    }
+
+    if digits.is_empty()
+        || fraction
+            .as_ref()
+            .is_some_and(|fraction| fraction.is_empty())
+    {
+        return Err(LaptimeError::new(end + 1, "expected a digit"));
+    }
+
+    let seconds = parse_digits(&format!(
+        "{digits}.{}",
+        fraction.unwrap_or_else(|| "0".to_string())
+    ));
+
+    if separators > 0 && seconds >= SECONDS_IN_MINUTE {
+        return Err(LaptimeError::new(
+            field_position,
+            "seconds must be under 60",
+        ));
+    }
+
+    Ok(total_seconds + seconds)
+}
+
+// The unit and any whitespace around it aren't part of the time
+fn find_end_of_time(chars: &[char], start: usize) -> usize {
+    let mut end = chars.len();
+
+    while end > start && chars[end - 1].is_whitespace() {
+        end -= 1;
+    }
+
+    let time: String = chars[start..end].iter().collect::<String>().to_lowercase();
+
+    if let Some(unit) = UNITS.iter().find(|unit| time.ends_with(**unit)) {
+        end -= unit.len();
+    }
+
+    while end > start && chars[end - 1].is_whitespace() {
+        end -= 1;
+    }
+
+    end
+}
+
+fn is_decimal_mark(char: char, decimal_separator: Option<DecimalSeparator>) -> bool {
+    match decimal_separator {
+        Some(DecimalSeparator::Point) => char == '.',
+        Some(DecimalSeparator::Comma) => char == ',',
+        None => char == '.' || char == ',',
+    }
+}
+
+// Only ever given ASCII digits with at most one point
+fn parse_digits(digits: &str) -> f64 {
+    digits.parse::<f64>().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod laptime_parser_should {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("48.667", 48.667)]
+    #[case("54", 54.0)]
+    #[case("1:02.5", 62.5)]
+    #[case("2:00.6", 120.6)]
+    #[case("14:02:11.5", 50531.5)]
+    #[case("1:02:03.456", 3723.456)]
+    #[case("1'02.345", 62.345)]
+    #[case("62,345", 62.345)]
+    #[case("1:02,345", 62.345)]
+    #[case("62.3s", 62.3)]
+    #[case(" 62.3 sec ", 62.3)]
+    fn test_parse_laptime(#[case] laptime: &str, #[case] expected_time: f64) {
+        // When
+        let time = parse_laptime(laptime, None);
+
+        // Then
+        pretty_assertions::assert_eq!(Ok(expected_time as f32), time.map(|time| time as f32));
+    }
+
+    #[rstest]
+    #[case("", None, 1, "no time was given")]
+    #[case("boop", None, 1, "unexpected 'b'")]
+    #[case("3:boop", None, 3, "unexpected 'b'")]
+    #[case(":45.6", None, 1, "expected a digit before ':'")]
+    #[case("1:2:3:4", None, 6, "expected at most hours, minutes and seconds")]
+    #[case("1:75.0", None, 3, "seconds must be under 60")]
+    #[case("1:75:00", None, 3, "minutes must be under 60")]
+    #[case("1.5:02", None, 4, "only the seconds can have a decimal")]
+    #[case("1.2.3", None, 4, "unexpected second decimal")]
+    #[case("1:", None, 3, "expected a digit")]
+    #[case("62.", None, 4, "expected a digit")]
+    #[case("62,345", Some(DecimalSeparator::Point), 3, "unexpected ','")]
+    #[case("62.345", Some(DecimalSeparator::Comma), 3, "unexpected '.'")]
+    fn test_report_where_laptime_is_invalid(
+        #[case] laptime: &str,
+        #[case] decimal_separator: Option<DecimalSeparator>,
+        #[case] position: usize,
+        #[case] reason: &str,
+    ) {
+        // When
+        let time = parse_laptime(laptime, decimal_separator);
+
+        // Then
+        pretty_assertions::assert_eq!(Err(LaptimeError::new(position, reason)), time);
+    }
+
+    #[test]
+    fn test_display_laptime_error() {
+        // Given
+        let laptime_error = LaptimeError::new(3, "unexpected 'b'");
+
+        // Then
+        pretty_assertions::assert_eq!("unexpected 'b' at character 3", laptime_error.to_string());
+    }
+}
@@ -1,67 +1,87 @@
-use crate::models::driver::session_information::lap::Lap;
-
-pub fn format_laptime(time_in_seconds: f32) -> String {
+use crate::{
+    controllers::converters::laptime_parser::parse_laptime,
+    models::{
+        application::time_format::{DecimalPlaces, TimeFormat, TimeStyle},
+        driver::session_information::lap::Lap,
+    },
+};
+use chrono::{NaiveTime, Timelike};
+
+const SECONDS_IN_MINUTE: u64 = 60;
+const MINUTES_IN_HOUR: u64 = 60;
+
+// The time is rounded once to the chosen precision so a fraction can never round up to a whole
+// second, minutes are always shown in the minutes style so every time has the same shape
+pub fn format_laptime(time_in_seconds: f32, time_format: TimeFormat) -> String {
     if time_in_seconds == 0.0 {
         return "".to_string();
     }
 
-    if time_in_seconds < 60.0 {
-        return format!("{:.2}", time_in_seconds);
-    }
+    let digits = time_format.decimal_places.to_digits();
+    let scale = 10_u64.pow(digits);
+    let total_units = (f64::from(time_in_seconds.abs()) * scale as f64).round() as u64;
 
-    let total_milliseconds = (time_in_seconds.fract() * 100.0).round() as u32;
+    let fraction = format!("{:0width$}", total_units % scale, width = digits as usize);
+    let total_seconds = total_units / scale;
 
-    let minutes = (time_in_seconds / 60.0).floor() as u32;
-    let seconds = (time_in_seconds % 60.0) as u32;
+    if time_format.time_style == TimeStyle::Seconds {
+        return format!("{total_seconds}.{fraction}");
+    }
 
-    if minutes < 60 {
-        return format!("{}:{:02}.{:02}", minutes, seconds, total_milliseconds);
+    let seconds = total_seconds % SECONDS_IN_MINUTE;
+    let minutes = total_seconds / SECONDS_IN_MINUTE;
+
+    if minutes < MINUTES_IN_HOUR {
+        return format!("{minutes}:{seconds:02}.{fraction}");
     }
 
-    let hours = minutes / 60;
-    let remaining_minutes = minutes % 60;
+    format!(
+        "{}:{:02}:{seconds:02}.{fraction}",
+        minutes / MINUTES_IN_HOUR,
+        minutes % MINUTES_IN_HOUR
+    )
+}
 
+// Spreads and gaps are short enough that they're always shown in seconds
+pub fn format_seconds(time_in_seconds: f32, time_format: TimeFormat) -> String {
     format!(
-        "{}:{:02}:{:02}.{:02}",
-        hours, remaining_minutes, seconds, total_milliseconds
+        "{:.*}",
+        time_format.decimal_places.to_digits() as usize,
+        time_in_seconds
     )
 }
 
-pub fn convert_laps_to_string_laps(laptimes: Vec<Lap>) -> Vec<String> {
-    let mut formatted_laptimes: Vec<String> = vec![];
+// A drop in time reads as a negative number
+pub fn format_time_gap(gap_in_seconds: f32, time_format: TimeFormat) -> String {
+    let sign = if gap_in_seconds < 0.0 { "-" } else { "+" };
 
-    for laptime in laptimes {
-        formatted_laptimes.push(format_laptime(laptime.time));
-    }
+    format!(
+        "{sign}{}",
+        format_seconds(gap_in_seconds.abs(), time_format)
+    )
+}
 
-    formatted_laptimes
+// Sessions on the same day are told apart by their start time e.g 10:32 is session 1032
+pub fn convert_session_time_to_session_id(session_time: &str) -> u32 {
+    ["%H:%M:%S%.f", "%H:%M:%S", "%H:%M"]
+        .iter()
+        .find_map(|format| NaiveTime::parse_from_str(session_time.trim(), format).ok())
+        .map(|time| time.hour() * 100 + time.minute())
+        .filter(|session_id| *session_id != 0)
+        .unwrap_or(1)
 }
 
+// Laps that can't be read are kept as 0 seconds so later laps keep their sectors and channels
 pub fn convert_string_laps_to_laps(laptimes: Vec<String>) -> Vec<Lap> {
-    let mut formatted_laptimes: Vec<Lap> = vec![];
-
-    for lap in laptimes.iter().enumerate() {
-        let trimmed_lap = lap.1.trim();
-
-        if trimmed_lap.contains(':') {
-            let parts: Vec<&str> = trimmed_lap.split(':').collect();
-
-            let minutes = parts[0].parse::<f32>();
-
-            if let Ok(minutes) = minutes {
-                let seconds = parts[1].parse::<f32>();
-
-                if let Ok(seconds) = seconds {
-                    formatted_laptimes.push(Lap::new((lap.0 + 1) as u32, minutes * 60.0 + seconds))
-                };
-            }
-        } else {
-            let time = lap.1.trim().parse::<f32>().ok();
-            formatted_laptimes.push(Lap::new((lap.0 + 1) as u32, time.unwrap_or_default()));
-        }
-    }
-
-    formatted_laptimes
+    laptimes
+        .iter()
+        .enumerate()
+        .map(|(index, laptime)| {
+            let time = parse_laptime(laptime, None).unwrap_or_default();
+
+            Lap::new((index + 1) as u32, time as f32)
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -70,56 +90,77 @@ mod format_laptime_should {
     use rstest::rstest;
 
     #[rstest]
-    #[case(1.0, "1.00".to_string())]
-    #[case(59.0, "59.00".to_string())]
-    #[case(59.9, "59.90".to_string())]
-    #[case(59.99, "59.99".to_string())]
-    #[case(60.0, "1:00.00".to_string())]
-    #[case(61.0, "1:01.00".to_string())]
-    #[case(61.90, "1:01.90".to_string())]
-    #[case(61.99, "1:01.99".to_string())]
-    #[case(120.0, "2:00.00".to_string())]
-    #[case(121.90, "2:01.90".to_string())]
-    #[case(121.99, "2:01.99".to_string())]
-    #[case(3600.0, "1:00:00.00".to_string())]
-    #[case(3601.0, "1:00:01.00".to_string())]
-    #[case(3601.9, "1:00:01.90".to_string())]
-    #[case(3601.99, "1:00:01.99".to_string())]
-    #[case(3660.0, "1:01:00.00".to_string())]
-    #[case(3660.0, "1:01:00.00".to_string())]
-    #[case(3661.0, "1:01:01.00".to_string())]
-    #[case(3661.9, "1:01:01.90".to_string())]
-    #[case(3661.99, "1:01:01.99".to_string())]
-    #[case(8661.99, "2:24:21.99".to_string())]
-    fn test_format_laptime(#[case] time_in_seconds: f32, #[case] expected_formatted_time: String) {
+    #[case(1.0, "0:01.000")]
+    #[case(45.123, "0:45.123")]
+    #[case(59.99, "0:59.990")]
+    #[case(59.9999, "1:00.000")]
+    #[case(60.0, "1:00.000")]
+    #[case(61.9, "1:01.900")]
+    #[case(121.99, "2:01.990")]
+    #[case(3600.0, "1:00:00.000")]
+    #[case(3601.9, "1:00:01.900")]
+    #[case(3661.99, "1:01:01.990")]
+    #[case(8661.99, "2:24:21.990")]
+    fn test_format_laptime(#[case] time_in_seconds: f32, #[case] expected_formatted_time: &str) {
         // When
-        let formatted_time = format_laptime(time_in_seconds);
+        let formatted_time = format_laptime(time_in_seconds, TimeFormat::default());
+
+        // Then
+        pretty_assertions::assert_eq!(expected_formatted_time, formatted_time);
+    }
+
+    #[rstest]
+    #[case(TimeStyle::Seconds, DecimalPlaces::One, "83.5")]
+    #[case(TimeStyle::Seconds, DecimalPlaces::Two, "83.46")]
+    #[case(TimeStyle::Seconds, DecimalPlaces::Three, "83.456")]
+    #[case(TimeStyle::Minutes, DecimalPlaces::One, "1:23.5")]
+    #[case(TimeStyle::Minutes, DecimalPlaces::Two, "1:23.46")]
+    #[case(TimeStyle::Minutes, DecimalPlaces::Three, "1:23.456")]
+    fn test_format_laptime_with_time_format(
+        #[case] time_style: TimeStyle,
+        #[case] decimal_places: DecimalPlaces,
+        #[case] expected_formatted_time: &str,
+    ) {
+        // Given
+        let time_format = TimeFormat {
+            time_style,
+            decimal_places,
+        };
+
+        // When
+        let formatted_time = format_laptime(83.456, time_format);
 
         // Then
         pretty_assertions::assert_eq!(expected_formatted_time, formatted_time);
     }
 
     #[test]
-    fn test_be_able_to_convert_laps_to_string_laps() {
+    fn test_format_laptime_leaves_no_time_blank() {
+        // Then
+        pretty_assertions::assert_eq!("", format_laptime(0.0, TimeFormat::default()));
+    }
+
+    #[rstest]
+    #[case(-0.5321, DecimalPlaces::Three, "-0.532")]
+    #[case(1.26, DecimalPlaces::One, "+1.3")]
+    #[case(0.0, DecimalPlaces::Two, "+0.00")]
+    #[case(-75.5, DecimalPlaces::Two, "-75.50")]
+    fn test_format_time_gap(
+        #[case] gap_in_seconds: f32,
+        #[case] decimal_places: DecimalPlaces,
+        #[case] expected_formatted_gap: &str,
+    ) {
         // Given
-        let laps = vec![
-            Lap::new(1, 120.6),
-            Lap::new(2, 120.7),
-            Lap::new(3, 120.8),
-            Lap::new(4, 120.9),
-        ];
-        let expected_string_laps = vec![
-            "2:00.60".to_string(),
-            "2:00.70".to_string(),
-            "2:00.80".to_string(),
-            "2:00.90".to_string(),
-        ];
+        let time_format = TimeFormat {
+            decimal_places,
+            ..Default::default()
+        };
 
         // When
-        let actual_laps = convert_laps_to_string_laps(laps);
+        let formatted_gap = format_time_gap(gap_in_seconds, time_format);
 
         // Then
-        pretty_assertions::assert_eq!(expected_string_laps, actual_laps);
+        pretty_assertions::assert_eq!(expected_formatted_gap, formatted_gap);
     }
 
     #[test]
@@ -1,2 +1,8 @@
+pub mod consistency_summary;
+pub mod data_repair;
+pub mod merge_profile;
+pub mod personal_bests;
 pub mod profile_statistics;
 pub mod sort_races;
+pub mod statistics_settings;
+pub mod undo_history;
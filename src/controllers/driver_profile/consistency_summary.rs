@@ -0,0 +1,97 @@
+use crate::models::driver::{
+    consistency_summary::TrackConsistency, session_information::race_result::RaceResult,
+};
+
+// Races without laps have no consistency so they're left out of the averages
+pub fn summarise_consistency(races: &[RaceResult]) -> Vec<TrackConsistency> {
+    let mut track_races: Vec<(String, Vec<&RaceResult>)> = vec![];
+
+    for race in races
+        .iter()
+        .filter(|race| race.race_statistics.number_of_laps > 0)
+    {
+        let track_name = race.race_information.track_name.trim();
+
+        match track_races
+            .iter_mut()
+            .find(|(name, _)| name.to_lowercase() == track_name.to_lowercase())
+        {
+            Some((_, races)) => races.push(race),
+            None => track_races.push((track_name.to_string(), vec![race])),
+        }
+    }
+
+    let mut track_consistency: Vec<TrackConsistency> = track_races
+        .into_iter()
+        .map(|(track_name, races)| summarise_track(track_name, &races))
+        .collect();
+
+    track_consistency.sort_by_key(|track| track.track_name.to_lowercase());
+
+    track_consistency
+}
+
+fn summarise_track(track_name: String, races: &[&RaceResult]) -> TrackConsistency {
+    let average = |statistic: fn(&RaceResult) -> f32| {
+        races.iter().map(|race| statistic(race)).sum::<f32>() / races.len() as f32
+    };
+
+    TrackConsistency {
+        track_name,
+        races: races.len() as u32,
+        standard_deviation: average(|race| race.race_statistics.consistency.standard_deviation),
+        coefficient_of_variation: average(|race| {
+            race.race_statistics.consistency.coefficient_of_variation
+        }),
+        interquartile_range: average(|race| race.race_statistics.consistency.interquartile_range),
+        laps_within_1_percent: average(|race| {
+            race.race_statistics.consistency.laps_within_1_percent
+        }),
+        laps_within_2_percent: average(|race| {
+            race.race_statistics.consistency.laps_within_2_percent
+        }),
+        longest_consistent_run: races
+            .iter()
+            .map(|race| race.race_statistics.consistency.longest_consistent_run)
+            .max()
+            .unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod consistency_summary_should {
+    use super::*;
+    use crate::models::driver::session_information::{lap::Lap, race_information::RaceInformation};
+
+    fn race(track_name: &str, laptimes: &[f32]) -> RaceResult {
+        RaceResult::new(
+            RaceInformation::new(track_name, Default::default(), Default::default()),
+            Default::default(),
+            laptimes
+                .iter()
+                .enumerate()
+                .map(|(index, time)| Lap::new(index as u32 + 1, *time))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_summarise_consistency_per_track() {
+        // Given
+        let races = vec![
+            race("Three Sisters", &[50.0, 50.0, 50.0, 50.0]),
+            race("Llandow", &[]),
+            race("three sisters", &[50.0, 50.0, 60.0, 50.0]),
+        ];
+
+        // When
+        let track_consistency = summarise_consistency(&races);
+
+        // Then
+        pretty_assertions::assert_eq!(1, track_consistency.len());
+        pretty_assertions::assert_eq!("Three Sisters", track_consistency[0].track_name);
+        pretty_assertions::assert_eq!(2, track_consistency[0].races);
+        pretty_assertions::assert_eq!(87.5, track_consistency[0].laps_within_1_percent);
+        pretty_assertions::assert_eq!(4, track_consistency[0].longest_consistent_run);
+    }
+}
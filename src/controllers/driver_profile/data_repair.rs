@@ -0,0 +1,394 @@
+use crate::{
+    controllers::converters::time_parser::format_laptime,
+    models::{
+        application::{
+            data_repair::{DataIssue, DataIssueKind, DataRepair},
+            karting_time::KartingTime,
+            time_format::TimeFormat,
+        },
+        date::RaceDate,
+        driver::session_information::{lap::Lap, race_result::RaceResult},
+    },
+};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+const OUTLANDISH_LAP_FACTOR: f32 = 3.0;
+const MAXIMUM_LAP_TIME: f32 = 3600.0;
+
+pub fn find_data_issues(races: &[RaceResult], time_format: TimeFormat) -> Vec<DataIssue> {
+    let mut issues = vec![];
+    let mut identifier_counts: HashMap<&str, usize> = HashMap::new();
+
+    for race in races {
+        let race_information = &race.race_information;
+
+        // Races sharing an identifier are told apart by the order they appear in
+        let identifier_count = identifier_counts
+            .entry(race_information.unique_race_identifier.as_str())
+            .or_default();
+        let race_occurrence = *identifier_count;
+        *identifier_count += 1;
+
+        let issue = |kind: DataIssueKind, lap_number: Option<u32>, problem: String| DataIssue {
+            race_identifier: race_information.unique_race_identifier.clone(),
+            race_occurrence,
+            lap_number,
+            kind,
+            description: format!(
+                "{} {} session {}: {}",
+                race_information.track_name,
+                race_information.date,
+                race_information.session.session_id,
+                problem
+            ),
+        };
+
+        if race_occurrence > 0 {
+            issues.push(issue(
+                DataIssueKind::DuplicateRace,
+                None,
+                "the race appears more than once in the profile".to_string(),
+            ));
+        }
+
+        if !is_possible_date(&race_information.date) {
+            issues.push(issue(
+                DataIssueKind::ImpossibleDate,
+                None,
+                "the date doesn't exist".to_string(),
+            ));
+        }
+
+        if race_information.session.session_id == 0 || race_information.session.race_position == 0 {
+            issues.push(issue(
+                DataIssueKind::MissingSession,
+                None,
+                "the session or finishing position is 0".to_string(),
+            ));
+        }
+
+        let median_time = find_median_time(&race.laptimes);
+
+        for lap in &race.laptimes {
+            if !is_valid_time(lap.time) {
+                issues.push(issue(
+                    DataIssueKind::InvalidLap,
+                    Some(lap.lap_number),
+                    format!("lap {} has no valid time", lap.lap_number),
+                ));
+            } else if lap.time > MAXIMUM_LAP_TIME
+                || median_time
+                    .is_some_and(|median_time| lap.time > median_time * OUTLANDISH_LAP_FACTOR)
+            {
+                issues.push(issue(
+                    DataIssueKind::OutlandishLap,
+                    Some(lap.lap_number),
+                    format!(
+                        "lap {} of {} is far slower than the rest of the race",
+                        lap.lap_number,
+                        format_laptime(lap.time, time_format)
+                    ),
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+fn is_possible_date(date: &RaceDate) -> bool {
+    date.year > 0 && NaiveDate::from_ymd_opt(date.year, date.month, date.day).is_some()
+}
+
+fn is_valid_time(time: f32) -> bool {
+    time.is_finite() && time > 0.0
+}
+
+fn find_median_time(laps: &[Lap]) -> Option<f32> {
+    let mut times: Vec<f32> = laps
+        .iter()
+        .map(|lap| lap.time)
+        .filter(|time| is_valid_time(*time))
+        .collect();
+
+    if times.is_empty() {
+        return None;
+    }
+
+    times.sort_by(|a, b| a.total_cmp(b));
+
+    Some(times[times.len() / 2])
+}
+
+// Out of range parts are moved to the nearest day that exists, a missing year is taken as 2000
+fn repair_date(date: &RaceDate) -> RaceDate {
+    let year = match date.year > 0 {
+        true => date.year,
+        false => RaceDate::default().year,
+    };
+    let month = date.month.clamp(1, 12);
+    let mut day = date.day.clamp(1, 31);
+
+    while NaiveDate::from_ymd_opt(year, month, day).is_none() {
+        day -= 1;
+    }
+
+    RaceDate::new(day, month, year)
+}
+
+fn remove_lap(race: &mut RaceResult, lap_number: u32) {
+    race.laptimes.retain(|lap| lap.lap_number != lap_number);
+
+    for (index, lap) in race.laptimes.iter_mut().enumerate() {
+        lap.lap_number = index as u32 + 1;
+    }
+}
+
+impl DataIssue {
+    fn is_same_issue(&self, other: &DataIssue) -> bool {
+        self.race_identifier == other.race_identifier
+            && self.race_occurrence == other.race_occurrence
+            && self.lap_number == other.lap_number
+            && self.kind == other.kind
+    }
+}
+
+impl DataRepair {
+    // Issues the user chose to keep aren't raised again until another profile is loaded
+    pub fn refresh(&mut self, races: &[RaceResult], time_format: TimeFormat) {
+        self.issues = find_data_issues(races, time_format)
+            .into_iter()
+            .filter(|issue| {
+                !self
+                    .kept_issues
+                    .iter()
+                    .any(|kept_issue| kept_issue.is_same_issue(issue))
+            })
+            .collect();
+
+        if self.issues.is_empty() {
+            self.is_visible = false;
+        }
+    }
+
+    pub fn keep(&mut self, index: usize) {
+        if index < self.issues.len() {
+            let issue = self.issues.remove(index);
+            self.kept_issues.push(issue);
+        }
+
+        if self.issues.is_empty() {
+            self.is_visible = false;
+        }
+    }
+
+    pub fn keep_all(&mut self) {
+        self.kept_issues.append(&mut self.issues);
+        self.is_visible = false;
+    }
+}
+
+impl KartingTime {
+    pub fn check_data_issues(&mut self) {
+        let time_format = self.application_state.time_format;
+        let data_repair = &mut self.application_state.data_repair;
+
+        data_repair.refresh(&self.driver_profile.races, time_format);
+        data_repair.is_visible = !data_repair.issues.is_empty();
+    }
+
+    pub fn repair_data_issue(&mut self, index: usize) {
+        let Some(issue) = self
+            .application_state
+            .data_repair
+            .issues
+            .get(index)
+            .cloned()
+        else {
+            return;
+        };
+
+        let race_index = self
+            .driver_profile
+            .races
+            .iter()
+            .enumerate()
+            .filter(|(_, race)| {
+                race.race_information.unique_race_identifier == issue.race_identifier
+            })
+            .map(|(race_index, _)| race_index)
+            .nth(issue.race_occurrence);
+
+        if let Some(race_index) = race_index {
+            self.repair_race(race_index, &issue);
+        }
+
+        self.driver_profile.sort_races();
+        self.application_state.data_repair.refresh(
+            &self.driver_profile.races,
+            self.application_state.time_format,
+        );
+    }
+
+    fn repair_race(&mut self, race_index: usize, issue: &DataIssue) {
+        if issue.kind == DataIssueKind::DuplicateRace {
+            self.driver_profile.races.remove(race_index);
+            return;
+        }
+
+        let race = &mut self.driver_profile.races[race_index];
+
+        match issue.kind {
+            DataIssueKind::ImpossibleDate => {
+                race.race_information.date = repair_date(&race.race_information.date);
+            }
+            DataIssueKind::MissingSession => {
+                let session = &mut race.race_information.session;
+                session.session_id = session.session_id.max(1);
+                session.race_position = session.race_position.max(1);
+            }
+            _ => {
+                if let Some(lap_number) = issue.lap_number {
+                    remove_lap(race, lap_number);
+                }
+            }
+        }
+
+        race.race_information.update_unique_identifier();
+        race.update_race_result();
+    }
+
+    // Each repair can move or remove races, so the issues are found again after every one
+    pub fn repair_all_data_issues(&mut self) {
+        while !self.application_state.data_repair.issues.is_empty() {
+            self.repair_data_issue(0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod data_repair_should {
+    use super::*;
+    use crate::{
+        data_models::race_result_file::RaceResultFile,
+        models::driver::{
+            driver_profile::DriverProfile,
+            session_information::{race_metadata::RaceMetadata, session::Session},
+        },
+    };
+
+    fn race(laptimes: Vec<&str>, session: Session, date: RaceDate) -> RaceResult {
+        RaceResultFile::new(
+            "Three Sisters",
+            laptimes.into_iter().map(|lap| lap.to_string()).collect(),
+            RaceMetadata::default(),
+            session,
+            date,
+        )
+        .convert_to_race_result()
+    }
+
+    #[test]
+    fn test_find_each_kind_of_issue() {
+        // Given
+        let races = vec![
+            race(
+                vec!["50.4", "abc", "-2.0", "51.2", "3:10.0"],
+                Session::new(1, 1),
+                RaceDate::new(12, 12, 2025),
+            ),
+            race(
+                vec!["50.4"],
+                Session::new(1, 1),
+                RaceDate::new(12, 12, 2025),
+            ),
+            race(vec!["50.4"], Session::new(2, 0), RaceDate::new(30, 2, 2025)),
+        ];
+
+        // When
+        let issues = find_data_issues(&races, TimeFormat::default());
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![
+                "Three Sisters 2025-12-12 session 1: lap 2 has no valid time",
+                "Three Sisters 2025-12-12 session 1: lap 3 has no valid time",
+                "Three Sisters 2025-12-12 session 1: lap 5 of 3:10.000 is far slower than the rest of the race",
+                "Three Sisters 2025-12-12 session 1: the race appears more than once in the profile",
+                "Three Sisters 2025-2-30 session 2: the date doesn't exist",
+                "Three Sisters 2025-2-30 session 2: the session or finishing position is 0",
+            ],
+            issues
+                .iter()
+                .map(|issue| issue.description.as_str())
+                .collect::<Vec<_>>()
+        );
+        pretty_assertions::assert_eq!(1, issues[3].race_occurrence);
+    }
+
+    #[test]
+    fn test_repair_all_data_issues() {
+        // Given
+        let mut karting_time = KartingTime::new(DriverProfile::new(
+            "Jack Jackson",
+            vec![
+                race(
+                    vec!["50.4", "0", "51.2"],
+                    Session::new(0, 0),
+                    RaceDate::new(0, 0, 0),
+                ),
+                race(vec!["50.4"], Session::new(1, 1), RaceDate::new(31, 4, 2025)),
+                race(vec!["50.4"], Session::new(1, 1), RaceDate::new(31, 4, 2025)),
+            ],
+        ));
+
+        // When
+        karting_time.check_data_issues();
+        let is_visible = karting_time.application_state.data_repair.is_visible;
+        karting_time.repair_all_data_issues();
+
+        // Then
+        let races = &karting_time.driver_profile.races;
+        assert!(is_visible);
+        assert!(!karting_time.application_state.data_repair.is_visible);
+        assert!(find_data_issues(races, TimeFormat::default()).is_empty());
+        pretty_assertions::assert_eq!(2, races.len());
+        pretty_assertions::assert_eq!(RaceDate::new(30, 4, 2025), races[0].race_information.date);
+        pretty_assertions::assert_eq!(RaceDate::new(1, 1, 2000), races[1].race_information.date);
+        pretty_assertions::assert_eq!(Session::new(1, 1), races[1].race_information.session);
+        pretty_assertions::assert_eq!(
+            vec![(1, 50.4), (2, 51.2)],
+            races[1]
+                .laptimes
+                .iter()
+                .map(|lap| (lap.lap_number, lap.time))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_kept_issue_is_not_raised_again() {
+        // Given
+        let mut karting_time = KartingTime::new(DriverProfile::new(
+            "Jack Jackson",
+            vec![race(
+                vec!["50.4", "51.2", "5:00.0"],
+                Session::new(1, 1),
+                RaceDate::new(12, 12, 2025),
+            )],
+        ));
+
+        // When
+        karting_time.check_data_issues();
+        karting_time.application_state.data_repair.keep(0);
+        karting_time.check_data_issues();
+
+        // Then
+        let data_repair = &karting_time.application_state.data_repair;
+        assert!(!data_repair.is_visible);
+        assert!(data_repair.issues.is_empty());
+        pretty_assertions::assert_eq!(1, data_repair.kept_issues.len());
+        pretty_assertions::assert_eq!(3, karting_time.driver_profile.races[0].laptimes.len());
+    }
+}
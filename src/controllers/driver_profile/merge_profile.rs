@@ -0,0 +1,224 @@
+use crate::models::{
+    application::profile_merge::{MergeChoice, ProfileMerge, RaceMerge, RaceMergeStatus},
+    driver::session_information::race_result::RaceResult,
+};
+
+impl ProfileMerge {
+    pub fn open(
+        &mut self,
+        file_path: &str,
+        driver_name: &str,
+        current_races: &[RaceResult],
+        incoming_races: &[RaceResult],
+    ) {
+        let mut race_merges = vec![];
+
+        for incoming_race in incoming_races {
+            let current_race = current_races.iter().find(|race| {
+                race.race_information.unique_race_identifier
+                    == incoming_race.race_information.unique_race_identifier
+            });
+
+            let Some(status) = compare_races(current_race, incoming_race) else {
+                continue;
+            };
+
+            race_merges.push(RaceMerge {
+                status,
+                current: current_race.cloned(),
+                incoming: incoming_race.clone(),
+                choice: match status {
+                    RaceMergeStatus::New | RaceMergeStatus::Changed => MergeChoice::Incoming,
+                    RaceMergeStatus::Conflict => MergeChoice::Current,
+                },
+            });
+        }
+
+        *self = Self {
+            file_path: Some(file_path.to_string()),
+            driver_name: driver_name.to_string(),
+            race_merges,
+        };
+    }
+
+    pub fn close(&mut self) {
+        *self = Default::default();
+    }
+
+    pub fn select_choice(&mut self, identifier: &str, choice: MergeChoice) {
+        if let Some(race_merge) = self.race_merges.iter_mut().find(|race_merge| {
+            race_merge.incoming.race_information.unique_race_identifier == identifier
+        }) {
+            race_merge.choice = choice;
+        }
+    }
+
+    pub fn merge_races(&self, current_races: &[RaceResult]) -> Vec<RaceResult> {
+        let mut races = current_races.to_vec();
+
+        for race_merge in &self.race_merges {
+            if race_merge.choice == MergeChoice::Current {
+                continue;
+            }
+
+            let mut incoming_race = race_merge.incoming.clone();
+            incoming_race.is_deleting = false;
+
+            match races.iter_mut().find(|race| {
+                race.race_information.unique_race_identifier
+                    == incoming_race.race_information.unique_race_identifier
+            }) {
+                Some(race) => *race = incoming_race,
+                None => races.push(incoming_race),
+            }
+        }
+
+        races
+    }
+}
+
+fn compare_races(
+    current_race: Option<&RaceResult>,
+    incoming_race: &RaceResult,
+) -> Option<RaceMergeStatus> {
+    let Some(current_race) = current_race else {
+        return Some(RaceMergeStatus::New);
+    };
+
+    if current_race.convert_to_race_file() == incoming_race.convert_to_race_file() {
+        return None;
+    }
+
+    match current_race.laptimes == incoming_race.laptimes {
+        true => Some(RaceMergeStatus::Changed),
+        false => Some(RaceMergeStatus::Conflict),
+    }
+}
+
+#[cfg(test)]
+mod merge_profile_should {
+    use super::*;
+    use crate::models::{
+        date::RaceDate,
+        driver::session_information::{
+            lap::Lap, race_information::RaceInformation, race_metadata::RaceMetadata,
+            session::Session,
+        },
+    };
+
+    fn race(session_id: u32, notes: &str, laptimes: Vec<Lap>) -> RaceResult {
+        RaceResult::new(
+            RaceInformation::new(
+                "Three Sisters",
+                RaceDate::new(12, 12, 2025),
+                Session::new(session_id, 1),
+            ),
+            RaceMetadata::new(
+                Default::default(),
+                Default::default(),
+                "Kart",
+                Default::default(),
+                notes,
+            ),
+            laptimes,
+        )
+    }
+
+    #[test]
+    fn test_open_profile_merge() {
+        // Given
+        let current_races = vec![
+            race(1, "", vec![Lap::new(1, 50.1)]),
+            race(2, "", vec![Lap::new(1, 51.2)]),
+            race(3, "", vec![Lap::new(1, 52.3)]),
+            race(4, "", vec![Lap::new(1, 53.4)]),
+        ];
+        let incoming_races = vec![
+            race(1, "", vec![Lap::new(1, 50.1)]),
+            race(2, "Wet", vec![Lap::new(1, 51.2)]),
+            race(3, "", vec![Lap::new(1, 52.3), Lap::new(2, 52.4)]),
+            race(5, "", vec![Lap::new(1, 54.5)]),
+        ];
+        let mut profile_merge = ProfileMerge::default();
+
+        // When
+        profile_merge.open("laptop.toml", "Jack", &current_races, &incoming_races);
+
+        // Then
+        pretty_assertions::assert_eq!(Some("laptop.toml".to_string()), profile_merge.file_path);
+        pretty_assertions::assert_eq!(
+            vec![
+                (RaceMergeStatus::Changed, MergeChoice::Incoming),
+                (RaceMergeStatus::Conflict, MergeChoice::Current),
+                (RaceMergeStatus::New, MergeChoice::Incoming),
+            ],
+            profile_merge
+                .race_merges
+                .iter()
+                .map(|race_merge| (race_merge.status, race_merge.choice))
+                .collect::<Vec<_>>()
+        );
+        pretty_assertions::assert_eq!(
+            Some(current_races[2].clone()),
+            profile_merge.race_merges[1].current
+        );
+        pretty_assertions::assert_eq!(None, profile_merge.race_merges[2].current);
+    }
+
+    #[test]
+    fn test_merge_races() {
+        // Given
+        let current_races = vec![
+            race(2, "", vec![Lap::new(1, 51.2)]),
+            race(3, "", vec![Lap::new(1, 52.3)]),
+            race(4, "", vec![Lap::new(1, 53.4)]),
+        ];
+        let incoming_races = vec![
+            race(2, "Wet", vec![Lap::new(1, 51.2)]),
+            race(3, "", vec![Lap::new(1, 52.3), Lap::new(2, 52.4)]),
+            race(5, "", vec![Lap::new(1, 54.5)]),
+            race(6, "", vec![Lap::new(1, 55.6)]),
+        ];
+        let mut profile_merge = ProfileMerge::default();
+        profile_merge.open("laptop.toml", "Jack", &current_races, &incoming_races);
+
+        // When
+        profile_merge.select_choice(
+            &incoming_races[0].race_information.unique_race_identifier,
+            MergeChoice::Current,
+        );
+        profile_merge.select_choice(
+            &incoming_races[1].race_information.unique_race_identifier,
+            MergeChoice::Incoming,
+        );
+        profile_merge.select_choice(
+            &incoming_races[3].race_information.unique_race_identifier,
+            MergeChoice::Current,
+        );
+        let races = profile_merge.merge_races(&current_races);
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![
+                current_races[0].clone(),
+                incoming_races[1].clone(),
+                current_races[2].clone(),
+                incoming_races[2].clone(),
+            ],
+            races
+        );
+    }
+
+    #[test]
+    fn test_close_profile_merge() {
+        // Given
+        let mut profile_merge = ProfileMerge::default();
+        profile_merge.open("laptop.toml", "Jack", &[], &[race(1, "", vec![])]);
+
+        // When
+        profile_merge.close();
+
+        // Then
+        pretty_assertions::assert_eq!(ProfileMerge::default(), profile_merge);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::models::driver::{
+    personal_bests::{PersonalBest, PersonalBestCategory, TrackPersonalBests},
+    session_information::race_result::RaceResult,
+};
+
+// Races are replayed in the order they were run so the progression shows each record as it fell
+pub fn find_personal_bests(races: &[RaceResult]) -> Vec<TrackPersonalBests> {
+    let mut chronological_races: Vec<&RaceResult> = races.iter().collect();
+    chronological_races.sort_by(|a, b| {
+        a.race_information.date.cmp(&b.race_information.date).then(
+            a.race_information
+                .session
+                .session_id
+                .cmp(&b.race_information.session.session_id),
+        )
+    });
+
+    let mut track_personal_bests: Vec<TrackPersonalBests> = vec![];
+
+    for race in chronological_races {
+        let track_name = race.race_information.track_name.trim();
+        let car_used = race.race_metadata.car_used.trim();
+
+        let track_index = match track_personal_bests
+            .iter()
+            .position(|track| track.is_same_track_and_car(track_name, car_used))
+        {
+            Some(track_index) => track_index,
+            None => {
+                track_personal_bests.push(TrackPersonalBests {
+                    track_name: track_name.to_string(),
+                    car_used: car_used.to_string(),
+                    ..Default::default()
+                });
+                track_personal_bests.len() - 1
+            }
+        };
+
+        for (category, time) in race_times(race) {
+            track_personal_bests[track_index].record(category, time, race);
+        }
+    }
+
+    track_personal_bests.retain(|track| !track.personal_bests.is_empty());
+    track_personal_bests.sort_by_key(|track| {
+        (
+            track.track_name.to_lowercase(),
+            track.car_used.to_lowercase(),
+        )
+    });
+
+    track_personal_bests
+}
+
+fn race_times(race: &RaceResult) -> Vec<(PersonalBestCategory, f32)> {
+    let race_statistics = &race.race_statistics;
+
+    let mut times = vec![
+        (
+            PersonalBestCategory::FastestLap,
+            race_statistics.fastest_lap,
+        ),
+        (
+            PersonalBestCategory::AverageLap(race.statistics_windows.cutoff_percentage()),
+            race_statistics.average_105_lap,
+        ),
+    ];
+
+    for number_of_laps in race.statistics_windows.summary_lap_counts() {
+        if let Some((_, total_time)) = race_statistics
+            .total_times_table
+            .iter()
+            .find(|(lap_number, _)| *lap_number == number_of_laps)
+        {
+            times.push((PersonalBestCategory::TotalLap(number_of_laps), *total_time));
+        }
+    }
+
+    times.into_iter().filter(|(_, time)| *time > 0.0).collect()
+}
+
+impl TrackPersonalBests {
+    fn is_same_track_and_car(&self, track_name: &str, car_used: &str) -> bool {
+        self.track_name.to_lowercase() == track_name.to_lowercase()
+            && self.car_used.to_lowercase() == car_used.to_lowercase()
+    }
+
+    fn record(&mut self, category: PersonalBestCategory, time: f32, race: &RaceResult) {
+        let current_index = self
+            .personal_bests
+            .iter()
+            .position(|personal_best| personal_best.category == category);
+
+        let improvement = match current_index {
+            Some(current_index) => {
+                let current_time = self.personal_bests[current_index].time;
+
+                if time >= current_time {
+                    return;
+                }
+
+                Some(time - current_time)
+            }
+            None => None,
+        };
+
+        let personal_best = PersonalBest {
+            category,
+            time,
+            improvement,
+            unique_race_identifier: race.race_information.unique_race_identifier.clone(),
+            date: race.race_information.date.clone(),
+        };
+
+        match current_index {
+            Some(current_index) => self.personal_bests[current_index] = personal_best.clone(),
+            None => {
+                self.personal_bests.push(personal_best.clone());
+                self.personal_bests
+                    .sort_by_key(|personal_best| personal_best.category);
+            }
+        }
+
+        self.progression.push(personal_best);
+    }
+}
+
+#[cfg(test)]
+mod personal_bests_should {
+    use super::*;
+    use crate::models::{
+        date::RaceDate,
+        driver::{
+            personal_bests::PersonalBests,
+            session_information::{
+                lap::Lap, race_information::RaceInformation, race_metadata::RaceMetadata,
+                session::Session,
+            },
+        },
+    };
+
+    fn race(track_name: &str, car_used: &str, date: RaceDate, laptimes: &[f32]) -> RaceResult {
+        RaceResult::new(
+            RaceInformation::new(track_name, date, Session::new(1, 1)),
+            RaceMetadata::new("Race", "Dry", car_used, "", ""),
+            laptimes
+                .iter()
+                .enumerate()
+                .map(|(index, time)| Lap::new(index as u32 + 1, *time))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_find_personal_bests_per_track_and_car() {
+        // Given
+        let races = vec![
+            race("Llandow", "Rotax Max", RaceDate::new(1, 2, 2025), &[50.0]),
+            race(
+                "Three Sisters",
+                "Rotax Max",
+                RaceDate::new(1, 1, 2025),
+                &[60.0],
+            ),
+            race("llandow ", "Rotax Max", RaceDate::new(1, 3, 2025), &[49.0]),
+            race("Llandow", "X30", RaceDate::new(1, 4, 2025), &[48.0]),
+        ];
+
+        // When
+        let track_personal_bests = find_personal_bests(&races);
+
+        // Then
+        let tracks: Vec<(&str, &str, f32)> = track_personal_bests
+            .iter()
+            .map(|track| {
+                (
+                    track.track_name.as_str(),
+                    track.car_used.as_str(),
+                    track.personal_bests[0].time,
+                )
+            })
+            .collect();
+
+        pretty_assertions::assert_eq!(
+            vec![
+                ("Llandow", "Rotax Max", 49.0),
+                ("Llandow", "X30", 48.0),
+                ("Three Sisters", "Rotax Max", 60.0),
+            ],
+            tracks
+        );
+    }
+
+    #[test]
+    fn test_find_personal_best_progression() {
+        // Given
+        let first_race = race("Llandow", "Rotax Max", RaceDate::new(1, 1, 2025), &[50.0]);
+        let slower_race = race("Llandow", "Rotax Max", RaceDate::new(1, 2, 2025), &[51.0]);
+        let faster_race = race("Llandow", "Rotax Max", RaceDate::new(1, 3, 2025), &[49.5]);
+        let races = vec![faster_race.clone(), slower_race, first_race.clone()];
+
+        // When
+        let track_personal_bests = find_personal_bests(&races);
+
+        // Then
+        let progression: Vec<(PersonalBestCategory, f32, Option<f32>, String)> =
+            track_personal_bests[0]
+                .progression
+                .iter()
+                .map(|personal_best| {
+                    (
+                        personal_best.category,
+                        personal_best.time,
+                        personal_best.improvement,
+                        personal_best.unique_race_identifier.clone(),
+                    )
+                })
+                .collect();
+
+        pretty_assertions::assert_eq!(
+            vec![
+                (
+                    PersonalBestCategory::FastestLap,
+                    50.0,
+                    None,
+                    first_race.race_information.unique_race_identifier.clone()
+                ),
+                (
+                    PersonalBestCategory::AverageLap(105),
+                    50.0,
+                    None,
+                    first_race.race_information.unique_race_identifier.clone()
+                ),
+                (
+                    PersonalBestCategory::FastestLap,
+                    49.5,
+                    Some(-0.5),
+                    faster_race.race_information.unique_race_identifier.clone()
+                ),
+                (
+                    PersonalBestCategory::AverageLap(105),
+                    49.5,
+                    Some(-0.5),
+                    faster_race.race_information.unique_race_identifier.clone()
+                ),
+            ],
+            progression
+        );
+    }
+
+    #[test]
+    fn test_find_personal_best_totals() {
+        // Given
+        let races = vec![race(
+            "Llandow",
+            "Rotax Max",
+            RaceDate::new(1, 1, 2025),
+            &[50.0; 12],
+        )];
+
+        // When
+        let track_personal_bests = find_personal_bests(&races);
+
+        // Then
+        let categories: Vec<(PersonalBestCategory, f32)> = track_personal_bests[0]
+            .personal_bests
+            .iter()
+            .map(|personal_best| (personal_best.category, personal_best.time))
+            .collect();
+
+        pretty_assertions::assert_eq!(
+            vec![
+                (PersonalBestCategory::FastestLap, 50.0),
+                (PersonalBestCategory::AverageLap(105), 50.0),
+                (PersonalBestCategory::TotalLap(5), 250.0),
+                (PersonalBestCategory::TotalLap(10), 500.0),
+            ],
+            categories
+        );
+    }
+
+    #[test]
+    fn test_badge_races_that_set_a_personal_best() {
+        // Given
+        let first_race = race("Llandow", "Rotax Max", RaceDate::new(1, 1, 2025), &[50.0]);
+        let slower_race = race("Llandow", "Rotax Max", RaceDate::new(1, 2, 2025), &[51.0]);
+        let personal_bests = PersonalBests::new(&[first_race.clone(), slower_race.clone()]);
+
+        // Then
+        pretty_assertions::assert_eq!(
+            Some("Personal Best: Fastest Lap, Average Lap (105%)".to_string()),
+            personal_bests.badge(&first_race.race_information.unique_race_identifier)
+        );
+        pretty_assertions::assert_eq!(
+            None,
+            personal_bests.badge(&slower_race.race_information.unique_race_identifier)
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{
+    models::driver::{
+        driver_profile::DriverProfile,
+        session_information::race_result::RaceResult,
+        statistics_settings::{SessionTypeWindows, StatisticsSettings, StatisticsWindows},
+    },
+    views::application::input_parser::parse_input_u32,
+};
+
+const SUMMARY_BUCKETS: usize = 3;
+const MAXIMUM_BUCKET_SIZE: u32 = 1000;
+const MAXIMUM_AVERAGE_CUTOFF_PERCENTAGE: u32 = 1000;
+
+impl StatisticsWindows {
+    // A bucket can't be empty and a cutoff below 100% would leave out even the fastest lap, the
+    // entered values are kept as typed so they can be edited a digit at a time
+    pub fn laps_per_bucket(&self) -> usize {
+        self.bucket_size.max(1) as usize
+    }
+
+    pub fn cutoff_percentage(&self) -> u32 {
+        self.average_cutoff_percentage.max(100)
+    }
+
+    // The first few buckets are the ones shown in the overview and exports
+    pub fn summary_lap_counts(&self) -> Vec<usize> {
+        (1..=SUMMARY_BUCKETS)
+            .map(|bucket| bucket * self.laps_per_bucket())
+            .collect()
+    }
+
+    pub fn set_bucket_size(&mut self, bucket_size: String) {
+        self.bucket_size = parse_input_u32(bucket_size, 1, MAXIMUM_BUCKET_SIZE);
+    }
+
+    pub fn set_average_cutoff_percentage(&mut self, average_cutoff_percentage: String) {
+        self.average_cutoff_percentage = parse_input_u32(
+            average_cutoff_percentage,
+            100,
+            MAXIMUM_AVERAGE_CUTOFF_PERCENTAGE,
+        );
+    }
+}
+
+impl StatisticsSettings {
+    pub fn is_default(&self) -> bool {
+        *self == StatisticsSettings::default()
+    }
+
+    pub fn windows_for(&self, session_type: &str) -> StatisticsWindows {
+        self.session_type_windows
+            .iter()
+            .find(|session_type_windows| {
+                let windows_session_type = session_type_windows.session_type.trim();
+
+                !windows_session_type.is_empty()
+                    && windows_session_type.to_lowercase() == session_type.trim().to_lowercase()
+            })
+            .map(|session_type_windows| session_type_windows.statistics_windows)
+            .unwrap_or(self.statistics_windows)
+    }
+
+    // Every session type's buckets get a column so each race can fill in its own
+    pub fn summary_lap_counts(&self) -> Vec<usize> {
+        let mut lap_counts: Vec<usize> = std::iter::once(&self.statistics_windows)
+            .chain(
+                self.session_type_windows
+                    .iter()
+                    .map(|session_type_windows| &session_type_windows.statistics_windows),
+            )
+            .flat_map(|statistics_windows| statistics_windows.summary_lap_counts())
+            .collect();
+
+        lap_counts.sort();
+        lap_counts.dedup();
+
+        lap_counts
+    }
+
+    // Races are only recalculated when their windows change
+    pub fn apply_to_races(&self, races: &mut [RaceResult]) {
+        for race in races {
+            let statistics_windows = self.windows_for(&race.race_metadata.session_type);
+
+            if race.statistics_windows != statistics_windows {
+                race.statistics_windows = statistics_windows;
+                race.update_race_result();
+            }
+        }
+    }
+
+    pub fn add_session_type_windows(&mut self) {
+        self.session_type_windows.push(SessionTypeWindows {
+            session_type: Default::default(),
+            statistics_windows: self.statistics_windows,
+        });
+    }
+
+    pub fn remove_session_type_windows(&mut self, index: usize) {
+        if index < self.session_type_windows.len() {
+            self.session_type_windows.remove(index);
+        }
+    }
+}
+
+impl DriverProfile {
+    pub fn apply_statistics_settings(&mut self) {
+        self.statistics_settings.apply_to_races(&mut self.races);
+    }
+}
+
+#[cfg(test)]
+mod statistics_settings_should {
+    use super::*;
+    use crate::models::driver::session_information::{
+        lap::Lap, race_information::RaceInformation, race_metadata::RaceMetadata,
+    };
+    use rstest::rstest;
+
+    fn statistics_settings() -> StatisticsSettings {
+        StatisticsSettings {
+            statistics_windows: StatisticsWindows::default(),
+            session_type_windows: vec![SessionTypeWindows {
+                session_type: "Sprint".to_string(),
+                statistics_windows: StatisticsWindows {
+                    bucket_size: 2,
+                    average_cutoff_percentage: 107,
+                },
+            }],
+        }
+    }
+
+    #[rstest]
+    #[case("Sprint", 2)]
+    #[case(" sprint ", 2)]
+    #[case("Race", 5)]
+    #[case("N/A", 5)]
+    fn test_windows_for_session_type(#[case] session_type: &str, #[case] bucket_size: u32) {
+        // When
+        let statistics_windows = statistics_settings().windows_for(session_type);
+
+        // Then
+        pretty_assertions::assert_eq!(bucket_size, statistics_windows.bucket_size);
+    }
+
+    #[test]
+    fn test_summary_lap_counts() {
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![2, 4, 5, 6, 10, 15],
+            statistics_settings().summary_lap_counts()
+        );
+    }
+
+    #[test]
+    fn test_apply_to_races() {
+        // Given
+        let mut races = vec![RaceResult::new(
+            RaceInformation::default(),
+            RaceMetadata::new("Sprint", "Dry", "", "", ""),
+            vec![
+                Lap::new(1, 50.0),
+                Lap::new(2, 53.0),
+                Lap::new(3, 51.0),
+                Lap::new(4, 52.0),
+            ],
+        )];
+
+        // When
+        statistics_settings().apply_to_races(&mut races);
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![(2, 101.0), (4, 206.0)],
+            races[0].race_statistics.total_times_table
+        );
+        pretty_assertions::assert_eq!(51.5, races[0].race_statistics.average_105_lap);
+    }
+
+    #[rstest]
+    #[case("8", "107", 8, 107)]
+    #[case("0", "50", 1, 100)]
+    #[case("Eight", "", 1, 100)]
+    fn test_set_statistics_windows(
+        #[case] bucket_size: &str,
+        #[case] average_cutoff_percentage: &str,
+        #[case] expected_laps_per_bucket: usize,
+        #[case] expected_cutoff_percentage: u32,
+    ) {
+        // Given
+        let mut statistics_windows = StatisticsWindows::default();
+
+        // When
+        statistics_windows.set_bucket_size(bucket_size.to_string());
+        statistics_windows.set_average_cutoff_percentage(average_cutoff_percentage.to_string());
+
+        // Then
+        pretty_assertions::assert_eq!(
+            expected_laps_per_bucket,
+            statistics_windows.laps_per_bucket()
+        );
+        pretty_assertions::assert_eq!(
+            expected_cutoff_percentage,
+            statistics_windows.cutoff_percentage()
+        );
+    }
+}
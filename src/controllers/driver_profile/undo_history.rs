@@ -0,0 +1,364 @@
+use crate::models::application::{
+    karting_time::KartingTime,
+    undo_history::{ProfileSnapshot, TypedEdit, UndoHistory},
+};
+
+const UNDO_LIMIT: usize = 50;
+
+impl UndoHistory {
+    pub fn record(&mut self, snapshot: ProfileSnapshot, typed_edit: Option<TypedEdit>) {
+        self.redo_snapshots.clear();
+
+        if typed_edit.is_none() || typed_edit != self.typed_edit {
+            self.undo_snapshots.push(snapshot);
+        }
+
+        if self.undo_snapshots.len() > UNDO_LIMIT {
+            self.undo_snapshots.remove(0);
+        }
+
+        self.typed_edit = typed_edit;
+    }
+
+    pub fn undo(&mut self, current: ProfileSnapshot) -> Option<ProfileSnapshot> {
+        let snapshot = self.undo_snapshots.pop()?;
+
+        self.redo_snapshots.push(current);
+        self.typed_edit = None;
+
+        Some(snapshot)
+    }
+
+    pub fn redo(&mut self, current: ProfileSnapshot) -> Option<ProfileSnapshot> {
+        let snapshot = self.redo_snapshots.pop()?;
+
+        self.undo_snapshots.push(current);
+        self.typed_edit = None;
+
+        Some(snapshot)
+    }
+}
+
+impl KartingTime {
+    pub fn profile_snapshot(&self) -> ProfileSnapshot {
+        ProfileSnapshot {
+            races: Some(self.driver_profile.races.clone()),
+            ..self.typed_edit_snapshot()
+        }
+    }
+
+    pub fn typed_edit_snapshot(&self) -> ProfileSnapshot {
+        ProfileSnapshot {
+            name: self.driver_profile.name.clone(),
+            statistics_settings: self.driver_profile.statistics_settings.clone(),
+            races: None,
+        }
+    }
+
+    pub fn record_profile_edit(
+        &mut self,
+        snapshot: ProfileSnapshot,
+        typed_edit: Option<TypedEdit>,
+    ) {
+        if snapshot == self.current_snapshot(&snapshot) {
+            return;
+        }
+
+        self.application_state
+            .undo_history
+            .record(snapshot, typed_edit);
+    }
+
+    pub fn undo(&mut self) {
+        let Some(snapshot) = self.application_state.undo_history.undo_snapshots.last() else {
+            return;
+        };
+        let current = self.current_snapshot(snapshot);
+
+        if let Some(snapshot) = self.application_state.undo_history.undo(current) {
+            self.restore_profile_snapshot(snapshot);
+        }
+    }
+
+    pub fn redo(&mut self) {
+        let Some(snapshot) = self.application_state.undo_history.redo_snapshots.last() else {
+            return;
+        };
+        let current = self.current_snapshot(snapshot);
+
+        if let Some(snapshot) = self.application_state.undo_history.redo(current) {
+            self.restore_profile_snapshot(snapshot);
+        }
+    }
+
+    // Taken with the same parts as the snapshot it's compared with or swapped for
+    fn current_snapshot(&self, snapshot: &ProfileSnapshot) -> ProfileSnapshot {
+        match snapshot.races {
+            Some(_) => self.profile_snapshot(),
+            None => self.typed_edit_snapshot(),
+        }
+    }
+
+    fn restore_profile_snapshot(&mut self, snapshot: ProfileSnapshot) {
+        self.driver_profile.name = snapshot.name;
+        self.driver_profile.statistics_settings = snapshot.statistics_settings;
+
+        if let Some(races) = snapshot.races {
+            self.driver_profile.races = races;
+        }
+
+        for race in self.driver_profile.races.iter_mut() {
+            race.is_deleting = false;
+        }
+
+        self.driver_profile.sort_races();
+        self.update_races();
+    }
+}
+
+#[cfg(test)]
+mod undo_history_should {
+    use crate::{
+        commands::messages::Message,
+        controllers::{
+            file::test_file_guard::TestFileGuard,
+            telemetry::test_packets::{f1_event, f1_lap_data, f1_session},
+        },
+        models::{
+            application::{karting_time::KartingTime, telemetry::TelemetryGame},
+            date::RaceDate,
+            driver::{
+                driver_profile::DriverProfile,
+                session_information::{
+                    lap::Lap, race_information::RaceInformation, race_metadata::RaceMetadata,
+                    race_result::RaceResult, session::Session,
+                },
+            },
+        },
+    };
+    use std::fs;
+
+    fn races() -> Vec<RaceResult> {
+        (1..=3)
+            .map(|session_id| {
+                RaceResult::new(
+                    RaceInformation::new(
+                        "Three Sisters",
+                        RaceDate::new(12, 12, 2025),
+                        Session::new(session_id, session_id),
+                    ),
+                    RaceMetadata::default(),
+                    vec![Lap::new(1, 50.4)],
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_undo_then_redo_delete() {
+        // Given
+        let mut karting_time = KartingTime::new(DriverProfile::new("Jack Jackson", races()));
+        let identifier = karting_time.driver_profile.races[0]
+            .race_information
+            .unique_race_identifier
+            .clone();
+        let expected_races = karting_time.driver_profile.races.clone();
+
+        // When
+        let _ = karting_time.update(Message::DeletePressed(identifier.clone()));
+        let _ = karting_time.update(Message::DeleteConfirmedPressed(identifier));
+        let deleted_races = karting_time.driver_profile.races.clone();
+        let _ = karting_time.update(Message::Undo);
+        let undone_races = karting_time.driver_profile.races.clone();
+        let _ = karting_time.update(Message::Redo);
+
+        // Then
+        pretty_assertions::assert_eq!(expected_races, undone_races);
+        pretty_assertions::assert_eq!(deleted_races, karting_time.driver_profile.races);
+        pretty_assertions::assert_eq!(2, karting_time.driver_profile.races.len());
+    }
+
+    #[test]
+    fn test_undo_name_edits_as_one_step() {
+        // Given
+        let mut karting_time = KartingTime::new(DriverProfile::new("Jack", races()));
+
+        // When
+        let _ = karting_time.update(Message::DriverNameChanged("Jack ".to_string()));
+        let _ = karting_time.update(Message::DriverNameChanged("Jack J".to_string()));
+        let _ = karting_time.update(Message::Undo);
+
+        // Then
+        pretty_assertions::assert_eq!("Jack", karting_time.driver_profile.name);
+        assert!(
+            karting_time
+                .application_state
+                .undo_history
+                .undo_snapshots
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_undo_name_edit_without_copying_races() {
+        // Given
+        let mut karting_time = KartingTime::new(DriverProfile::new("Jack", races()));
+
+        // When
+        let _ = karting_time.update(Message::DriverNameChanged("Jack J".to_string()));
+
+        // Then
+        pretty_assertions::assert_eq!(
+            None,
+            karting_time.application_state.undo_history.undo_snapshots[0].races
+        );
+    }
+
+    #[test]
+    fn test_undo_statistics_settings_edits_as_one_step() {
+        // Given
+        let mut karting_time = KartingTime::new(DriverProfile::new("Jack Jackson", races()));
+        let expected_bucket_size = karting_time
+            .driver_profile
+            .statistics_settings
+            .statistics_windows
+            .bucket_size;
+
+        // When
+        let _ = karting_time.update(Message::BucketSizeChanged("1".to_string()));
+        let _ = karting_time.update(Message::BucketSizeChanged("10".to_string()));
+        let _ = karting_time.update(Message::Undo);
+
+        // Then
+        pretty_assertions::assert_eq!(
+            expected_bucket_size,
+            karting_time
+                .driver_profile
+                .statistics_settings
+                .statistics_windows
+                .bucket_size
+        );
+        pretty_assertions::assert_eq!(3, karting_time.driver_profile.races.len());
+        assert!(
+            karting_time
+                .application_state
+                .undo_history
+                .undo_snapshots
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_undo_race_recorded_live_as_its_own_step() {
+        // Given
+        let mut karting_time = KartingTime::new(DriverProfile::new("Jack Jackson", races()));
+        karting_time.select_telemetry_game(TelemetryGame::F1);
+        let identifier = karting_time.driver_profile.races[0]
+            .race_information
+            .unique_race_identifier
+            .clone();
+
+        // When
+        let _ = karting_time.update(Message::DeletePressed(identifier.clone()));
+        let _ = karting_time.update(Message::DeleteConfirmedPressed(identifier));
+
+        for packet in [
+            f1_session(1, 15, 7),
+            f1_lap_data(1, 0, 1, 5),
+            f1_lap_data(1, 91_234, 2, 4),
+            f1_event(1, b"SEND"),
+        ] {
+            let _ = karting_time.update(Message::TelemetryPacketReceived(packet));
+        }
+
+        let races_after_session = karting_time.driver_profile.races.len();
+        let _ = karting_time.update(Message::Undo);
+        let races_after_first_undo = karting_time.driver_profile.races.len();
+        let _ = karting_time.update(Message::Undo);
+
+        // Then
+        pretty_assertions::assert_eq!(3, races_after_session);
+        pretty_assertions::assert_eq!(2, races_after_first_undo);
+        pretty_assertions::assert_eq!(3, karting_time.driver_profile.races.len());
+        assert!(
+            karting_time
+                .driver_profile
+                .races
+                .iter()
+                .all(|race| race.race_information.track_name == "Three Sisters")
+        );
+    }
+
+    #[test]
+    fn test_new_edit_clears_redo() {
+        // Given
+        let mut karting_time = KartingTime::new(DriverProfile::new("Jack Jackson", races()));
+
+        // When
+        let _ = karting_time.update(Message::DriverNameChanged("Jill Jackson".to_string()));
+        let _ = karting_time.update(Message::Undo);
+        let _ = karting_time.update(Message::DriverNameChanged("Jim Jackson".to_string()));
+        let _ = karting_time.update(Message::Redo);
+
+        // Then
+        pretty_assertions::assert_eq!("Jim Jackson", karting_time.driver_profile.name);
+    }
+
+    #[test]
+    fn test_ignore_unchanged_profile() {
+        // Given
+        let mut karting_time = KartingTime::new(DriverProfile::new("Jack Jackson", races()));
+
+        // When
+        let _ = karting_time.update(Message::DeleteConfirmedPressed("missing".to_string()));
+
+        // Then
+        assert!(
+            karting_time
+                .application_state
+                .undo_history
+                .undo_snapshots
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_undo_watched_folder_poll_only_when_files_are_imported() {
+        // Given
+        let folder = "./watch_folder_undo_test";
+        let _test_file_guard = TestFileGuard::new(folder);
+        fs::create_dir_all(folder).unwrap();
+        let mut karting_time = KartingTime::new(DriverProfile::new("Jack Jackson", races()));
+        karting_time
+            .application_state
+            .watch_folders
+            .add_folder(folder);
+
+        // When
+        let _ = karting_time.update(Message::WatchedFoldersPolled);
+        let undo_steps_before_import = karting_time
+            .application_state
+            .undo_history
+            .undo_snapshots
+            .len();
+        fs::copy(
+            "./file_io_test_files/acc_file_2.json",
+            format!("{folder}/acc_file_2.json"),
+        )
+        .unwrap();
+        let _ = karting_time.update(Message::WatchedFoldersPolled);
+        let _ = karting_time.update(Message::WatchedFoldersPolled);
+
+        // Then
+        pretty_assertions::assert_eq!(0, undo_steps_before_import);
+        pretty_assertions::assert_eq!(
+            1,
+            karting_time
+                .application_state
+                .undo_history
+                .undo_snapshots
+                .len()
+        );
+        pretty_assertions::assert_eq!(6, karting_time.driver_profile.races.len());
+    }
+}
@@ -1,5 +1,11 @@
 pub mod file_application;
+pub mod file_error;
 pub mod file_io;
 pub mod file_picker;
 pub mod html_converter;
+pub mod import_report;
+pub mod recent_profiles;
+pub mod settings;
 pub mod test_file_guard;
+pub mod unsaved_changes;
+pub mod watch_folders;
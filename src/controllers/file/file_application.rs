@@ -1,92 +1,307 @@
 use super::file_io::{
-    read_application_state, read_race_file, upsert_application_state, upsert_races,
+    delete_recovery_file, get_recovery_file_path, read_driver_profile, read_race_file,
+    upsert_races, upsert_recovery_file,
 };
 use crate::{
-    controllers::file::file_io::{read_acc_laptimes_file, read_laptimes_file, upsert_html_races},
-    models::application::karting_time::KartingTime,
+    controllers::converters::cumulative_time_converter::detect_lap_entry_mode,
+    controllers::file::file_io::{
+        read_acc_laptimes_file, read_csv_laptimes_file, read_data_logger_laptimes_file,
+        read_file_contents, read_gps_laptimes_file, read_laptimes_file, read_telemetry_replay,
+        read_timing_sheet_file, read_timing_system_laptimes_file, read_watched_file,
+        upsert_html_races, upsert_telemetry_replay,
+    },
+    controllers::storage::profile_storage::{
+        ProfileFolderStorage, ProfileStorage, TomlFileStorage,
+    },
+    data_models::race_result_file::RaceResultFile,
+    models::{
+        application::{
+            file_error::FileError, import_report::ImportOutcome, karting_time::KartingTime,
+            telemetry::TelemetryRecorder,
+        },
+        driver::session_information::race_result::RaceResult,
+    },
 };
 
 impl KartingTime {
     pub fn file_new(&mut self) {
-        *self = KartingTime::default();
+        self.replace_karting_time(KartingTime::default());
+        self.application_state.settings.last_profile = None;
     }
 
-    pub fn export_races(&self, folder_location: &str) {
-        upsert_races(folder_location, &self.driver_profile.races);
+    pub fn export_races(&self, folder_location: &str) -> Result<(), FileError> {
+        upsert_races(folder_location, &self.driver_profile.races)
     }
 
-    pub fn export_html_races(&self, folder_location: &str) {
-        upsert_html_races(folder_location, &self.driver_profile);
+    pub fn export_html_races(&self, folder_location: &str) -> Result<(), FileError> {
+        upsert_html_races(
+            folder_location,
+            &self.driver_profile,
+            self.application_state.time_format,
+        )
     }
 
     pub fn import_acc_laptimes(&mut self, file_name: &str) {
-        let race_files = read_acc_laptimes_file(file_name);
+        self.application_state.import_report.open();
+        self.import_race_files(file_name, read_acc_laptimes_file(file_name));
+        self.check_data_issues();
+    }
 
-        for race_file in race_files {
-            let Some(race_file) = race_file else { return };
-            let race = race_file.convert_to_race_result();
+    pub fn import_timing_system_laptimes(&mut self, file_name: &str) {
+        let race_files = read_timing_system_laptimes_file(file_name, &self.driver_profile.name);
 
-            if race.is_unique_identifier(&self.driver_profile.races) {
-                self.driver_profile.races.push(race);
-            }
-        }
+        self.application_state.import_report.open();
+        self.import_race_files(file_name, race_files);
+        self.check_data_issues();
+    }
+
+    pub fn import_data_logger_laptimes(&mut self, file_name: &str) {
+        let race_file = read_data_logger_laptimes_file(file_name);
+
+        self.application_state.import_report.open();
+        self.import_race_files(file_name, race_file.map(|race_file| vec![race_file]));
+        self.check_data_issues();
+    }
+
+    pub fn import_gps_laptimes(&mut self, file_name: &str) {
+        let race_file = read_gps_laptimes_file(file_name);
+
+        self.application_state.import_report.open();
+        self.import_race_files(file_name, race_file.map(|race_file| vec![race_file]));
+        self.check_data_issues();
+    }
+
+    pub fn open_csv_import(&mut self, file_name: &str) -> Result<(), FileError> {
+        let contents = read_file_contents(file_name)?;
+
+        self.application_state.csv_import.open(file_name, &contents);
+
+        Ok(())
+    }
+
+    pub fn import_csv_laptimes(&mut self) {
+        let csv_import = &mut self.application_state.csv_import;
+
+        let Some(file_name) = csv_import.file_path.clone() else {
+            return;
+        };
+
+        csv_import.save_mapping();
+        let race_file = read_csv_laptimes_file(&file_name, &csv_import.mapping);
+        csv_import.close();
+
+        self.application_state.import_report.open();
+        self.import_race_files(&file_name, race_file.map(|race_file| vec![race_file]));
+        self.check_data_issues();
     }
 
     pub fn import_laptimes(&mut self, file_name: &str) {
         let race_file = read_laptimes_file(file_name);
 
-        let Some(race_file) = race_file else { return };
-        let race = race_file.convert_to_race_result();
+        self.application_state.import_report.open();
+        self.import_race_files(file_name, race_file.map(|race_file| vec![race_file]));
+        self.check_data_issues();
+    }
+
+    pub fn open_timing_sheet(&mut self, file_name: &str) -> Result<(), FileError> {
+        let times = read_timing_sheet_file(file_name)?;
+
+        self.application_state
+            .race_editor
+            .paste_timing_sheet(&times, detect_lap_entry_mode(&times));
+
+        Ok(())
+    }
+
+    pub fn import_watched_folders(&mut self) {
+        let changed_files = self.application_state.watch_folders.find_changed_files();
+
+        self.import_watched_files(changed_files);
+    }
+
+    // Polling carries on past a broken file, which is rejected in the import report
+    pub fn import_watched_files(&mut self, changed_files: Vec<String>) {
+        if changed_files.is_empty() {
+            return;
+        }
+
+        self.application_state.import_report.open();
+
+        for file_name in changed_files {
+            let race_files = read_watched_file(&file_name, &self.driver_profile.name);
+            self.import_race_files(&file_name, race_files);
+        }
+
+        let application_state = &mut self.application_state;
+
+        for entry in &application_state.import_report.entries {
+            if entry.outcome == ImportOutcome::Added {
+                application_state
+                    .watch_folders
+                    .announce(format!("Imported {} from {}", entry.race, entry.file_name));
+            }
+        }
+
+        self.check_data_issues();
+    }
+
+    pub fn save_telemetry_replay(&self, file_path: &str) -> Result<(), FileError> {
+        let telemetry = &self.application_state.telemetry;
+
+        upsert_telemetry_replay(file_path, telemetry.game, &telemetry.recorded_packets)
+    }
+
+    pub fn replay_telemetry(&mut self, file_name: &str) {
+        self.application_state.import_report.open();
+
+        let (game, packets) = match read_telemetry_replay(file_name) {
+            Ok(replay) => replay,
+            Err(file_error) => {
+                self.import_race_files(file_name, Err(file_error));
+                return;
+            }
+        };
+
+        let mut recorder = TelemetryRecorder::new(game);
 
-        if race.is_unique_identifier(&self.driver_profile.races) {
-            self.driver_profile.races.push(race);
+        // Races are added as they finish so the next race's session is numbered after them
+        for packet in packets {
+            let races = recorder.handle_packet(&packet, &self.driver_profile.races);
+            self.import_race_files(file_name, Ok(convert_to_race_files(&races)));
         }
+
+        let race = recorder.finish_session(&self.driver_profile.races);
+        self.import_race_files(file_name, Ok(convert_to_race_files(race.as_slice())));
+
+        self.check_data_issues();
     }
 
     pub fn import_races(&mut self, file_names: Vec<String>) {
+        self.application_state.import_report.open();
+
         for file_name in file_names {
             let race_file = read_race_file(&file_name);
+            self.import_race_files(&file_name, race_file.map(|race_file| vec![race_file]));
+        }
 
-            let Some(race_file) = race_file else { continue };
-            let race = race_file.convert_to_race_result();
+        self.check_data_issues();
+        self.driver_profile.update_driver_profile();
+    }
 
-            if race.is_unique_identifier(&self.driver_profile.races) {
-                self.driver_profile.races.push(race);
-            }
+    pub fn open_profile_merge(&mut self, file_name: &str) -> Result<(), FileError> {
+        let karting_time_file = read_driver_profile(file_name)?;
 
-            self.driver_profile.update_driver_profile();
-        }
+        let incoming_profile = karting_time_file
+            .driver_profile_file
+            .convert_to_driver_profile();
+
+        self.application_state.profile_merge.open(
+            file_name,
+            &incoming_profile.name,
+            &self.driver_profile.races,
+            &incoming_profile.races,
+        );
+
+        Ok(())
+    }
+
+    pub fn merge_profile(&mut self) {
+        let profile_merge = &mut self.application_state.profile_merge;
+
+        self.driver_profile.races = profile_merge.merge_races(&self.driver_profile.races);
+        profile_merge.close();
     }
 
-    pub fn save_application(&self, file_path: &str) {
+    pub fn save_application(&self, file_path: &str) -> Result<(), FileError> {
         let karting_time_file = self.convert_to_karting_time_file();
 
-        upsert_application_state(file_path, &karting_time_file);
+        TomlFileStorage::new(file_path).save_profile(&karting_time_file)
+    }
+
+    pub fn save_profile_folder(&self, folder_location: &str) -> Result<(), FileError> {
+        let karting_time_file = self.convert_to_karting_time_file();
+
+        ProfileFolderStorage::new(folder_location).save_profile(&karting_time_file)
+    }
+
+    // A crashed session's recovery file isn't overwritten until the user restores or discards it
+    pub fn autosave(&self, recovery_file_path: &str) -> Result<(), FileError> {
+        let unsaved_changes = &self.application_state.unsaved_changes;
+
+        if !unsaved_changes.is_dirty || unsaved_changes.recovery_file_path.is_some() {
+            return Ok(());
+        }
+
+        upsert_recovery_file(recovery_file_path, &self.convert_to_karting_time_file())
+    }
+
+    pub fn restore_recovery_file(&mut self, recovery_file_path: &str) -> Result<(), FileError> {
+        self.load_application(recovery_file_path)?;
+
+        let unsaved_changes = &mut self.application_state.unsaved_changes;
+        unsaved_changes.recovery_file_path = None;
+        unsaved_changes.mark_changed();
+
+        Ok(())
     }
 
-    pub fn load_application(&mut self, file_name: &str) {
-        let karting_time_file = read_application_state(file_name);
+    // The backup isn't where the profile lives so it stays unsaved until saved somewhere
+    pub fn restore_backup_file(&mut self, backup_file_path: &str) -> Result<(), FileError> {
+        self.load_application(backup_file_path)?;
+        self.application_state.unsaved_changes.mark_changed();
 
-        let Some(karting_time_file) = karting_time_file else {
+        Ok(())
+    }
+
+    pub fn discard_recovery_file(&mut self, recovery_file_path: &str) {
+        delete_recovery_file(recovery_file_path);
+        self.application_state.unsaved_changes.recovery_file_path = None;
+    }
+
+    // The recovery file is kept while the user hasn't yet chosen to restore or discard it
+    pub fn clear_unsaved_changes(&mut self) {
+        let unsaved_changes = &mut self.application_state.unsaved_changes;
+        unsaved_changes.is_dirty = false;
+
+        if unsaved_changes.recovery_file_path.is_some() {
             return;
-        };
-        *self = karting_time_file.convert_to_karting_time()
+        }
+
+        if let Some(recovery_file_path) = get_recovery_file_path() {
+            delete_recovery_file(&recovery_file_path);
+        }
     }
+
+    pub fn load_application(&mut self, file_name: &str) -> Result<(), FileError> {
+        let karting_time_file = read_driver_profile(file_name)?;
+
+        self.replace_karting_time(karting_time_file.convert_to_karting_time());
+        self.check_data_issues();
+
+        Ok(())
+    }
+}
+
+fn convert_to_race_files(races: &[RaceResult]) -> Vec<RaceResultFile> {
+    races.iter().map(RaceResult::convert_to_race_file).collect()
 }
 
 #[cfg(test)]
 mod file_application_should {
     use super::*;
     use crate::{
-        controllers::file::test_file_guard::TestFileGuard,
-        data_models::race_result_file::RaceResultFile,
+        controllers::{
+            file::test_file_guard::TestFileGuard,
+            telemetry::test_packets::{f1_lap_data, f1_session},
+        },
         models::{
+            application::{race_editor::LapEntryMode, telemetry::TelemetryGame},
             date::RaceDate,
             driver::{
                 driver_profile::DriverProfile,
                 session_information::{
                     lap::Lap, race_information::RaceInformation, race_metadata::RaceMetadata,
-                    race_result::RaceResult, session::Session,
+                    session::Session,
                 },
             },
         },
@@ -136,7 +351,7 @@ mod file_application_should {
         let karting_time = KartingTime::new(driver_profile);
 
         // When
-        karting_time.export_races(file_location);
+        karting_time.export_races(file_location).unwrap();
 
         // Then
         let file_name_1 = "./".to_string()
@@ -202,7 +417,7 @@ mod file_application_should {
         let karting_time = KartingTime::new(driver_profile);
 
         // When
-        karting_time.export_html_races(file_location);
+        karting_time.export_html_races(file_location).unwrap();
 
         // Then
         let file_name = format!("./{}.html", &karting_time.driver_profile.name);
@@ -221,6 +436,13 @@ mod file_application_should {
 
         // Then
         assert!(karting_time.driver_profile.races.is_empty());
+        pretty_assertions::assert_eq!(
+            1,
+            karting_time
+                .application_state
+                .import_report
+                .count(ImportOutcome::Rejected)
+        );
     }
 
     #[test]
@@ -231,7 +453,12 @@ mod file_application_should {
         let session_1 = Session::new(1001, 3);
         let session_2 = Session::new(1002, 2);
         let session_3 = Session::new(1003, 1);
-        let race_date = RaceDate::today();
+        let race_date = RaceDate::from_system_time(
+            fs::metadata("./file_io_test_files/acc_file_2.json")
+                .unwrap()
+                .modified()
+                .unwrap(),
+        );
         let race_information_1 = RaceInformation::new(track_name, race_date.clone(), session_1);
         let race_information_2 = RaceInformation::new(track_name, race_date.clone(), session_2);
         let race_information_3 = RaceInformation::new(track_name, race_date.clone(), session_3);
@@ -283,6 +510,314 @@ mod file_application_should {
         pretty_assertions::assert_eq!(expected_race_3, karting_time.driver_profile.races[2]);
     }
 
+    #[test]
+    fn test_import_non_existent_timing_system_laptimes() {
+        // Given
+        let mut karting_time = KartingTime::default();
+
+        // When
+        karting_time.import_timing_system_laptimes("");
+
+        // Then
+        assert!(karting_time.driver_profile.races.is_empty());
+        pretty_assertions::assert_eq!(
+            1,
+            karting_time
+                .application_state
+                .import_report
+                .count(ImportOutcome::Rejected)
+        );
+    }
+
+    #[test]
+    fn test_import_timing_system_laptimes() {
+        // Given
+        let mut karting_time = KartingTime::new(DriverProfile::new("Jack Jackson", vec![]));
+        let expected_race = RaceResult::new(
+            RaceInformation::new(
+                "Three Sisters",
+                RaceDate::new(18, 11, 2023),
+                Session::new(2, 2),
+            ),
+            RaceMetadata::new("Heat 2", "N/A", "Kart 12", "", "Imported from Alpha Timing"),
+            vec![
+                Lap::new(1, 48.667),
+                Lap::new(2, 43.434),
+                Lap::new(3, 62.564),
+            ],
+        );
+
+        let file_name = "./file_io_test_files/alpha_timing_test.csv";
+
+        // When
+        karting_time.import_timing_system_laptimes(file_name);
+        karting_time.import_timing_system_laptimes(file_name);
+
+        // Then
+        pretty_assertions::assert_eq!(1, karting_time.driver_profile.races.len());
+        pretty_assertions::assert_eq!(expected_race, karting_time.driver_profile.races[0]);
+    }
+
+    #[test]
+    fn test_import_data_logger_laptimes() {
+        // Given
+        let mut karting_time = KartingTime::default();
+        let file_name = "./file_io_test_files/aim_mychron_test.csv";
+
+        // When
+        karting_time.import_data_logger_laptimes(file_name);
+        karting_time.import_data_logger_laptimes(file_name);
+
+        // Then
+        pretty_assertions::assert_eq!(1, karting_time.driver_profile.races.len());
+
+        let race = &karting_time.driver_profile.races[0];
+        pretty_assertions::assert_eq!("10:32", race.race_metadata.session_time);
+        pretty_assertions::assert_eq!("Rotax Max", race.race_metadata.car_used);
+        pretty_assertions::assert_eq!(Some(14050.0), race.laptimes[0].channels.max_rpm);
+        pretty_assertions::assert_eq!(Some(101.0), race.laptimes[2].channels.max_speed);
+    }
+
+    #[test]
+    fn test_import_gps_laptimes() {
+        // Given
+        let mut karting_time = KartingTime::default();
+
+        // When
+        karting_time.import_gps_laptimes("./file_io_test_files/gpx_test.gpx");
+
+        // Then
+        pretty_assertions::assert_eq!(1, karting_time.driver_profile.races.len());
+
+        let race = &karting_time.driver_profile.races[0];
+        pretty_assertions::assert_eq!("Lydd", race.race_information.track_name);
+        pretty_assertions::assert_eq!(
+            vec![45.5, 44.5],
+            race.laptimes
+                .iter()
+                .map(|lap| lap.time)
+                .collect::<Vec<f32>>()
+        );
+        assert!(race.calculate_gps_statistics().is_some());
+        assert!(!race.calculate_track_outline().is_empty());
+    }
+
+    #[test]
+    fn test_open_non_existent_csv_import() {
+        // Given
+        let mut karting_time = KartingTime::default();
+
+        // When
+        let result = karting_time.open_csv_import("");
+
+        // Then
+        assert!(result.is_err());
+        assert!(
+            karting_time
+                .application_state
+                .csv_import
+                .file_path
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_import_csv_laptimes() {
+        // Given
+        let mut karting_time = KartingTime::default();
+        let file_name = "./file_io_test_files/csv_mapping_test.csv";
+        let mut expected_race = RaceResult::new(
+            RaceInformation::new("Default", RaceDate::default(), Session::default()),
+            RaceMetadata::default(),
+            vec![Lap::new(1, 62.345), Lap::new(2, 61.002)],
+        );
+        expected_race.laptimes[0].sectors = vec![20.1, 21.2, 21.045];
+        expected_race.laptimes[1].sectors = vec![19.9, 20.8, 20.302];
+
+        // When
+        karting_time.open_csv_import(file_name).unwrap();
+
+        let csv_import = &mut karting_time.application_state.csv_import;
+        csv_import.mapping.source_name = "Club Timing".to_string();
+        csv_import.mapping.lap_number_column = 1;
+        csv_import.mapping.laptime_column = 2;
+        csv_import.set_sector_columns("3,4,5".to_string());
+        csv_import.mapping.validity_column = 6;
+
+        karting_time.import_csv_laptimes();
+
+        // Then
+        let csv_import = &karting_time.application_state.csv_import;
+        assert!(csv_import.file_path.is_none());
+        pretty_assertions::assert_eq!(1, csv_import.saved_mappings.len());
+        pretty_assertions::assert_eq!(
+            "Lap,Lap Time,S1,S2,S3,Valid",
+            csv_import.saved_mappings[0].header
+        );
+        pretty_assertions::assert_eq!(1, karting_time.driver_profile.races.len());
+        pretty_assertions::assert_eq!(expected_race, karting_time.driver_profile.races[0]);
+    }
+
+    #[test]
+    fn test_open_timing_sheet() {
+        // Given
+        let mut karting_time = KartingTime::default();
+        let file_name = "./file_io_test_files/transponder_crossings_test.txt";
+
+        // When
+        karting_time.open_timing_sheet(file_name).unwrap();
+
+        // Then
+        let race_editor = &karting_time.application_state.race_editor;
+        pretty_assertions::assert_eq!(LapEntryMode::CrossingTimestamps, race_editor.lap_entry_mode);
+        pretty_assertions::assert_eq!(
+            "48.667\n43.434\n87.651\n44.118\n",
+            race_editor.get_laptimes_from_text_editor()
+        );
+        pretty_assertions::assert_eq!(
+            vec![
+                "Lap 3: 87.651s is far slower than the median lap, a crossing may be missing"
+                    .to_string()
+            ],
+            race_editor.timing_warnings
+        );
+    }
+
+    #[test]
+    fn test_import_watched_folders() {
+        // Given
+        let folder = "./watch_folder_import_test";
+        let _test_file_guard = TestFileGuard::new(folder);
+        fs::create_dir_all(folder).unwrap();
+        fs::copy(
+            "./file_io_test_files/acc_file_2.json",
+            format!("{folder}/acc_file_2.json"),
+        )
+        .unwrap();
+        fs::write(format!("{folder}/notes.txt"), "Not a session").unwrap();
+
+        let mut karting_time = KartingTime::default();
+        karting_time
+            .application_state
+            .watch_folders
+            .add_folder(folder);
+
+        // When
+        karting_time.import_watched_folders();
+        fs::copy(
+            "./file_io_test_files/acc_file_2.json",
+            format!("{folder}/acc_file_2_copy.json"),
+        )
+        .unwrap();
+        karting_time.import_watched_folders();
+
+        // Then
+        pretty_assertions::assert_eq!(3, karting_time.driver_profile.races.len());
+        pretty_assertions::assert_eq!(
+            3,
+            karting_time
+                .application_state
+                .watch_folders
+                .announcements
+                .len()
+        );
+        assert!(
+            karting_time.application_state.watch_folders.announcements[0]
+                .starts_with("Imported silverstone")
+        );
+        pretty_assertions::assert_eq!(
+            "0 added, 3 skipped as duplicates, 0 rejected",
+            karting_time.application_state.import_report.summary()
+        );
+    }
+
+    #[test]
+    fn test_import_watched_folders_after_reopening_profile() {
+        // Given
+        let folder = "./watch_folder_reopen_test";
+        let _test_file_guard = TestFileGuard::new(folder);
+        fs::create_dir_all(folder).unwrap();
+        fs::copy(
+            "./file_io_test_files/acc_file_2.json",
+            format!("{folder}/acc_file_2.json"),
+        )
+        .unwrap();
+
+        let mut karting_time = KartingTime::default();
+        karting_time
+            .application_state
+            .watch_folders
+            .add_folder(folder);
+        karting_time.import_watched_folders();
+
+        // When
+        let mut reopened_karting_time = karting_time
+            .convert_to_karting_time_file()
+            .convert_to_karting_time();
+        reopened_karting_time.import_watched_folders();
+
+        // Then
+        pretty_assertions::assert_eq!(3, reopened_karting_time.driver_profile.races.len());
+        assert!(
+            reopened_karting_time
+                .application_state
+                .watch_folders
+                .announcements
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_replay_non_existent_telemetry() {
+        // Given
+        let mut karting_time = KartingTime::default();
+
+        // When
+        karting_time.replay_telemetry("");
+
+        // Then
+        assert!(karting_time.driver_profile.races.is_empty());
+        pretty_assertions::assert_eq!(
+            1,
+            karting_time
+                .application_state
+                .import_report
+                .count(ImportOutcome::Rejected)
+        );
+    }
+
+    #[test]
+    fn test_save_then_replay_telemetry_acceptance() {
+        // Given
+        let file_name = "./telemetry_replay.ktr";
+        let _test_file_guard = TestFileGuard::new(file_name);
+        let mut recording = KartingTime::default();
+        recording.select_telemetry_game(TelemetryGame::F1);
+        recording.application_state.telemetry.recorded_packets = vec![
+            f1_session(7, 15, 10),
+            f1_lap_data(7, 0, 1, 8),
+            f1_lap_data(7, 139_012, 2, 6),
+            f1_lap_data(7, 137_554, 3, 5),
+        ];
+        let mut karting_time = KartingTime::default();
+
+        // When
+        recording.save_telemetry_replay(file_name).unwrap();
+        karting_time.replay_telemetry(file_name);
+
+        // Then
+        pretty_assertions::assert_eq!(1, karting_time.driver_profile.races.len());
+
+        let race = &karting_time.driver_profile.races[0];
+        pretty_assertions::assert_eq!("Spa", race.race_information.track_name);
+        pretty_assertions::assert_eq!("Race", race.race_metadata.session_type);
+        pretty_assertions::assert_eq!(5, race.race_information.session.race_position);
+        pretty_assertions::assert_eq!(
+            vec![Lap::new(1, 139.012), Lap::new(2, 137.554)],
+            race.laptimes
+        );
+    }
+
     #[test]
     fn test_read_non_existent_laptime_file() {
         // Given
@@ -293,6 +828,13 @@ mod file_application_should {
 
         // Then
         assert!(karting_time.driver_profile.races.is_empty());
+        pretty_assertions::assert_eq!(
+            1,
+            karting_time
+                .application_state
+                .import_report
+                .count(ImportOutcome::Rejected)
+        );
     }
 
     #[rstest]
@@ -371,6 +913,11 @@ mod file_application_should {
         let expected_race_file = RaceResultFile {
             track_name: "Default".to_string(),
             laptimes,
+            day: 1,
+            month: 1,
+            year: 2000,
+            session_id: 1,
+            race_position: 1,
             ..Default::default()
         };
         let mut karting_time = KartingTime::default();
@@ -400,6 +947,13 @@ mod file_application_should {
 
         // Then
         assert!(karting_time.driver_profile.races.is_empty());
+        pretty_assertions::assert_eq!(
+            1,
+            karting_time
+                .application_state
+                .import_report
+                .count(ImportOutcome::Rejected)
+        );
     }
 
     #[test]
@@ -429,7 +983,7 @@ mod file_application_should {
 
         let _guard = TestFileGuard::new(&file_name);
 
-        upsert_races(file_location, &races);
+        upsert_races(file_location, &races).unwrap();
 
         karting_time.import_races(vec![file_name]);
 
@@ -530,12 +1084,290 @@ mod file_application_should {
         // When
         let _guard = TestFileGuard::new(file_name);
 
-        karting_time.save_application(file_name);
-        karting_time.load_application(file_name);
+        karting_time.save_application(file_name).unwrap();
+        karting_time.load_application(file_name).unwrap();
 
         // Then
         assert!(fs::metadata(file_name).is_ok());
         assert!(fs::metadata(file_name).unwrap().len() != 0);
         pretty_assertions::assert_eq!(expected, karting_time);
     }
+
+    #[test]
+    fn test_application_saves_then_loads_profile_folder_acceptance() {
+        // Given
+        let folder_location = "./karting_time_state_folder";
+        let driver_profile = DriverProfile::new(
+            "Jack Jackson",
+            vec![
+                RaceResult::new(
+                    RaceInformation::new(
+                        "Trafford Park",
+                        RaceDate::new(15, 1, 2024),
+                        Session::new(2, 3),
+                    ),
+                    RaceMetadata::new(
+                        Default::default(),
+                        Default::default(),
+                        "Kart",
+                        "Championship",
+                        Default::default(),
+                    ),
+                    vec![Lap::new(1, 56.8), Lap::new(2, 58.7)],
+                ),
+                RaceResult::new(
+                    RaceInformation::new(
+                        "Three Sisters",
+                        RaceDate::new(12, 12, 2025),
+                        Session::new(1, 1),
+                    ),
+                    RaceMetadata::new(
+                        Default::default(),
+                        Default::default(),
+                        "Kart",
+                        "Championship",
+                        Default::default(),
+                    ),
+                    vec![Lap::new(1, 50.4), Lap::new(2, 55.5)],
+                ),
+            ],
+        );
+        let expected = KartingTime::new(driver_profile.clone());
+        let mut karting_time = KartingTime::new(driver_profile.clone());
+
+        // When
+        let _guard = TestFileGuard::new(folder_location);
+
+        karting_time.save_profile_folder(folder_location).unwrap();
+        karting_time.load_application(folder_location).unwrap();
+
+        // Then
+        assert!(fs::metadata(folder_location.to_string() + "/profile.toml").is_ok());
+        pretty_assertions::assert_eq!(
+            2,
+            fs::read_dir(folder_location.to_string() + "/races")
+                .unwrap()
+                .count()
+        );
+        pretty_assertions::assert_eq!(expected, karting_time);
+    }
+
+    #[test]
+    fn test_autosave_then_restore_recovery_file_acceptance() {
+        // Given
+        let recovery_file_path = "./karting_time_test_recovery.toml";
+        let _guard = TestFileGuard::new(recovery_file_path);
+        let driver_profile = DriverProfile::new(
+            "Jack Jackson",
+            vec![RaceResult::new(
+                RaceInformation::new(
+                    "Three Sisters",
+                    RaceDate::new(12, 12, 2025),
+                    Session::new(1, 1),
+                ),
+                RaceMetadata::default(),
+                vec![Lap::new(1, 50.4), Lap::new(2, 55.5)],
+            )],
+        );
+        let mut expected = KartingTime::new(driver_profile.clone());
+        expected.application_state.unsaved_changes.is_dirty = true;
+        let mut karting_time = KartingTime::new(driver_profile);
+
+        // When
+        karting_time.autosave(recovery_file_path).unwrap();
+        let is_saved_without_changes = fs::metadata(recovery_file_path).is_ok();
+
+        karting_time
+            .application_state
+            .unsaved_changes
+            .mark_changed();
+        karting_time.autosave(recovery_file_path).unwrap();
+
+        let mut recovered_karting_time = KartingTime::default();
+        recovered_karting_time
+            .restore_recovery_file(recovery_file_path)
+            .unwrap();
+
+        // Then
+        assert!(!is_saved_without_changes);
+        pretty_assertions::assert_eq!(expected, recovered_karting_time);
+    }
+
+    #[test]
+    fn test_autosave_keeps_recovery_file_until_restored_or_discarded() {
+        // Given
+        let recovery_file_path = "./karting_time_test_pending_recovery.toml";
+        let _guard = TestFileGuard::new(recovery_file_path);
+        let mut crashed_karting_time = KartingTime::new(DriverProfile::new("Jack Jackson", vec![]));
+        crashed_karting_time
+            .application_state
+            .unsaved_changes
+            .mark_changed();
+        crashed_karting_time.autosave(recovery_file_path).unwrap();
+
+        let mut karting_time = KartingTime::new(DriverProfile::new("Obi Wan Kenobi", vec![]));
+        karting_time
+            .application_state
+            .unsaved_changes
+            .recovery_file_path = Some(recovery_file_path.to_string());
+
+        // When
+        karting_time
+            .application_state
+            .unsaved_changes
+            .mark_changed();
+        karting_time.autosave(recovery_file_path).unwrap();
+
+        // Then
+        pretty_assertions::assert_eq!(
+            "Jack Jackson",
+            read_driver_profile(recovery_file_path)
+                .unwrap()
+                .driver_profile_file
+                .name
+        );
+    }
+
+    #[test]
+    fn test_reopen_last_profile_keeps_recovery_file_to_restore() {
+        // Given
+        let recovery_file_path = "./karting_time_test_reopened_recovery.toml";
+        let profile_path = "./karting_time_test_reopened_profile.toml";
+        let _recovery_guard = TestFileGuard::new(recovery_file_path);
+        let _profile_guard = TestFileGuard::new(profile_path);
+
+        let mut crashed_karting_time =
+            KartingTime::new(DriverProfile::new("Obi Wan Kenobi", vec![]));
+        crashed_karting_time
+            .application_state
+            .unsaved_changes
+            .mark_changed();
+        crashed_karting_time.autosave(recovery_file_path).unwrap();
+        KartingTime::new(DriverProfile::new("Jack Jackson", vec![]))
+            .save_application(profile_path)
+            .unwrap();
+
+        let mut karting_time = KartingTime::default();
+        karting_time
+            .application_state
+            .unsaved_changes
+            .recovery_file_path = Some(recovery_file_path.to_string());
+        karting_time.add_recent_profile(profile_path);
+        karting_time.toggle_reopen_last_profile();
+
+        // When
+        let profile_to_reopen = karting_time.last_profile_to_reopen().unwrap();
+        karting_time.load_application(&profile_to_reopen).unwrap();
+        let reopened_recovery_file_path = karting_time
+            .application_state
+            .unsaved_changes
+            .recovery_file_path
+            .clone();
+
+        karting_time
+            .restore_recovery_file(recovery_file_path)
+            .unwrap();
+
+        // Then
+        pretty_assertions::assert_eq!(
+            Some(recovery_file_path.to_string()),
+            reopened_recovery_file_path
+        );
+        pretty_assertions::assert_eq!("Obi Wan Kenobi", karting_time.driver_profile.name);
+        assert!(
+            karting_time
+                .application_state
+                .unsaved_changes
+                .recovery_file_path
+                .is_none()
+        );
+        assert!(karting_time.application_state.unsaved_changes.is_dirty);
+    }
+
+    #[test]
+    fn test_discard_recovery_file() {
+        // Given
+        let recovery_file_path = "./karting_time_test_discarded_recovery.toml";
+        let _guard = TestFileGuard::new(recovery_file_path);
+        let mut karting_time = KartingTime::default();
+        karting_time
+            .application_state
+            .unsaved_changes
+            .mark_changed();
+        karting_time.autosave(recovery_file_path).unwrap();
+        karting_time
+            .application_state
+            .unsaved_changes
+            .recovery_file_path = Some(recovery_file_path.to_string());
+
+        // When
+        karting_time.discard_recovery_file(recovery_file_path);
+
+        // Then
+        assert!(fs::metadata(recovery_file_path).is_err());
+        assert!(
+            karting_time
+                .application_state
+                .unsaved_changes
+                .recovery_file_path
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_merge_profile_acceptance() {
+        // Given
+        let file_name = "./karting_time_merge_state.toml";
+        let _guard = TestFileGuard::new(file_name);
+        let race = RaceResult::new(
+            RaceInformation::new(
+                "Three Sisters",
+                RaceDate::new(12, 12, 2025),
+                Session::new(1, 1),
+            ),
+            RaceMetadata::default(),
+            vec![Lap::new(1, 50.4), Lap::new(2, 55.5)],
+        );
+        let mut edited_race = race.clone();
+        edited_race.race_metadata.notes = "New tyres".to_string();
+        let new_race = RaceResult::new(
+            RaceInformation::new(
+                "Trafford Park",
+                RaceDate::new(15, 1, 2024),
+                Session::new(2, 3),
+            ),
+            RaceMetadata::default(),
+            vec![Lap::new(1, 56.8), Lap::new(2, 58.7)],
+        );
+        KartingTime::new(DriverProfile::new(
+            "Jack Jackson",
+            vec![edited_race.clone(), new_race.clone()],
+        ))
+        .save_application(file_name)
+        .unwrap();
+        let mut karting_time = KartingTime::new(DriverProfile::new("Jack Jackson", vec![race]));
+
+        // When
+        karting_time.open_profile_merge(file_name).unwrap();
+        let race_merges = karting_time
+            .application_state
+            .profile_merge
+            .race_merges
+            .len();
+        karting_time.merge_profile();
+
+        // Then
+        pretty_assertions::assert_eq!(2, race_merges);
+        pretty_assertions::assert_eq!(
+            vec![edited_race, new_race],
+            karting_time.driver_profile.races
+        );
+        assert!(
+            karting_time
+                .application_state
+                .profile_merge
+                .file_path
+                .is_none()
+        );
+    }
 }
@@ -0,0 +1,165 @@
+use crate::{
+    controllers::converters::laptime_parser::LaptimeError,
+    models::application::{file_error::FileError, karting_time::KartingTime},
+};
+use std::fmt::Display;
+
+impl FileError {
+    pub fn io(file_name: &str, error: &std::io::Error) -> Self {
+        Self::Io {
+            file_name: file_name.to_string(),
+            reason: error.to_string(),
+        }
+    }
+
+    // The error only knows its byte offset, so the line is counted from the contents
+    pub fn toml(file_name: &str, contents: &str, error: &toml::de::Error) -> Self {
+        let line = error.span().map(|span| {
+            contents[..span.start.min(contents.len())]
+                .matches('\n')
+                .count()
+                + 1
+        });
+
+        Self::Parse {
+            file_name: file_name.to_string(),
+            line,
+            reason: error.message().trim().to_string(),
+        }
+    }
+
+    // The line is kept apart from the reason, which otherwise ends with the line and column
+    pub fn json(file_name: &str, error: &serde_json::Error) -> Self {
+        let location = format!(" at line {} column {}", error.line(), error.column());
+
+        let line = match error.line() {
+            0 => None,
+            line => Some(line),
+        };
+
+        Self::Parse {
+            file_name: file_name.to_string(),
+            line,
+            reason: error.to_string().trim_end_matches(&location).to_string(),
+        }
+    }
+
+    // The error counts characters from the start of the time, the reason counts them from the
+    // start of the line
+    pub fn laptime(file_name: &str, line: usize, column: usize, error: &LaptimeError) -> Self {
+        Self::Parse {
+            file_name: file_name.to_string(),
+            line: Some(line),
+            reason: format!("{} at character {}", error.reason, column + error.position),
+        }
+    }
+
+    pub fn database(file_name: &str, error: &rusqlite::Error) -> Self {
+        Self::Io {
+            file_name: file_name.to_string(),
+            reason: error.to_string(),
+        }
+    }
+
+    pub fn validation(file_name: &str, reason: &str) -> Self {
+        Self::Validation {
+            file_name: file_name.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl Display for FileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileError::Io { file_name, reason } => {
+                write!(f, "Couldn't access {file_name}: {reason}")
+            }
+            FileError::Parse {
+                file_name,
+                line: Some(line),
+                reason,
+            } => write!(f, "Couldn't read {file_name} line {line}: {reason}"),
+            FileError::Parse {
+                file_name,
+                line: None,
+                reason,
+            } => write!(f, "Couldn't read {file_name}: {reason}"),
+            FileError::Validation { file_name, reason } => {
+                write!(f, "Couldn't import {file_name}: {reason}")
+            }
+        }
+    }
+}
+
+impl KartingTime {
+    // Keeps the error for the notification bar and tells the caller whether to carry on
+    pub fn report_file_result(&mut self, result: Result<(), FileError>) -> bool {
+        match result {
+            Ok(()) => true,
+            Err(file_error) => {
+                self.application_state.file_errors.push(file_error);
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod file_error_should {
+    use super::*;
+    use crate::data_models::race_result_file::RaceResultFile;
+
+    #[test]
+    fn test_toml_error_names_file_and_line() {
+        // Given
+        let contents = "track_name = \"Three Sisters\"\nday = \n";
+        let error = toml::from_str::<RaceResultFile>(contents).unwrap_err();
+
+        // When
+        let file_error = FileError::toml("race.toml", contents, &error);
+
+        // Then
+        let line = match &file_error {
+            FileError::Parse { line, .. } => *line,
+            _ => None,
+        };
+        pretty_assertions::assert_eq!(Some(2), line);
+        assert!(
+            file_error
+                .to_string()
+                .starts_with("Couldn't read race.toml line 2: ")
+        );
+    }
+
+    #[test]
+    fn test_validation_error_display() {
+        // Given
+        let file_error = FileError::validation("laptimes.txt", "no laptimes were found");
+
+        // When
+        let message = file_error.to_string();
+
+        // Then
+        pretty_assertions::assert_eq!(
+            "Couldn't import laptimes.txt: no laptimes were found",
+            message
+        );
+    }
+
+    #[test]
+    fn test_report_file_result() {
+        // Given
+        let mut karting_time = KartingTime::default();
+        let file_error = FileError::validation("laptimes.txt", "no laptimes were found");
+
+        // When
+        let is_ok = karting_time.report_file_result(Ok(()));
+        let is_error_ok = karting_time.report_file_result(Err(file_error.clone()));
+
+        // Then
+        assert!(is_ok);
+        assert!(!is_error_ok);
+        pretty_assertions::assert_eq!(vec![file_error], karting_time.application_state.file_errors);
+    }
+}
@@ -1,73 +1,94 @@
+use crate::controllers::converters::laptime_parser::parse_laptime;
 use crate::controllers::file::html_converter::convert_to_html;
+use crate::controllers::telemetry::packet_replay::{read_packet_replay, write_packet_replay};
 use crate::data_models::karting_time_file::KartingTimeFile;
+use crate::data_models::profile_manifest_file::ProfileManifestFile;
 use crate::data_models::race_result_file::RaceResultFile;
+use crate::data_models::settings_file::SettingsFile;
+use crate::models::application::csv_import::CsvColumnMapping;
+use crate::models::application::file_error::FileError;
+use crate::models::application::telemetry::TelemetryGame;
+use crate::models::application::time_format::TimeFormat;
 use crate::models::date::RaceDate;
 use crate::models::driver::driver_profile::DriverProfile;
 use crate::models::driver::session_information::acc_lap::AccLap;
 use crate::models::driver::session_information::acc_session_data::AccSessionData;
+use crate::models::driver::session_information::data_logger_session::DataLoggerSession;
+use crate::models::driver::session_information::gps_session::GpsSession;
 use crate::models::driver::session_information::race_metadata::RaceMetadata;
 use crate::models::driver::session_information::race_result::RaceResult;
 use crate::models::driver::session_information::session::Session;
+use crate::models::driver::session_information::timing_system_session::TimingSystemSession;
+use chrono::{Datelike, Local, NaiveDate};
 use maud::Markup;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{Read, Write};
-
-const FILE_ERROR: &str = "failed to create file";
-
-pub fn upsert_races(folder_location: &str, races: &Vec<RaceResult>) {
+use std::path::{Path, PathBuf};
+
+const MAXIMUM_GPS_TRACE_POINTS: usize = 2000;
+const PROFILE_MANIFEST_FILE: &str = "profile.toml";
+const PROFILE_RACES_FOLDER: &str = "races";
+const RECOVERY_FOLDER: &str = "karting-time";
+const RECOVERY_FILE: &str = "recovery.toml";
+const SETTINGS_FILE: &str = "settings.toml";
+const BACKUP_FOLDER: &str = "backups";
+const MAXIMUM_BACKUPS: usize = 5;
+
+pub fn upsert_races(folder_location: &str, races: &Vec<RaceResult>) -> Result<(), FileError> {
     for race in races {
         let race_file = race.convert_to_race_file();
 
         let file_name = format!(
             "{}/{}.toml",
-            folder_location, race.race_information.unique_race_identifier
+            folder_location,
+            sanitise_file_name(&race.race_information.unique_race_identifier)
         );
 
-        let mut file = match File::create(file_name) {
-            Ok(file) => file,
-            Err(_) => {
-                println!("{FILE_ERROR}");
-                return;
-            }
-        };
+        let mut file =
+            File::create(&file_name).map_err(|error| FileError::io(&file_name, &error))?;
 
         let toml = toml::to_string_pretty(&race_file).unwrap_or_default();
 
-        write!(file, "{toml}").unwrap_or_default()
+        write!(file, "{toml}").map_err(|error| FileError::io(&file_name, &error))?;
     }
+
+    Ok(())
 }
 
-pub fn upsert_html_races(folder_location: &str, driver_profile: &DriverProfile) {
-    let markup: Markup = convert_to_html(&driver_profile.convert_to_driver_profile_file());
+pub fn upsert_html_races(
+    folder_location: &str,
+    driver_profile: &DriverProfile,
+    time_format: TimeFormat,
+) -> Result<(), FileError> {
+    let markup: Markup = convert_to_html(
+        &driver_profile.convert_to_driver_profile_file(),
+        time_format,
+    );
 
     let file_name = format!("{}/{}.html", folder_location, &driver_profile.name);
 
-    let mut file = match File::create(file_name) {
-        Ok(file) => file,
-        Err(_) => {
-            println!("{FILE_ERROR}");
-            return;
-        }
-    };
+    let mut file = File::create(&file_name).map_err(|error| FileError::io(&file_name, &error))?;
 
-    write!(file, "{}", markup.into_string()).unwrap_or_default()
+    write!(file, "{}", markup.into_string()).map_err(|error| FileError::io(&file_name, &error))
 }
 
-pub fn read_acc_laptimes_file(file_name: &str) -> Vec<Option<RaceResultFile>> {
-    let contents = get_file_contents(file_name);
+pub fn read_acc_laptimes_file(file_name: &str) -> Result<Vec<RaceResultFile>, FileError> {
+    let contents = read_file_contents(file_name)?;
 
-    if contents.is_empty() {
-        return vec![];
-    }
+    let session_data: AccSessionData =
+        serde_json::from_str(&contents).map_err(|error| FileError::json(file_name, &error))?;
 
-    let mut race_result_files = vec![];
+    let grouped = session_data.group_laps_by_driver();
 
-    let session_data: AccSessionData = serde_json::from_str(&contents).unwrap_or_default();
+    if grouped.is_empty() {
+        return Err(FileError::validation(file_name, "no laps were found"));
+    }
 
-    let grouped = session_data.group_laps_by_driver();
+    let date = read_acc_file_date(file_name);
+    let mut race_result_files = vec![];
 
     for (session_index, (driver_index, laps)) in (1001..).zip(grouped) {
-        race_result_files.push(Some(RaceResultFile::new(
+        race_result_files.push(RaceResultFile::new(
             &session_data.track_name,
             AccLap::convert_to_laptimes(laps),
             RaceMetadata::new(
@@ -81,80 +102,655 @@ pub fn read_acc_laptimes_file(file_name: &str) -> Vec<Option<RaceResultFile>> {
                 session_index,
                 session_data.calculate_race_position(driver_index),
             ),
-            RaceDate::today(),
-        )));
+            date.clone(),
+        ));
+    }
+
+    Ok(race_result_files)
+}
+
+// The results don't include a date, ACC names them after when the session was saved
+// e.g 251019_143025_R.json and renamed files fall back to when they were last modified
+fn read_acc_file_date(file_name: &str) -> RaceDate {
+    let file_stem = Path::new(file_name)
+        .file_stem()
+        .and_then(|file_stem| file_stem.to_str())
+        .unwrap_or_default();
+
+    if let Some(date) = file_stem
+        .split('_')
+        .next()
+        .and_then(|date| NaiveDate::parse_from_str(date, "%y%m%d").ok())
+    {
+        return RaceDate::new(date.day(), date.month(), date.year());
+    }
+
+    fs::metadata(file_name)
+        .and_then(|metadata| metadata.modified())
+        .map(RaceDate::from_system_time)
+        .unwrap_or_else(|_| RaceDate::today())
+}
+
+pub fn read_timing_system_laptimes_file(
+    file_name: &str,
+    driver_name: &str,
+) -> Result<Vec<RaceResultFile>, FileError> {
+    let contents = read_file_contents(file_name)?;
+
+    let Some(timing_system_session) = TimingSystemSession::parse(&contents) else {
+        return Err(FileError::validation(
+            file_name,
+            "not a recognised timing system export",
+        ));
+    };
+
+    let mut track_name = timing_system_session.track_name.as_str();
+    if track_name.is_empty() {
+        track_name = "Default";
+    }
+
+    let date = timing_system_session
+        .date
+        .clone()
+        .unwrap_or_else(RaceDate::today);
+
+    let Some(laps) = timing_system_session.find_driver_laps(driver_name) else {
+        return Err(FileError::validation(
+            file_name,
+            "the driver profile name was not found in the lap chart",
+        ));
+    };
+
+    let kart_number = laps
+        .first()
+        .map(|lap| lap.kart_number.clone())
+        .unwrap_or_default();
+
+    Ok(vec![RaceResultFile::new(
+        track_name,
+        TimingSystemSession::convert_to_laptimes(&laps),
+        RaceMetadata::new(
+            &timing_system_session.session_name,
+            Default::default(),
+            &format!("Kart {kart_number}"),
+            Default::default(),
+            &format!(
+                "Imported from {}",
+                timing_system_session.timing_system.name()
+            ),
+        ),
+        Session::new(
+            timing_system_session.session_id(),
+            TimingSystemSession::calculate_race_position(&laps),
+        ),
+        date,
+    )])
+}
+
+pub fn read_data_logger_laptimes_file(file_name: &str) -> Result<RaceResultFile, FileError> {
+    let contents = read_file_contents(file_name)?;
+
+    let Some(data_logger_session) = DataLoggerSession::parse(&contents) else {
+        return Err(FileError::validation(
+            file_name,
+            "not a recognised data logger export",
+        ));
+    };
+
+    if data_logger_session.laps.is_empty() {
+        return Err(FileError::validation(file_name, "no laps were found"));
+    }
+
+    let mut track_name = data_logger_session.track_name.as_str();
+    if track_name.is_empty() {
+        track_name = "Default";
     }
 
-    race_result_files
+    let mut race_file = RaceResultFile::new(
+        track_name,
+        data_logger_session.convert_to_laptimes(),
+        RaceMetadata::new(
+            Default::default(),
+            Default::default(),
+            &data_logger_session.kart_name,
+            Default::default(),
+            &format!("Imported from {}", data_logger_session.data_logger.name()),
+        ),
+        Session::new(data_logger_session.session_id(), 1),
+        data_logger_session
+            .date
+            .clone()
+            .unwrap_or_else(RaceDate::today),
+    );
+
+    race_file.lap_channels = data_logger_session.convert_to_lap_channels();
+
+    if !data_logger_session.session_time.is_empty() {
+        race_file.session_time = Some(data_logger_session.session_time);
+    }
+
+    Ok(race_file)
 }
 
-pub fn read_laptimes_file(file_name: &str) -> Option<RaceResultFile> {
-    let contents = get_file_contents(file_name);
+pub fn read_gps_laptimes_file(file_name: &str) -> Result<RaceResultFile, FileError> {
+    let contents = read_file_contents(file_name)?;
+
+    let Some(gps_session) = GpsSession::parse(&contents) else {
+        return Err(FileError::validation(
+            file_name,
+            "not a recognised GPS export",
+        ));
+    };
+
+    let laptimes = gps_session.convert_to_laptimes();
 
-    if contents.is_empty() {
-        return None;
+    if laptimes.is_empty() {
+        return Err(FileError::validation(file_name, "no laps were found"));
+    }
+
+    let mut track_name = gps_session.track_name.as_str();
+    if track_name.is_empty() {
+        track_name = "Default";
+    }
+
+    let mut race_file = RaceResultFile::new(
+        track_name,
+        laptimes,
+        RaceMetadata::new(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            &format!("Imported from {}", gps_session.gps_source.name()),
+        ),
+        Session::new(gps_session.session_id(), 1),
+        gps_session.date.clone().unwrap_or_else(RaceDate::today),
+    );
+
+    race_file.gps_trace = gps_session.simplify_trace(MAXIMUM_GPS_TRACE_POINTS);
+
+    if !gps_session.session_time.is_empty() {
+        race_file.session_time = Some(gps_session.session_time);
     }
 
-    let laptimes: Vec<String> = contents
-        .lines()
-        .map(|line| line.trim().trim_end_matches(',').to_string())
-        .map(|line| {
-            line.chars()
-                .filter(|char| char.is_ascii_digit() || *char == '.' || *char == ':')
-                .collect::<String>()
+    Ok(race_file)
+}
+
+pub fn read_csv_laptimes_file(
+    file_name: &str,
+    csv_column_mapping: &CsvColumnMapping,
+) -> Result<RaceResultFile, FileError> {
+    let contents = read_file_contents(file_name)?;
+
+    csv_column_mapping
+        .convert_to_race_file(&contents)
+        .ok_or_else(|| {
+            FileError::validation(file_name, "no laptimes were found in the mapped columns")
         })
-        .filter(|line| line.chars().any(|c| c.is_ascii_digit()))
-        .collect();
+}
+
+pub fn read_laptimes_file(file_name: &str) -> Result<RaceResultFile, FileError> {
+    let contents = read_file_contents(file_name)?;
+
+    let laptimes = extract_times(file_name, &contents)?;
 
     if laptimes.is_empty() {
-        return None;
+        return Err(FileError::validation(file_name, "no laptimes were found"));
     }
 
-    Some(RaceResultFile::new_from_laptime_file(laptimes))
+    Ok(RaceResultFile::new_from_laptime_file(laptimes))
 }
 
-pub fn read_race_file(file_name: &str) -> Option<RaceResultFile> {
-    let contents = get_file_contents(file_name);
+pub fn read_timing_sheet_file(file_name: &str) -> Result<Vec<String>, FileError> {
+    let times = extract_times(file_name, &read_file_contents(file_name)?)?;
 
-    if contents.is_empty() {
-        return None;
+    if times.is_empty() {
+        return Err(FileError::validation(file_name, "no times were found"));
     }
 
-    toml::from_str(&contents).unwrap_or_default()
+    Ok(times)
 }
 
-pub fn upsert_application_state(file_path: &str, karting_time: &KartingTimeFile) {
-    let mut file = match File::create(file_path) {
-        Ok(file) => file,
-        Err(_) => {
-            println!("{FILE_ERROR}");
-            return;
+// Lines without a digit are headings or file structure, every other line has to be a time
+fn extract_times(file_name: &str, contents: &str) -> Result<Vec<String>, FileError> {
+    let mut times = vec![];
+
+    for (line_number, line) in (1..).zip(contents.lines()) {
+        let time = line.trim_start();
+        let mut column = line.chars().count() - time.chars().count();
+
+        let time = time.trim_end().trim_end_matches(',').trim_end();
+
+        if time.starts_with('"') {
+            column += 1;
+        }
+
+        let time = time.trim_matches('"');
+
+        if !time.chars().any(|char| char.is_ascii_digit()) {
+            continue;
         }
+
+        parse_laptime(time, None)
+            .map_err(|error| FileError::laptime(file_name, line_number, column, &error))?;
+
+        times.push(time.to_string());
+    }
+
+    Ok(times)
+}
+
+// Picks the importer from the file extension, CSV files are tried as data logger, GPS then timing system exports
+pub fn read_watched_file(
+    file_name: &str,
+    driver_name: &str,
+) -> Result<Vec<RaceResultFile>, FileError> {
+    let extension = Path::new(file_name)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+
+    match extension.as_str() {
+        "json" => read_acc_laptimes_file(file_name),
+        "toml" => read_race_file(file_name).map(|race_file| vec![race_file]),
+        "vbo" | "gpx" => read_gps_laptimes_file(file_name).map(|race_file| vec![race_file]),
+        "csv" => read_data_logger_laptimes_file(file_name)
+            .or_else(|_| read_gps_laptimes_file(file_name))
+            .map(|race_file| vec![race_file])
+            .or_else(|_| read_timing_system_laptimes_file(file_name, driver_name)),
+        _ => Ok(vec![]),
+    }
+}
+
+pub fn upsert_telemetry_replay(
+    file_path: &str,
+    game: TelemetryGame,
+    packets: &[Vec<u8>],
+) -> Result<(), FileError> {
+    let mut file = File::create(file_path).map_err(|error| FileError::io(file_path, &error))?;
+
+    file.write_all(&write_packet_replay(game, packets))
+        .map_err(|error| FileError::io(file_path, &error))
+}
+
+pub fn read_telemetry_replay(file_name: &str) -> Result<(TelemetryGame, Vec<Vec<u8>>), FileError> {
+    let mut contents = vec![];
+
+    File::open(file_name)
+        .and_then(|mut file| file.read_to_end(&mut contents))
+        .map_err(|error| FileError::io(file_name, &error))?;
+
+    read_packet_replay(&contents)
+        .ok_or_else(|| FileError::validation(file_name, "not a telemetry replay"))
+}
+
+pub fn upsert_text_file(file_path: &str, contents: &str) -> Result<(), FileError> {
+    fs::write(file_path, contents).map_err(|error| FileError::io(file_path, &error))
+}
+
+pub fn read_race_file(file_name: &str) -> Result<RaceResultFile, FileError> {
+    let contents = read_file_contents(file_name)?;
+
+    toml::from_str(&contents).map_err(|error| FileError::toml(file_name, &contents, &error))
+}
+
+pub fn upsert_application_state(
+    file_path: &str,
+    karting_time: &KartingTimeFile,
+) -> Result<(), FileError> {
+    let toml = toml::to_string_pretty(&karting_time).unwrap_or_default();
+
+    backup_file(file_path)?;
+
+    write_file_atomically(file_path, &toml)
+}
+
+// Written beside the target then renamed over it, so a failed write leaves the old file intact
+fn write_file_atomically(file_path: &str, contents: &str) -> Result<(), FileError> {
+    let temporary_file_path = format!("{file_path}.tmp");
+
+    let result = File::create(&temporary_file_path)
+        .and_then(|mut file| {
+            file.write_all(contents.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&temporary_file_path, file_path));
+
+    if result.is_err() {
+        fs::remove_file(&temporary_file_path).unwrap_or_default();
+    }
+
+    result.map_err(|error| FileError::io(file_path, &error))
+}
+
+fn backup_file(file_path: &str) -> Result<(), FileError> {
+    let path = Path::new(file_path);
+
+    let (Some(backup_folder), Some(file_stem)) = (
+        get_backup_folder(path),
+        path.file_stem().and_then(|file_stem| file_stem.to_str()),
+    ) else {
+        return Ok(());
+    };
+
+    if !path.is_file() {
+        return Ok(());
+    }
+
+    let timestamp = Local::now().format("%Y%m%d-%H%M%S%3f");
+    let backup_file_path = backup_folder.join(format!("{file_stem}-{timestamp}.toml"));
+
+    fs::create_dir_all(&backup_folder)
+        .and_then(|_| fs::copy(path, &backup_file_path))
+        .map_err(|error| FileError::io(&backup_file_path.to_string_lossy(), &error))?;
+
+    let backup_file_paths = get_backup_file_paths(&backup_folder, file_stem);
+    let excess_backups = backup_file_paths.len().saturating_sub(MAXIMUM_BACKUPS);
+
+    for backup_file_path in backup_file_paths.iter().take(excess_backups) {
+        fs::remove_file(backup_file_path).unwrap_or_default();
+    }
+
+    Ok(())
+}
+
+// Backups are kept in a folder next to the profile file
+pub fn get_backup_folder(profile_path: &Path) -> Option<PathBuf> {
+    profile_path
+        .parent()
+        .map(|parent| parent.join(BACKUP_FOLDER))
+}
+
+// Timestamps sort by name, so the oldest backups come first
+fn get_backup_file_paths(backup_folder: &Path, file_stem: &str) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(backup_folder) else {
+        return vec![];
+    };
+
+    let prefix = format!("{file_stem}-");
+
+    let mut backup_file_paths: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|file_name| file_name.to_str())
+                .and_then(|file_name| file_name.strip_prefix(&prefix))
+                .and_then(|file_name| file_name.strip_suffix(".toml"))
+                .is_some_and(is_backup_timestamp)
+        })
+        .collect();
+
+    backup_file_paths.sort();
+
+    backup_file_paths
+}
+
+// Only an exact YYYYMMDD-HHMMSSmmm timestamp is a backup, so a profile named race-2024 isn't
+// taken for a backup of race
+fn is_backup_timestamp(timestamp: &str) -> bool {
+    let Some((date, time)) = timestamp.split_once('-') else {
+        return false;
     };
 
+    date.len() == 8
+        && time.len() == 9
+        && date
+            .chars()
+            .chain(time.chars())
+            .all(|character| character.is_ascii_digit())
+}
+
+pub fn read_application_state(file_name: &str) -> Result<KartingTimeFile, FileError> {
+    let contents = read_file_contents(file_name)?;
+
+    toml::from_str(&contents).map_err(|error| FileError::toml(file_name, &contents, &error))
+}
+
+// The races are written to a temporary folder that replaces the races folder once every race is
+// written, so a failed save leaves the previous races in place
+pub fn upsert_profile_folder(
+    folder_location: &str,
+    karting_time: &KartingTimeFile,
+) -> Result<(), FileError> {
+    let races_folder = format!("{folder_location}/{PROFILE_RACES_FOLDER}");
+    let temporary_races_folder = format!("{races_folder}.tmp");
+
+    fs::remove_dir_all(&temporary_races_folder).unwrap_or_default();
+
+    let result = upsert_profile_races(&temporary_races_folder, karting_time)
+        .and_then(|_| replace_folder(&temporary_races_folder, &races_folder));
+
+    if result.is_err() {
+        fs::remove_dir_all(&temporary_races_folder).unwrap_or_default();
+    }
+
+    result?;
+
+    let manifest_file_name = format!("{folder_location}/{PROFILE_MANIFEST_FILE}");
+
+    let toml = toml::to_string_pretty(&ProfileManifestFile::new(karting_time)).unwrap_or_default();
+
+    write_file_atomically(&manifest_file_name, &toml)
+}
+
+fn upsert_profile_races(
+    races_folder: &str,
+    karting_time: &KartingTimeFile,
+) -> Result<(), FileError> {
+    fs::create_dir_all(races_folder).map_err(|error| FileError::io(races_folder, &error))?;
+
+    for race_file in &karting_time.driver_profile_file.races {
+        let race_file_name = format!(
+            "{races_folder}/{}.toml",
+            sanitise_file_name(&race_file.unique_race_identifier())
+        );
+
+        let toml = toml::to_string_pretty(race_file).unwrap_or_default();
+
+        fs::write(&race_file_name, toml).map_err(|error| FileError::io(&race_file_name, &error))?;
+    }
+
+    Ok(())
+}
+
+// The old folder is kept until the new one is in place so it can be put back if the rename fails
+fn replace_folder(new_folder: &str, folder: &str) -> Result<(), FileError> {
+    let old_folder = format!("{folder}.old");
+
+    fs::remove_dir_all(&old_folder).unwrap_or_default();
+
+    if Path::new(folder).exists() {
+        fs::rename(folder, &old_folder).map_err(|error| FileError::io(folder, &error))?;
+    }
+
+    if let Err(error) = fs::rename(new_folder, folder) {
+        fs::rename(&old_folder, folder).unwrap_or_default();
+        return Err(FileError::io(folder, &error));
+    }
+
+    fs::remove_dir_all(&old_folder).unwrap_or_default();
+
+    Ok(())
+}
+
+// Track names are typed by the user so characters that aren't allowed in file names, and trailing
+// dots and spaces, are percent encoded, which keeps the file names of different races apart
+fn sanitise_file_name(file_name: &str) -> String {
+    let trimmed_length = file_name.trim_end_matches(['.', ' ']).len();
+
+    file_name
+        .char_indices()
+        .map(|(index, character)| match character {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '%' => {
+                format!("%{:02X}", character as u32)
+            }
+            character if character.is_control() || index >= trimmed_length => {
+                format!("%{:02X}", character as u32)
+            }
+            character => character.to_string(),
+        })
+        .collect()
+}
+
+pub fn read_profile_folder(folder_location: &str) -> Result<KartingTimeFile, FileError> {
+    let manifest_file_name = format!("{folder_location}/{PROFILE_MANIFEST_FILE}");
+    let contents = read_file_contents(&manifest_file_name)?;
+
+    let manifest: ProfileManifestFile = toml::from_str(&contents)
+        .map_err(|error| FileError::toml(&manifest_file_name, &contents, &error))?;
+
+    let races = get_profile_race_file_paths(&find_profile_races_folder(folder_location)?)?
+        .iter()
+        .map(|race_file_path| read_race_file(&race_file_path.to_string_lossy()))
+        .collect::<Result<Vec<RaceResultFile>, FileError>>()?;
+
+    Ok(manifest.convert_to_karting_time_file(races))
+}
+
+pub fn read_driver_profile(file_name: &str) -> Result<KartingTimeFile, FileError> {
+    if Path::new(file_name).is_dir() {
+        read_profile_folder(file_name)
+    } else {
+        read_application_state(file_name)
+    }
+}
+
+pub fn convert_profile_file_to_folder(
+    file_name: &str,
+    folder_location: &str,
+) -> Result<(), FileError> {
+    let karting_time = read_application_state(file_name)?;
+
+    upsert_profile_folder(folder_location, &karting_time)
+}
+
+pub fn convert_profile_folder_to_file(
+    folder_location: &str,
+    file_path: &str,
+) -> Result<(), FileError> {
+    let karting_time = read_profile_folder(folder_location)?;
+
+    upsert_application_state(file_path, &karting_time)
+}
+
+// A save that stopped while swapping the races folders leaves the previous races in races.old, and
+// the new races in races.tmp once every race has been written
+fn find_profile_races_folder(folder_location: &str) -> Result<String, FileError> {
+    let races_folder = format!("{folder_location}/{PROFILE_RACES_FOLDER}");
+    let temporary_races_folder = format!("{races_folder}.tmp");
+    let old_races_folder = format!("{races_folder}.old");
+
+    if Path::new(&races_folder).is_dir() {
+        return Ok(races_folder);
+    }
+
+    if !Path::new(&old_races_folder).is_dir() {
+        return Err(FileError::validation(
+            &races_folder,
+            "the races folder is missing",
+        ));
+    }
+
+    match Path::new(&temporary_races_folder).is_dir() {
+        true => Ok(temporary_races_folder),
+        false => Ok(old_races_folder),
+    }
+}
+
+fn get_profile_race_file_paths(races_folder: &str) -> Result<Vec<PathBuf>, FileError> {
+    let entries =
+        fs::read_dir(races_folder).map_err(|error| FileError::io(races_folder, &error))?;
+
+    let mut race_file_paths = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<PathBuf>, std::io::Error>>()
+        .map_err(|error| FileError::io(races_folder, &error))?;
+
+    race_file_paths.retain(|path| {
+        path.extension()
+            .is_some_and(|extension| extension == "toml")
+    });
+
+    race_file_paths.sort();
+
+    Ok(race_file_paths)
+}
+
+pub fn get_recovery_file_path() -> Option<String> {
+    let recovery_file_path = dirs::data_dir()?.join(RECOVERY_FOLDER).join(RECOVERY_FILE);
+
+    recovery_file_path.to_str().map(|path| path.to_string())
+}
+
+pub fn find_recovery_file() -> Option<String> {
+    get_recovery_file_path().filter(|recovery_file_path| Path::new(recovery_file_path).is_file())
+}
+
+pub fn upsert_recovery_file(
+    recovery_file_path: &str,
+    karting_time: &KartingTimeFile,
+) -> Result<(), FileError> {
+    if let Some(recovery_folder) = Path::new(recovery_file_path).parent() {
+        fs::create_dir_all(recovery_folder)
+            .map_err(|error| FileError::io(recovery_file_path, &error))?;
+    }
+
+    // Autosaves run every minute so they aren't backed up
     let toml = toml::to_string_pretty(&karting_time).unwrap_or_default();
 
-    write!(file, "{toml}").unwrap_or_default()
+    write_file_atomically(recovery_file_path, &toml)
+}
+
+pub fn delete_recovery_file(recovery_file_path: &str) {
+    if Path::new(recovery_file_path).is_file() {
+        fs::remove_file(recovery_file_path).unwrap_or_default();
+    }
+}
+
+pub fn get_settings_file_path() -> Option<String> {
+    let settings_file_path = dirs::config_dir()?
+        .join(RECOVERY_FOLDER)
+        .join(SETTINGS_FILE);
+
+    settings_file_path.to_str().map(|path| path.to_string())
+}
+
+pub fn find_settings_file() -> Option<String> {
+    get_settings_file_path().filter(|settings_file_path| Path::new(settings_file_path).is_file())
 }
 
-pub fn read_application_state(file_name: &str) -> Option<KartingTimeFile> {
-    let contents = get_file_contents(file_name);
+pub fn read_settings_file(settings_file_path: &str) -> Result<SettingsFile, FileError> {
+    let contents = read_file_contents(settings_file_path)?;
+
+    toml::from_str(&contents)
+        .map_err(|error| FileError::toml(settings_file_path, &contents, &error))
+}
 
-    if contents.is_empty() {
-        return None;
+pub fn upsert_settings_file(
+    settings_file_path: &str,
+    settings_file: &SettingsFile,
+) -> Result<(), FileError> {
+    if let Some(settings_folder) = Path::new(settings_file_path).parent() {
+        fs::create_dir_all(settings_folder)
+            .map_err(|error| FileError::io(settings_file_path, &error))?;
     }
 
-    toml::from_str(&contents).unwrap_or_default()
+    let toml = toml::to_string_pretty(settings_file).unwrap_or_default();
+
+    write_file_atomically(settings_file_path, &toml)
 }
 
-fn get_file_contents(file_name: &str) -> String {
-    let mut contents = String::new();
+pub fn read_file_contents(file_name: &str) -> Result<String, FileError> {
+    let contents =
+        fs::read_to_string(file_name).map_err(|error| FileError::io(file_name, &error))?;
 
-    if let Ok(mut file) = File::open(file_name) {
-        file.read_to_string(&mut contents).unwrap_or_default();
+    if contents.trim().is_empty() {
+        return Err(FileError::validation(file_name, "the file is empty"));
     }
 
-    contents
+    Ok(contents)
 }
 
 #[cfg(test)]
@@ -162,7 +758,9 @@ mod file_integration_should {
     use super::*;
     use crate::{
         controllers::file::test_file_guard::TestFileGuard,
+        data_models::driver_profile_file::DriverProfileFile,
         models::{
+            application::time_format::{DecimalPlaces, TimeStyle},
             date::RaceDate,
             driver::session_information::{
                 lap::Lap, race_information::RaceInformation, race_metadata::RaceMetadata,
@@ -203,9 +801,10 @@ mod file_integration_should {
         // When
         let _guard = TestFileGuard::new(file_name);
 
-        upsert_races(file_location, &races);
+        let result = upsert_races(file_location, &races);
 
         // Then
+        assert!(result.is_err());
         assert!(fs::metadata(file_name).is_err());
     }
 
@@ -240,7 +839,7 @@ mod file_integration_should {
         )];
 
         // When
-        upsert_races(file_location, &races);
+        upsert_races(file_location, &races).unwrap();
 
         // Then
         let file_name =
@@ -274,9 +873,10 @@ mod file_integration_should {
         // When
         let _guard = TestFileGuard::new(file_name);
 
-        upsert_html_races(file_location, &driver_profile);
+        let result = upsert_html_races(file_location, &driver_profile, TimeFormat::default());
 
         // Then
+        assert!(result.is_err());
         assert!(fs::metadata(file_name).is_err());
     }
 
@@ -301,20 +901,43 @@ mod file_integration_should {
         // When
         let _guard = TestFileGuard::new(&file_name);
 
-        upsert_html_races(file_location, &driver_profile);
+        upsert_html_races(file_location, &driver_profile, TimeFormat::default()).unwrap();
 
         // Then
         assert!(fs::metadata(&file_name).is_ok());
         assert_ne!(fs::metadata(&file_name).unwrap().len(), 0);
     }
 
+    fn modified_date(file_name: &str) -> RaceDate {
+        RaceDate::from_system_time(fs::metadata(file_name).unwrap().modified().unwrap())
+    }
+
+    #[test]
+    fn test_read_acc_laptime_file_dated_from_file_name() {
+        // Given
+        let folder = "./acc_file_date_test";
+        let _test_file_guard = TestFileGuard::new(folder);
+        fs::create_dir_all(folder).unwrap();
+        let file_name = format!("{folder}/231118_143025_FP.json");
+        fs::copy("./file_io_test_files/acc_file_1.json", &file_name).unwrap();
+
+        // When
+        let race_files = read_acc_laptimes_file(&file_name).unwrap();
+
+        // Then
+        pretty_assertions::assert_eq!(
+            (18, 11, 2023),
+            (race_files[0].day, race_files[0].month, race_files[0].year)
+        );
+    }
+
     #[test]
     fn test_read_non_existent_acc_laptime_file() {
         // When
-        let race_file = read_acc_laptimes_file("");
+        let race_files = read_acc_laptimes_file("");
 
         // Then
-        assert!(race_file.is_empty());
+        assert!(race_files.is_err());
     }
 
     #[test]
@@ -338,13 +961,13 @@ mod file_integration_should {
             ],
             RaceMetadata::new("FP", "N/A", "", "", "Imported from ACC"),
             Session::new(1001, 1),
-            RaceDate::today(),
+            modified_date("./file_io_test_files/acc_file_1.json"),
         );
 
         let file_name = "./file_io_test_files/acc_file_1.json";
 
         // When
-        let race_files = read_acc_laptimes_file(file_name);
+        let race_files = read_acc_laptimes_file(file_name).unwrap();
 
         // Then
         assert!(
@@ -354,8 +977,7 @@ mod file_integration_should {
         );
 
         pretty_assertions::assert_eq!(1, race_files.len());
-        assert!(race_files[0].is_some(), "Unexpectedly returned None");
-        pretty_assertions::assert_eq!(expected_race_file, race_files[0].clone().unwrap());
+        pretty_assertions::assert_eq!(expected_race_file, race_files[0].clone());
     }
 
     #[test]
@@ -363,7 +985,7 @@ mod file_integration_should {
         // Given
         let track_name = "silverstone";
         let race_meta_data = RaceMetadata::new("FP", "N/A", "", "", "Imported from ACC");
-        let race_date = RaceDate::today();
+        let race_date = modified_date("./file_io_test_files/acc_file_2.json");
 
         let expected_race_file_1 = RaceResultFile::new(
             track_name,
@@ -405,7 +1027,7 @@ mod file_integration_should {
         let file_name = "./file_io_test_files/acc_file_2.json";
 
         // When
-        let race_files = read_acc_laptimes_file(file_name);
+        let race_files = read_acc_laptimes_file(file_name).unwrap();
 
         // Then
         assert!(
@@ -414,47 +1036,296 @@ mod file_integration_should {
             file_name
         );
         pretty_assertions::assert_eq!(3, race_files.len());
-        assert!(race_files[0].is_some(), "Unexpectedly returned None");
-        assert!(race_files[1].is_some(), "Unexpectedly returned None");
-        assert!(race_files[2].is_some(), "Unexpectedly returned None");
-        pretty_assertions::assert_eq!(5, race_files[0].clone().unwrap().laptimes.len());
-        pretty_assertions::assert_eq!(4, race_files[1].clone().unwrap().laptimes.len());
-        pretty_assertions::assert_eq!(3, race_files[2].clone().unwrap().laptimes.len());
-        pretty_assertions::assert_eq!(expected_race_file_1, race_files[0].clone().unwrap());
-        pretty_assertions::assert_eq!(expected_race_file_2, race_files[1].clone().unwrap());
-        pretty_assertions::assert_eq!(expected_race_file_3, race_files[2].clone().unwrap());
+        pretty_assertions::assert_eq!(5, race_files[0].clone().laptimes.len());
+        pretty_assertions::assert_eq!(4, race_files[1].clone().laptimes.len());
+        pretty_assertions::assert_eq!(3, race_files[2].clone().laptimes.len());
+        pretty_assertions::assert_eq!(expected_race_file_1, race_files[0].clone());
+        pretty_assertions::assert_eq!(expected_race_file_2, race_files[1].clone());
+        pretty_assertions::assert_eq!(expected_race_file_3, race_files[2].clone());
     }
 
     #[test]
-    fn test_read_non_existent_laptime_file() {
+    fn test_read_non_existent_data_logger_laptime_file() {
         // When
-        let race_file = read_laptimes_file("");
+        let race_file = read_data_logger_laptimes_file("");
 
         // Then
-        assert!(race_file.is_none());
+        assert!(race_file.is_err());
     }
 
     #[rstest]
     #[case(
-        "./file_io_test_files/laptime_file_test.txt".to_string(), 
-        vec!["2:00.6".to_string(),
-            "120.7".to_string(),
-            "120.8".to_string(),
-            "120.9".to_string()]
+        "./file_io_test_files/aim_mychron_test.csv",
+        "Whilton Mill",
+        "AiM MyChron",
+        RaceDate::new(15, 3, 2025),
+        1032,
+        3
     )]
     #[case(
-        "./file_io_test_files/laptime_file_test.csv".to_string(), 
-        vec!["2:00.6".to_string(),
-            "120.7".to_string(),
-            "120.8".to_string(),
-            "120.9".to_string()]
+        "./file_io_test_files/alfano_test.csv",
+        "PFI",
+        "Alfano",
+        RaceDate::new(2, 8, 2025),
+        1405,
+        2
     )]
+    fn test_read_data_logger_laptime_file(
+        #[case] file_name: &str,
+        #[case] track_name: &str,
+        #[case] data_logger: &str,
+        #[case] date: RaceDate,
+        #[case] session_id: u32,
+        #[case] number_of_laps: usize,
+    ) {
+        // When
+        let race_file = read_data_logger_laptimes_file(file_name).unwrap();
+
+        // Then
+        pretty_assertions::assert_eq!(track_name, race_file.track_name);
+        pretty_assertions::assert_eq!(
+            Some(format!("Imported from {data_logger}")),
+            race_file.notes
+        );
+        pretty_assertions::assert_eq!(
+            date,
+            RaceDate::new(race_file.day, race_file.month, race_file.year)
+        );
+        pretty_assertions::assert_eq!(session_id, race_file.session_id);
+        pretty_assertions::assert_eq!(number_of_laps, race_file.laptimes.len());
+        pretty_assertions::assert_eq!(number_of_laps, race_file.lap_channels.len());
+        assert!(race_file.session_time.is_some());
+        assert!(
+            race_file
+                .lap_channels
+                .iter()
+                .all(|lap_channels| lap_channels.max_rpm.is_some()
+                    && lap_channels.exhaust_gas_temperature.is_some())
+        );
+    }
+
+    #[test]
+    fn test_read_non_existent_gps_laptime_file() {
+        // When
+        let race_file = read_gps_laptimes_file("");
+
+        // Then
+        assert!(race_file.is_err());
+    }
+
+    #[rstest]
     #[case(
-        "./file_io_test_files/laptime_file_test.md".to_string(), 
-        vec!["2:00.6".to_string(),
-            "120.7".to_string(),
-            "120.8".to_string(),
-            "120.9".to_string()]
+        "./file_io_test_files/racechrono_test.csv",
+        "Imported from RaceChrono",
+        2,
+        1032
+    )]
+    #[case(
+        "./file_io_test_files/harrys_laptimer_test.csv",
+        "Imported from Harry's LapTimer",
+        2,
+        1405
+    )]
+    #[case("./file_io_test_files/vbox_test.vbo", "Imported from VBOX", 2, 1032)]
+    #[case("./file_io_test_files/gpx_test.gpx", "Imported from GPX", 2, 1405)]
+    fn test_read_gps_laptime_file(
+        #[case] file_name: &str,
+        #[case] notes: &str,
+        #[case] number_of_laps: usize,
+        #[case] session_id: u32,
+    ) {
+        // When
+        let race_file = read_gps_laptimes_file(file_name).unwrap();
+
+        // Then
+        pretty_assertions::assert_eq!(Some(notes.to_string()), race_file.notes);
+        pretty_assertions::assert_eq!(number_of_laps, race_file.laptimes.len());
+        pretty_assertions::assert_eq!(session_id, race_file.session_id);
+        assert!(!race_file.gps_trace.is_empty());
+        assert!(race_file.session_time.is_some());
+    }
+
+    #[test]
+    fn test_read_non_existent_timing_system_laptime_file() {
+        // When
+        let race_files = read_timing_system_laptimes_file("", "Jack Jackson");
+
+        // Then
+        assert!(race_files.is_err());
+    }
+
+    #[rstest]
+    #[case(
+        "./file_io_test_files/alpha_timing_test.csv",
+        "Alpha Timing",
+        "Heat 2",
+        2,
+        2
+    )]
+    #[case(
+        "./file_io_test_files/apex_timing_test.csv",
+        "Apex Timing",
+        "Final",
+        1,
+        2
+    )]
+    #[case(
+        "./file_io_test_files/mylaps_speedhive_test.csv",
+        "MyLaps Speedhive",
+        "Race 1",
+        1402,
+        2
+    )]
+    fn test_read_timing_system_laptime_file_for_driver(
+        #[case] file_name: &str,
+        #[case] timing_system: &str,
+        #[case] session_type: &str,
+        #[case] session_id: u32,
+        #[case] race_position: u32,
+    ) {
+        // Given
+        let expected_race_file = RaceResultFile::new(
+            "Three Sisters",
+            vec![
+                "48.667".to_string(),
+                "43.434".to_string(),
+                "1:02.564".to_string(),
+            ],
+            RaceMetadata::new(
+                session_type,
+                Default::default(),
+                "Kart 12",
+                Default::default(),
+                &format!("Imported from {timing_system}"),
+            ),
+            Session::new(session_id, race_position),
+            RaceDate::new(18, 11, 2023),
+        );
+
+        // When
+        let race_files = read_timing_system_laptimes_file(file_name, "Jack Jackson").unwrap();
+
+        // Then
+        assert!(
+            std::path::Path::new(&file_name).is_file(),
+            "Expected test file to exist at path: {}",
+            file_name
+        );
+        pretty_assertions::assert_eq!(1, race_files.len());
+        pretty_assertions::assert_eq!(expected_race_file, race_files[0].clone());
+    }
+
+    #[test]
+    fn test_read_timing_system_laptime_file_without_the_driver() {
+        // Given
+        let file_name = "./file_io_test_files/alpha_timing_test.csv";
+
+        // When
+        let race_files = read_timing_system_laptimes_file(file_name, "Racer");
+
+        // Then
+        pretty_assertions::assert_eq!(
+            Err(FileError::validation(
+                file_name,
+                "the driver profile name was not found in the lap chart"
+            )),
+            race_files
+        );
+    }
+
+    #[test]
+    fn test_read_non_existent_csv_laptime_file() {
+        // When
+        let race_file = read_csv_laptimes_file("", &CsvColumnMapping::default());
+
+        // Then
+        assert!(race_file.is_err());
+    }
+
+    #[test]
+    fn test_read_csv_laptime_file() {
+        // Given
+        let file_name = "./file_io_test_files/csv_mapping_test.csv";
+        let csv_column_mapping = CsvColumnMapping {
+            has_header_row: true,
+            lap_number_column: 1,
+            laptime_column: 2,
+            sector_columns: vec![3, 4, 5],
+            validity_column: 6,
+            ..Default::default()
+        };
+        let mut expected_race_file = RaceResultFile::new_from_laptime_file(vec![
+            "1:02.345".to_string(),
+            "61.002".to_string(),
+        ]);
+        expected_race_file.sector_times = vec![
+            vec!["20.1".to_string(), "21.2".to_string(), "21.045".to_string()],
+            vec!["19.9".to_string(), "20.8".to_string(), "20.302".to_string()],
+        ];
+
+        // When
+        let race_file = read_csv_laptimes_file(file_name, &csv_column_mapping);
+
+        // Then
+        assert!(
+            std::path::Path::new(&file_name).is_file(),
+            "Expected test file to exist at path: {}",
+            file_name
+        );
+        pretty_assertions::assert_eq!(Ok(expected_race_file), race_file);
+    }
+
+    #[test]
+    fn test_read_timing_sheet_file() {
+        // Given
+        let file_name = "./file_io_test_files/transponder_crossings_test.txt";
+
+        // When
+        let times = read_timing_sheet_file(file_name).unwrap();
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![
+                "14:02:11.250".to_string(),
+                "14:02:59.917".to_string(),
+                "14:03:43.351".to_string(),
+                "14:05:11.002".to_string(),
+                "14:05:55.120".to_string(),
+            ],
+            times
+        );
+    }
+
+    #[test]
+    fn test_read_non_existent_laptime_file() {
+        // When
+        let race_file = read_laptimes_file("");
+
+        // Then
+        assert!(race_file.is_err());
+    }
+
+    #[rstest]
+    #[case(
+        "./file_io_test_files/laptime_file_test.txt".to_string(), 
+        vec!["2:00.6".to_string(),
+            "120.7".to_string(),
+            "120.8".to_string(),
+            "120.9".to_string()]
+    )]
+    #[case(
+        "./file_io_test_files/laptime_file_test.csv".to_string(), 
+        vec!["2:00.6".to_string(),
+            "120.7".to_string(),
+            "120.8".to_string(),
+            "120.9".to_string()]
+    )]
+    #[case(
+        "./file_io_test_files/laptime_file_test.md".to_string(), 
+        vec!["2:00.6".to_string(),
+            "120.7".to_string(),
+            "120.8".to_string(),
+            "120.9".to_string()]
     )]
     #[case(
         "./file_io_test_files/laptime_file_test_collection_1.json".to_string(), 
@@ -510,6 +1381,11 @@ mod file_integration_should {
         let expected_race_file = RaceResultFile {
             track_name: "Default".to_string(),
             laptimes,
+            day: 1,
+            month: 1,
+            year: 2000,
+            session_id: 1,
+            race_position: 1,
             ..Default::default()
         };
 
@@ -522,7 +1398,7 @@ mod file_integration_should {
             "Expected test file to exist at path: {}",
             file_name
         );
-        assert!(race_file.is_some(), "Unexpectedly returned None");
+        assert!(race_file.is_ok(), "Unexpectedly returned an error");
         pretty_assertions::assert_eq!(expected_race_file, race_file.unwrap());
     }
 
@@ -532,7 +1408,49 @@ mod file_integration_should {
         let race_file = read_race_file("");
 
         // Then
-        assert!(race_file.is_none());
+        assert!(race_file.is_err());
+    }
+
+    #[test]
+    fn test_read_laptimes_file_reports_where_a_time_is_invalid() {
+        // Given
+        let file_name = "./karting_time_test_malformed_laptimes.txt";
+        let _guard = TestFileGuard::new(file_name);
+        fs::write(file_name, "Lap Times\n1'02,5\n  62.3s,\n  1:x2.5\n").unwrap();
+
+        // When
+        let race_file = read_laptimes_file(file_name);
+
+        // Then
+        pretty_assertions::assert_eq!(
+            Err(FileError::Parse {
+                file_name: file_name.to_string(),
+                line: Some(4),
+                reason: "unexpected 'x' at character 5".to_string(),
+            }),
+            race_file
+        );
+    }
+
+    #[test]
+    fn test_read_malformed_race_file() {
+        // Given
+        let file_name = "./karting_time_test_malformed_race.toml";
+        let _guard = TestFileGuard::new(file_name);
+        fs::write(file_name, "track_name = \"Three Sisters\"\nday = \n").unwrap();
+
+        // When
+        let race_file = read_race_file(file_name);
+
+        // Then
+        pretty_assertions::assert_eq!(
+            Err(FileError::Parse {
+                file_name: file_name.to_string(),
+                line: Some(2),
+                reason: "string values must be quoted, expected literal string".to_string(),
+            }),
+            race_file
+        );
     }
 
     #[test]
@@ -569,7 +1487,7 @@ mod file_integration_should {
         );
 
         // When
-        upsert_races(file_location, &races);
+        upsert_races(file_location, &races).unwrap();
         let file_name =
             "./".to_string() + &races[0].race_information.unique_race_identifier + ".toml";
 
@@ -578,7 +1496,7 @@ mod file_integration_should {
         let race_file = read_race_file(&file_name);
 
         // Then
-        assert!(race_file.is_some(), "Unexpectedly returned None");
+        assert!(race_file.is_ok(), "Unexpectedly returned an error");
         pretty_assertions::assert_eq!(expected_race_file, race_file.unwrap());
     }
 
@@ -617,7 +1535,7 @@ mod file_integration_should {
         );
 
         // When
-        upsert_races(file_location, &races);
+        upsert_races(file_location, &races).unwrap();
         let file_name =
             "./".to_string() + &races[0].race_information.unique_race_identifier + ".toml";
 
@@ -626,7 +1544,7 @@ mod file_integration_should {
         let race_file = read_race_file(&file_name);
 
         // Then
-        assert!(race_file.is_some(), "Unexpectedly returned None");
+        assert!(race_file.is_ok(), "Unexpectedly returned an error");
         pretty_assertions::assert_eq!(expected_race_file, race_file.unwrap());
     }
 
@@ -641,7 +1559,7 @@ mod file_integration_should {
         let karting_time = read_application_state(file_name);
 
         // Then
-        assert!(karting_time.is_none());
+        assert!(karting_time.is_err());
     }
 
     #[test]
@@ -655,9 +1573,10 @@ mod file_integration_should {
         // When
         let _guard = TestFileGuard::new(file_name);
 
-        upsert_application_state(file_name, &karting_time_file);
+        let result = upsert_application_state(file_name, &karting_time_file);
 
         // Then
+        assert!(result.is_err());
         assert!(fs::metadata(file_name).is_err());
     }
 
@@ -670,7 +1589,7 @@ mod file_integration_should {
         // When
         let _guard = TestFileGuard::new(file_name);
 
-        upsert_application_state(file_name, &karting_time_file);
+        upsert_application_state(file_name, &karting_time_file).unwrap();
 
         // Then
         assert!(fs::metadata(file_name).is_ok());
@@ -686,11 +1605,396 @@ mod file_integration_should {
         // When
         let _guard = TestFileGuard::new(file_name);
 
-        upsert_application_state(file_name, &expected_karting_time);
+        upsert_application_state(file_name, &expected_karting_time).unwrap();
         let karting_time = read_application_state(file_name);
 
         // Then
-        assert!(karting_time.is_some(), "Unexpectedly returned None");
+        assert!(karting_time.is_ok(), "Unexpectedly returned an error");
         pretty_assertions::assert_eq!(expected_karting_time, karting_time.unwrap());
     }
+
+    #[test]
+    fn test_read_non_existent_profile_folder() {
+        // Given
+        let folder_location = "./non_existent_profile_folder";
+
+        // When
+        let karting_time = read_profile_folder(folder_location);
+
+        // Then
+        assert!(karting_time.is_err());
+    }
+
+    #[test]
+    fn test_upsert_profile_folder_then_read_acceptance() {
+        // Given
+        let folder_location = "./karting_time_test_profile_folder_1";
+        let race_file = RaceResult::new(
+            RaceInformation::new(
+                "Three Sisters",
+                RaceDate::new(1, 1, 2025),
+                Session::new(1, 1),
+            ),
+            RaceMetadata::default(),
+            vec![Lap::new(1, 50.4), Lap::new(2, 55.5)],
+        )
+        .convert_to_race_file();
+        let mut expected_karting_time =
+            KartingTimeFile::new(DriverProfileFile::new("Jack Jackson", vec![race_file]));
+        expected_karting_time.watched_folders = vec!["./results".to_string()];
+
+        // When
+        let _guard = TestFileGuard::new(folder_location);
+
+        upsert_profile_folder(folder_location, &expected_karting_time).unwrap();
+        let karting_time = read_driver_profile(folder_location);
+
+        // Then
+        assert!(fs::metadata(folder_location.to_string() + "/profile.toml").is_ok());
+        assert!(
+            fs::metadata(
+                folder_location.to_string()
+                    + "/races/Date_2025-1-1_Track_Three Sisters_Session_1.toml"
+            )
+            .is_ok()
+        );
+        assert!(karting_time.is_ok(), "Unexpectedly returned an error");
+        pretty_assertions::assert_eq!(
+            expected_karting_time.convert_to_karting_time(),
+            karting_time.unwrap().convert_to_karting_time()
+        );
+    }
+
+    #[test]
+    fn test_upsert_profile_folder_removes_deleted_races() {
+        // Given
+        let folder_location = "./karting_time_test_profile_folder_2";
+        let race_file_name =
+            folder_location.to_string() + "/races/Date_2025-1-1_Track_Three Sisters_Session_1.toml";
+        let race_file = RaceResult::new(
+            RaceInformation::new(
+                "Three Sisters",
+                RaceDate::new(1, 1, 2025),
+                Session::new(1, 1),
+            ),
+            RaceMetadata::default(),
+            vec![Lap::new(1, 50.4)],
+        )
+        .convert_to_race_file();
+        let mut karting_time =
+            KartingTimeFile::new(DriverProfileFile::new("Jack Jackson", vec![race_file]));
+
+        // When
+        let _guard = TestFileGuard::new(folder_location);
+
+        upsert_profile_folder(folder_location, &karting_time).unwrap();
+        let race_file_saved = fs::metadata(&race_file_name).is_ok();
+
+        karting_time.driver_profile_file.races.clear();
+        upsert_profile_folder(folder_location, &karting_time).unwrap();
+
+        // Then
+        assert!(race_file_saved);
+        assert!(fs::metadata(&race_file_name).is_err());
+    }
+
+    #[test]
+    fn test_upsert_profile_folder_with_unsafe_track_name() {
+        // Given
+        let folder_location = "./karting_time_test_profile_folder_3";
+        let race_file = RaceResult::new(
+            RaceInformation::new(
+                "Lydd: Club/National?",
+                RaceDate::new(1, 1, 2025),
+                Session::new(1, 1),
+            ),
+            RaceMetadata::default(),
+            vec![Lap::new(1, 50.4)],
+        )
+        .convert_to_race_file();
+        let karting_time =
+            KartingTimeFile::new(DriverProfileFile::new("Jack Jackson", vec![race_file]));
+
+        // When
+        let _guard = TestFileGuard::new(folder_location);
+
+        let result = upsert_profile_folder(folder_location, &karting_time);
+
+        // Then
+        assert!(result.is_ok(), "Unexpectedly returned an error");
+        assert!(
+            fs::metadata(
+                folder_location.to_string()
+                    + "/races/Date_2025-1-1_Track_Lydd%3A Club%2FNational%3F_Session_1.toml"
+            )
+            .is_ok()
+        );
+        assert!(fs::metadata(folder_location.to_string() + "/races.tmp").is_err());
+        pretty_assertions::assert_eq!(
+            "Lydd: Club/National?",
+            read_profile_folder(folder_location)
+                .unwrap()
+                .driver_profile_file
+                .races[0]
+                .track_name
+        );
+    }
+
+    #[test]
+    fn test_upsert_profile_folder_keeps_races_apart_by_unsafe_characters() {
+        // Given
+        let folder_location = "./karting_time_test_profile_folder_4";
+        let race_files = ["Lydd?", "Lydd*", "Lydd_", "Lydd%3F"]
+            .iter()
+            .map(|track_name| {
+                RaceResult::new(
+                    RaceInformation::new(track_name, RaceDate::new(1, 1, 2025), Session::new(1, 1)),
+                    RaceMetadata::default(),
+                    vec![Lap::new(1, 50.4)],
+                )
+                .convert_to_race_file()
+            })
+            .collect();
+        let karting_time = KartingTimeFile::new(DriverProfileFile::new("Jack Jackson", race_files));
+
+        // When
+        let _guard = TestFileGuard::new(folder_location);
+
+        upsert_profile_folder(folder_location, &karting_time).unwrap();
+        let karting_time = read_profile_folder(folder_location);
+
+        // Then
+        assert!(karting_time.is_ok(), "Unexpectedly returned an error");
+        pretty_assertions::assert_eq!(4, karting_time.unwrap().driver_profile_file.races.len());
+    }
+
+    #[test]
+    fn test_read_profile_folder_after_an_interrupted_save() {
+        // Given
+        let folder_location = "./karting_time_test_profile_folder_5";
+        let races_folder = folder_location.to_string() + "/races";
+        let race_file = RaceResult::new(
+            RaceInformation::new(
+                "Three Sisters",
+                RaceDate::new(1, 1, 2025),
+                Session::new(1, 1),
+            ),
+            RaceMetadata::default(),
+            vec![Lap::new(1, 50.4)],
+        )
+        .convert_to_race_file();
+        let karting_time =
+            KartingTimeFile::new(DriverProfileFile::new("Jack Jackson", vec![race_file]));
+
+        // When
+        let _guard = TestFileGuard::new(folder_location);
+
+        upsert_profile_folder(folder_location, &karting_time).unwrap();
+
+        fs::rename(&races_folder, races_folder.clone() + ".old").unwrap();
+        let old_races = read_profile_folder(folder_location);
+
+        fs::rename(races_folder.clone() + ".old", races_folder.clone() + ".tmp").unwrap();
+        fs::create_dir(races_folder.clone() + ".old").unwrap();
+        let new_races = read_profile_folder(folder_location);
+
+        fs::remove_dir_all(races_folder.clone() + ".old").unwrap();
+        let missing_races = read_profile_folder(folder_location);
+
+        // Then
+        pretty_assertions::assert_eq!(1, old_races.unwrap().driver_profile_file.races.len());
+        pretty_assertions::assert_eq!(1, new_races.unwrap().driver_profile_file.races.len());
+        pretty_assertions::assert_eq!(
+            Err(FileError::validation(
+                &races_folder,
+                "the races folder is missing"
+            )),
+            missing_races
+        );
+    }
+
+    #[test]
+    fn test_convert_profile_between_file_and_folder_acceptance() {
+        // Given
+        let file_name = "./karting_time_test_convert_profile_1.toml";
+        let converted_file_name = "./karting_time_test_convert_profile_2.toml";
+        let folder_location = "./karting_time_test_profile_folder_3";
+        let race_file = RaceResult::new(
+            RaceInformation::new(
+                "Trafford Park",
+                RaceDate::new(15, 1, 2024),
+                Session::new(2, 3),
+            ),
+            RaceMetadata::default(),
+            vec![Lap::new(1, 56.8), Lap::new(2, 58.7)],
+        )
+        .convert_to_race_file();
+        let expected_karting_time =
+            KartingTimeFile::new(DriverProfileFile::new("Jack Jackson", vec![race_file]));
+
+        // When
+        let _file_guard = TestFileGuard::new(file_name);
+        let _converted_file_guard = TestFileGuard::new(converted_file_name);
+        let _folder_guard = TestFileGuard::new(folder_location);
+
+        upsert_application_state(file_name, &expected_karting_time).unwrap();
+        convert_profile_file_to_folder(file_name, folder_location).unwrap();
+        convert_profile_folder_to_file(folder_location, converted_file_name).unwrap();
+        let karting_time = read_driver_profile(converted_file_name);
+
+        // Then
+        assert!(karting_time.is_ok(), "Unexpectedly returned an error");
+        pretty_assertions::assert_eq!(
+            expected_karting_time.convert_to_karting_time(),
+            karting_time.unwrap().convert_to_karting_time()
+        );
+    }
+
+    #[test]
+    fn test_upsert_then_delete_recovery_file() {
+        // Given
+        let recovery_folder = "./karting_time_test_recovery";
+        let recovery_file_path = "./karting_time_test_recovery/recovery.toml";
+        let expected_karting_time = KartingTimeFile::default();
+
+        // When
+        let _guard = TestFileGuard::new(recovery_folder);
+
+        upsert_recovery_file(recovery_file_path, &expected_karting_time).unwrap();
+        let karting_time = read_application_state(recovery_file_path);
+        delete_recovery_file(recovery_file_path);
+
+        // Then
+        pretty_assertions::assert_eq!(Ok(expected_karting_time), karting_time);
+        assert!(fs::metadata(recovery_file_path).is_err());
+    }
+
+    #[test]
+    fn test_upsert_then_read_settings_file() {
+        // Given
+        let settings_folder = "./karting_time_test_settings";
+        let settings_file_path = "./karting_time_test_settings/settings.toml";
+        let expected_settings_file = SettingsFile {
+            is_light_theme: true,
+            page_size: 20,
+            last_profile: Some("profile.toml".to_string()),
+            recent_profiles: vec!["profile.toml".to_string(), "races.db".to_string()],
+            window_width: Some(1280.0),
+            window_height: Some(720.0),
+            imports_folder: Some("imports".to_string()),
+            time_format: TimeFormat {
+                time_style: TimeStyle::Seconds,
+                decimal_places: DecimalPlaces::Two,
+            },
+            ..Default::default()
+        };
+
+        // When
+        let _guard = TestFileGuard::new(settings_folder);
+
+        upsert_settings_file(settings_file_path, &expected_settings_file).unwrap();
+        let settings_file = read_settings_file(settings_file_path);
+
+        // Then
+        pretty_assertions::assert_eq!(Ok(expected_settings_file), settings_file);
+    }
+
+    #[test]
+    fn test_read_settings_file_with_missing_settings() {
+        // Given
+        let settings_folder = "./karting_time_test_settings_partial";
+        let settings_file_path = "./karting_time_test_settings_partial/settings.toml";
+        let expected_settings_file = SettingsFile {
+            is_light_theme: true,
+            ..Default::default()
+        };
+
+        // When
+        let _guard = TestFileGuard::new(settings_folder);
+        fs::create_dir_all(settings_folder).unwrap();
+        fs::write(settings_file_path, "is_light_theme = true\n").unwrap();
+
+        let settings_file = read_settings_file(settings_file_path);
+
+        // Then
+        pretty_assertions::assert_eq!(Ok(expected_settings_file), settings_file);
+    }
+
+    #[test]
+    fn test_upsert_application_state_without_backup_or_temporary_file() {
+        // Given
+        let folder_location = "./karting_time_test_backups_1";
+        let file_name = "./karting_time_test_backups_1/profile.toml";
+        let karting_time_file = KartingTimeFile::default();
+
+        // When
+        let _guard = TestFileGuard::new(folder_location);
+        fs::create_dir_all(folder_location).unwrap();
+
+        upsert_application_state(file_name, &karting_time_file).unwrap();
+
+        // Then
+        assert!(fs::metadata(file_name).is_ok());
+        assert!(fs::metadata(format!("{file_name}.tmp")).is_err());
+        assert!(fs::metadata(format!("{folder_location}/{BACKUP_FOLDER}")).is_err());
+    }
+
+    #[test]
+    fn test_upsert_application_state_rotates_backups() {
+        // Given
+        let folder_location = "./karting_time_test_backups_2";
+        let file_name = "./karting_time_test_backups_2/profile.toml";
+        let backup_folder = Path::new(folder_location).join(BACKUP_FOLDER);
+        let first_profile = KartingTimeFile::new(DriverProfileFile::new("First Driver", vec![]));
+
+        // When
+        let _guard = TestFileGuard::new(folder_location);
+        fs::create_dir_all(folder_location).unwrap();
+
+        upsert_application_state(file_name, &first_profile).unwrap();
+
+        for _ in 0..=MAXIMUM_BACKUPS {
+            std::thread::sleep(std::time::Duration::from_millis(2));
+            upsert_application_state(file_name, &KartingTimeFile::default()).unwrap();
+        }
+
+        let backup_file_paths = get_backup_file_paths(&backup_folder, "profile");
+        let oldest_backup = read_application_state(backup_file_paths[0].to_str().unwrap());
+
+        // Then
+        pretty_assertions::assert_eq!(MAXIMUM_BACKUPS, backup_file_paths.len());
+        pretty_assertions::assert_eq!(Ok(KartingTimeFile::default()), oldest_backup);
+        pretty_assertions::assert_eq!(
+            Ok(KartingTimeFile::default()),
+            read_application_state(file_name)
+        );
+    }
+
+    #[test]
+    fn test_backups_of_profiles_sharing_a_name_prefix_are_kept_apart() {
+        // Given
+        let folder_location = "./karting_time_test_backups_3";
+        let file_name = "./karting_time_test_backups_3/race.toml";
+        let other_file_name = "./karting_time_test_backups_3/race-2024.toml";
+        let backup_folder = Path::new(folder_location).join(BACKUP_FOLDER);
+
+        // When
+        let _guard = TestFileGuard::new(folder_location);
+        fs::create_dir_all(folder_location).unwrap();
+
+        for _ in 0..=MAXIMUM_BACKUPS {
+            std::thread::sleep(std::time::Duration::from_millis(2));
+            upsert_application_state(other_file_name, &KartingTimeFile::default()).unwrap();
+        }
+
+        upsert_application_state(file_name, &KartingTimeFile::default()).unwrap();
+        upsert_application_state(file_name, &KartingTimeFile::default()).unwrap();
+
+        // Then
+        pretty_assertions::assert_eq!(1, get_backup_file_paths(&backup_folder, "race").len());
+        pretty_assertions::assert_eq!(
+            MAXIMUM_BACKUPS,
+            get_backup_file_paths(&backup_folder, "race-2024").len()
+        );
+    }
 }
@@ -1,9 +1,16 @@
 use iced::Task;
 use rfd::AsyncFileDialog;
 
-pub fn select_toml_file_to_load() -> Task<Option<String>> {
-    Task::future(async {
-        let file = AsyncFileDialog::new()
+fn file_dialog(folder: Option<String>) -> AsyncFileDialog {
+    match folder {
+        Some(folder) => AsyncFileDialog::new().set_directory(folder),
+        None => AsyncFileDialog::new(),
+    }
+}
+
+pub fn select_toml_file_to_load(folder: Option<String>) -> Task<Option<String>> {
+    Task::future(async move {
+        let file = file_dialog(folder)
             .add_filter("toml", &["toml"])
             .pick_file()
             .await;
@@ -12,9 +19,9 @@ pub fn select_toml_file_to_load() -> Task<Option<String>> {
     })
 }
 
-pub fn select_json_file_to_load() -> Task<Option<String>> {
-    Task::future(async {
-        let file = AsyncFileDialog::new()
+pub fn select_json_file_to_load(folder: Option<String>) -> Task<Option<String>> {
+    Task::future(async move {
+        let file = file_dialog(folder)
             .add_filter("json", &["json"])
             .pick_file()
             .await;
@@ -23,9 +30,53 @@ pub fn select_json_file_to_load() -> Task<Option<String>> {
     })
 }
 
-pub fn select_file_to_load() -> Task<Option<String>> {
-    Task::future(async {
-        let file = AsyncFileDialog::new()
+pub fn select_csv_file_to_load(folder: Option<String>) -> Task<Option<String>> {
+    Task::future(async move {
+        let file = file_dialog(folder)
+            .add_filter("csv", &["csv"])
+            .pick_file()
+            .await;
+
+        file.and_then(|handle| handle.path().to_str().map(|s| s.to_string()))
+    })
+}
+
+pub fn select_gps_file_to_load(folder: Option<String>) -> Task<Option<String>> {
+    Task::future(async move {
+        let file = file_dialog(folder)
+            .add_filter("gps", &["csv", "vbo", "gpx"])
+            .pick_file()
+            .await;
+
+        file.and_then(|handle| handle.path().to_str().map(|s| s.to_string()))
+    })
+}
+
+pub fn select_telemetry_replay_file_to_load(folder: Option<String>) -> Task<Option<String>> {
+    Task::future(async move {
+        let file = file_dialog(folder)
+            .add_filter("ktr", &["ktr"])
+            .pick_file()
+            .await;
+
+        file.and_then(|handle| handle.path().to_str().map(|s| s.to_string()))
+    })
+}
+
+pub fn select_database_file_to_load(folder: Option<String>) -> Task<Option<String>> {
+    Task::future(async move {
+        let file = file_dialog(folder)
+            .add_filter("sqlite", &["db", "sqlite"])
+            .pick_file()
+            .await;
+
+        file.and_then(|handle| handle.path().to_str().map(|s| s.to_string()))
+    })
+}
+
+pub fn select_file_to_load(folder: Option<String>) -> Task<Option<String>> {
+    Task::future(async move {
+        let file = file_dialog(folder)
             .add_filter("toml", &["toml"])
             .add_filter("json", &["json"])
             .add_filter("txt", &["txt"])
@@ -38,9 +89,9 @@ pub fn select_file_to_load() -> Task<Option<String>> {
     })
 }
 
-pub fn select_toml_files_to_load() -> Task<Option<Vec<String>>> {
-    Task::future(async {
-        AsyncFileDialog::new()
+pub fn select_toml_files_to_load(folder: Option<String>) -> Task<Option<Vec<String>>> {
+    Task::future(async move {
+        file_dialog(folder)
             .add_filter("toml", &["toml"])
             .pick_files()
             .await
@@ -53,9 +104,9 @@ pub fn select_toml_files_to_load() -> Task<Option<Vec<String>>> {
     })
 }
 
-pub fn save_toml_file_location() -> Task<Option<String>> {
-    Task::future(async {
-        let file = AsyncFileDialog::new()
+pub fn save_toml_file_location(folder: Option<String>) -> Task<Option<String>> {
+    Task::future(async move {
+        let file = file_dialog(folder)
             .add_filter("toml", &["toml"])
             .save_file()
             .await;
@@ -64,9 +115,50 @@ pub fn save_toml_file_location() -> Task<Option<String>> {
     })
 }
 
-pub fn save_folder_location() -> Task<Option<String>> {
-    Task::future(async {
-        let folder = AsyncFileDialog::new().pick_folder().await;
+pub fn save_database_file_location(folder: Option<String>) -> Task<Option<String>> {
+    Task::future(async move {
+        let file = file_dialog(folder)
+            .add_filter("sqlite", &["db", "sqlite"])
+            .save_file()
+            .await;
+
+        file.and_then(|handle| handle.path().to_str().map(|s| s.to_string()))
+    })
+}
+
+pub fn save_telemetry_replay_file_location(folder: Option<String>) -> Task<Option<String>> {
+    Task::future(async move {
+        let file = file_dialog(folder)
+            .add_filter("ktr", &["ktr"])
+            .save_file()
+            .await;
+
+        file.and_then(|handle| handle.path().to_str().map(|s| s.to_string()))
+    })
+}
+
+pub fn save_text_file_location(folder: Option<String>) -> Task<Option<String>> {
+    Task::future(async move {
+        let file = file_dialog(folder)
+            .add_filter("txt", &["txt"])
+            .save_file()
+            .await;
+
+        file.and_then(|handle| handle.path().to_str().map(|s| s.to_string()))
+    })
+}
+
+pub fn save_folder_location(folder: Option<String>) -> Task<Option<String>> {
+    Task::future(async move {
+        let folder = file_dialog(folder).pick_folder().await;
+
+        folder.and_then(|handle| handle.path().to_str().map(|s| s.to_string()))
+    })
+}
+
+pub fn select_folder_to_load(folder: Option<String>) -> Task<Option<String>> {
+    Task::future(async move {
+        let folder = file_dialog(folder).pick_folder().await;
 
         folder.and_then(|handle| handle.path().to_str().map(|s| s.to_string()))
     })
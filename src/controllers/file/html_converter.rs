@@ -1,10 +1,13 @@
 use crate::{
+    controllers::converters::time_parser::{format_laptime, format_seconds, format_time_gap},
     data_models::driver_profile_file::DriverProfileFile,
-    models::driver::session_information::race_result::RaceResult,
+    models::{
+        application::time_format::TimeFormat, driver::session_information::race_result::RaceResult,
+    },
 };
 use maud::{DOCTYPE, Markup, html};
 
-pub fn convert_to_html(driver_profile: &DriverProfileFile) -> Markup {
+pub fn convert_to_html(driver_profile: &DriverProfileFile, time_format: TimeFormat) -> Markup {
     html! {
         (DOCTYPE)
         html lang="en" {
@@ -58,7 +61,86 @@ pub fn convert_to_html(driver_profile: &DriverProfileFile) -> Markup {
                     }
                 }
 
+                // Personal Bests
+                h2 { ( &driver_profile.name ) " Personal Bests" }
+                table {
+                    thead {
+                        tr { th { "Track" } th { "Car Used" } th { "Personal Best" } th { "Time" } th { "Date" } }
+                    }
+                    tbody {
+                        @for track in &driver_profile.personal_bests.tracks {
+                            @for personal_best in &track.personal_bests {
+                                tr {
+                                    td data-label="Track" { ( &track.track_name ) }
+                                    td data-label="Car Used" { ( &track.car_used ) }
+                                    td data-label="Personal Best" { ( personal_best.category ) }
+                                    td data-label="Time" { ( format_laptime(personal_best.time, time_format) ) }
+                                    td data-label="Date" { ( personal_best.date.day ) "/" ( personal_best.date.month ) "/" ( personal_best.date.year ) }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                h3 { "Personal Best Progression" }
+                table {
+                    thead {
+                        tr { th { "Track" } th { "Car Used" } th { "Personal Best" } th { "Time" } th { "Improvement" } th { "Date" } }
+                    }
+                    tbody {
+                        @for track in &driver_profile.personal_bests.tracks {
+                            @for personal_best in &track.progression {
+                                tr {
+                                    td data-label="Track" { ( &track.track_name ) }
+                                    td data-label="Car Used" { ( &track.car_used ) }
+                                    td data-label="Personal Best" { ( personal_best.category ) }
+                                    td data-label="Time" { ( format_laptime(personal_best.time, time_format) ) }
+                                    td data-label="Improvement" {
+                                        @match personal_best.improvement {
+                                            Some(improvement) => { ( format_time_gap(improvement, time_format) ) }
+                                            None => { "First" }
+                                        }
+                                    }
+                                    td data-label="Date" { ( personal_best.date.day ) "/" ( personal_best.date.month ) "/" ( personal_best.date.year ) }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Consistency
+                h2 { ( &driver_profile.name ) " Consistency by Track" }
+                table {
+                    thead {
+                        tr {
+                            th { "Track" }
+                            th { "Races" }
+                            th { "Standard Deviation (s)" }
+                            th { "Coefficient of Variation" }
+                            th { "Interquartile Range (s)" }
+                            th { "Laps Within 1%" }
+                            th { "Laps Within 2%" }
+                            th { "Longest Consistent Run" }
+                        }
+                    }
+                    tbody {
+                        @for track in &driver_profile.consistency_summary.tracks {
+                            tr {
+                                td data-label="Track" { ( &track.track_name ) }
+                                td data-label="Races" { ( track.races ) }
+                                td data-label="Standard Deviation (s)" { ( format_seconds(track.standard_deviation, time_format) ) }
+                                td data-label="Coefficient of Variation" { ( format!("{:.1}%", track.coefficient_of_variation) ) }
+                                td data-label="Interquartile Range (s)" { ( format_seconds(track.interquartile_range, time_format) ) }
+                                td data-label="Laps Within 1%" { ( format!("{:.0}%", track.laps_within_1_percent) ) }
+                                td data-label="Laps Within 2%" { ( format!("{:.0}%", track.laps_within_2_percent) ) }
+                                td data-label="Longest Consistent Run" { ( track.longest_consistent_run ) }
+                            }
+                        }
+                    }
+                }
+
                 // Race Summary
+                @let lap_counts = driver_profile.statistics_settings.summary_lap_counts();
                 h2 { ( &driver_profile.name ) " Race Summary" }
                 table {
                     thead {
@@ -69,12 +151,12 @@ pub fn convert_to_html(driver_profile: &DriverProfileFile) -> Markup {
                             th { "Car Used" }
                             th { "Race Position" }
                             th { "Fastest Lap" }
-                            th { "Average Lap 5" }
-                            th { "Average Lap 10" }
-                            th { "Average Lap 15" }
-                            th { "Total Lap 5" }
-                            th { "Total Lap 10" }
-                            th { "Total Lap 15" }
+                            @for lap_count in &lap_counts {
+                                th { "Average Lap " ( lap_count ) }
+                            }
+                            @for lap_count in &lap_counts {
+                                th { "Total Lap " ( lap_count ) }
+                            }
                             th { "Total Time" }
                         }
                     }
@@ -88,16 +170,16 @@ pub fn convert_to_html(driver_profile: &DriverProfileFile) -> Markup {
                                     td data-label="Car Used" { ( car_used ) }
                                 }
                                 td data-label="Race Position" { ( &race.race_position ) }
-                                td data-label="Fastest Lap" { ( race.race_statistics.fastest_lap ) }
+                                td data-label="Fastest Lap" { ( format_laptime(race.race_statistics.fastest_lap, time_format) ) }
 
-                                td data-label="Average Lap 5" { ( RaceResult::get_time_by_key( &race.race_statistics.average_times_table, 5 ) ) }
-                                td data-label="Average Lap 10" { ( RaceResult::get_time_by_key( &race.race_statistics.average_times_table, 10 ) ) }
-                                td data-label="Average Lap 15" { ( RaceResult::get_time_by_key( &race.race_statistics.average_times_table, 15 ) ) }
+                                @for lap_count in &lap_counts {
+                                    td data-label={ "Average Lap " ( lap_count ) } { ( RaceResult::get_time_by_key( &race.race_statistics.average_times_table, *lap_count, time_format ) ) }
+                                }
 
-                                td data-label="Total Lap 5" { ( RaceResult::get_time_by_key( &race.race_statistics.total_times_table, 5 ) ) }
-                                td data-label="Total Lap 10" { ( RaceResult::get_time_by_key( &race.race_statistics.total_times_table, 10 ) ) }
-                                td data-label="Total Lap 15" { ( RaceResult::get_time_by_key( &race.race_statistics.total_times_table, 15 ) ) }
-                                td data-label="Total Time" { ( RaceResult::get_last_time( &race.race_statistics.total_times_table) ) }
+                                @for lap_count in &lap_counts {
+                                    td data-label={ "Total Lap " ( lap_count ) } { ( RaceResult::get_time_by_key( &race.race_statistics.total_times_table, *lap_count, time_format ) ) }
+                                }
+                                td data-label="Total Time" { ( RaceResult::get_last_time( &race.race_statistics.total_times_table, time_format ) ) }
                             }
                         }
                     }
@@ -108,16 +190,19 @@ pub fn convert_to_html(driver_profile: &DriverProfileFile) -> Markup {
                     h2 {
                         ( &race.track_name ) " Session: " ( &race.session_id ) " Date: " ( race.day ) "/" ( race.month ) "/" ( race.year )
                     }
+                    @if let Some(badge) = driver_profile.personal_bests.badge(&race.unique_race_identifier()) {
+                        p { mark { ( badge ) } }
+                    }
                     h3 { "Race" }
                     table {
                         thead {
                             tr { th { "Lap" } th { "Time" } }
                         }
                         tbody {
-                            @for (lap_number, lap_time) in race.race_statistics.formatted_laps.iter().enumerate() {
+                            @for (lap_number, lap_time) in race.race_statistics.laptimes.iter().enumerate() {
                                 tr {
                                     td data-label="Lap" { ( lap_number + 1 ) }
-                                    td data-label="Time" { ( lap_time ) }
+                                    td data-label="Time" { ( format_laptime(*lap_time, time_format) ) }
                                 }
                             }
                         }
@@ -139,11 +224,88 @@ pub fn convert_to_html(driver_profile: &DriverProfileFile) -> Markup {
                             }
                             tr {
                                 td data-label="Race Summary" { "Fastest lap" }
-                                td data-label="Race Statistic" { ( race.race_statistics.fastest_lap ) }
+                                td data-label="Race Statistic" { ( format_laptime(race.race_statistics.fastest_lap, time_format) ) }
+                            }
+                            tr {
+                                td data-label="Race Summary" {
+                                    "Average lap (" ( driver_profile.statistics_settings.windows_for(race.session_type.as_deref().unwrap_or_default()).cutoff_percentage() ) "%)"
+                                }
+                                td data-label="Race Statistic" { ( format_laptime(race.race_statistics.average_105_lap, time_format) ) }
+                            }
+                        }
+                    }
+                    // Consistency
+                    h3 { "Consistency" }
+                    table {
+                        thead {
+                            tr { th { "Consistency" } th { "Race Statistic" } }
+                        }
+                        tbody {
+                            tr {
+                                td data-label="Consistency" { "Standard deviation (s)" }
+                                td data-label="Race Statistic" { ( format_seconds(race.race_statistics.consistency.standard_deviation, time_format) ) }
+                            }
+                            tr {
+                                td data-label="Consistency" { "Coefficient of variation" }
+                                td data-label="Race Statistic" { ( format!("{:.1}%", race.race_statistics.consistency.coefficient_of_variation) ) }
+                            }
+                            tr {
+                                td data-label="Consistency" { "Median lap" }
+                                td data-label="Race Statistic" { ( format_laptime(race.race_statistics.consistency.median_lap, time_format) ) }
                             }
                             tr {
-                                td data-label="Race Summary" { "Average lap (105%)" }
-                                td data-label="Race Statistic" { ( race.race_statistics.average_105_lap ) }
+                                td data-label="Consistency" { "Interquartile range (s)" }
+                                td data-label="Race Statistic" { ( format_seconds(race.race_statistics.consistency.interquartile_range, time_format) ) }
+                            }
+                            tr {
+                                td data-label="Consistency" { "Laps within 1%" }
+                                td data-label="Race Statistic" { ( format!("{:.0}%", race.race_statistics.consistency.laps_within_1_percent) ) }
+                            }
+                            tr {
+                                td data-label="Consistency" { "Laps within 2%" }
+                                td data-label="Race Statistic" { ( format!("{:.0}%", race.race_statistics.consistency.laps_within_2_percent) ) }
+                            }
+                            tr {
+                                td data-label="Consistency" { "Longest consistent run" }
+                                td data-label="Race Statistic" { ( race.race_statistics.consistency.longest_consistent_run ) }
+                            }
+                        }
+                    }
+                    // Pace Trend
+                    @if !race.race_statistics.pace_trend.stints.is_empty() {
+                        @let pace_trend = &race.race_statistics.pace_trend;
+                        h3 { "Pace Trend" }
+                        table {
+                            thead {
+                                tr { th { "Pace Trend" } th { "Race Statistic" } }
+                            }
+                            tbody {
+                                tr {
+                                    td data-label="Pace Trend" { "Degradation (s/lap)" }
+                                    td data-label="Race Statistic" { ( format_time_gap(pace_trend.degradation_per_lap, time_format) ) }
+                                }
+                                tr {
+                                    td data-label="Pace Trend" { "First half average" }
+                                    td data-label="Race Statistic" { ( format_laptime(pace_trend.first_half_average, time_format) ) }
+                                }
+                                tr {
+                                    td data-label="Pace Trend" { "Second half average" }
+                                    td data-label="Race Statistic" { ( format_laptime(pace_trend.second_half_average, time_format) ) }
+                                }
+                                tr {
+                                    td data-label="Pace Trend" { "Second half fade (s)" }
+                                    td data-label="Race Statistic" { ( format_time_gap(pace_trend.second_half_average - pace_trend.first_half_average, time_format) ) }
+                                }
+                                tr {
+                                    td data-label="Pace Trend" { "Outlier laps" }
+                                    td data-label="Race Statistic" { ( pace_trend.outlier_laps.len() ) }
+                                }
+                                @for stint in &pace_trend.stints {
+                                    tr {
+                                        td data-label="Pace Trend" { "Stint laps " ( stint.first_lap ) "-" ( stint.last_lap ) }
+                                        td data-label="Race Statistic" { ( format_laptime(stint.average_lap, time_format) ) }
+                                    }
+                                }
                             }
                         }
                     }
@@ -158,7 +320,21 @@ pub fn convert_to_html(driver_profile: &DriverProfileFile) -> Markup {
                             @for (total_time_key, total_time_value) in &race.race_statistics.total_times_table {
                                 tr {
                                     td data-label="Lap" { "Total Time " (total_time_key) }
-                                    td data-label="Pace" { (total_time_value) }
+                                    td data-label="Pace" { ( format_laptime(*total_time_value, time_format) ) }
+                                }
+                            }
+                        }
+                    }
+                    h4 { "Consecutive Times" }
+                    table {
+                        thead {
+                            tr { th { "Lap" } th { "Pace" } }
+                        }
+                        tbody {
+                            @for (consecutive_time_key, consecutive_time_value) in &race.race_statistics.consecutive_times_table {
+                                tr {
+                                    td data-label="Lap" { "Consecutive Time " (consecutive_time_key) }
+                                    td data-label="Pace" { ( format_laptime(*consecutive_time_value, time_format) ) }
                                 }
                             }
                         }
@@ -172,7 +348,7 @@ pub fn convert_to_html(driver_profile: &DriverProfileFile) -> Markup {
                             @for (average_time_key, average_time_value) in &race.race_statistics.average_times_table {
                                 tr {
                                     td data-label="Lap" { "Average Time " (average_time_key) }
-                                    td data-label="Pace" { (average_time_value) }
+                                    td data-label="Pace" { ( format_laptime(*average_time_value, time_format) ) }
                                 }
                             }
                         }
@@ -224,11 +400,17 @@ pub fn convert_to_html(driver_profile: &DriverProfileFile) -> Markup {
 #[cfg(test)]
 mod html_converter_should {
     use crate::{
-        controllers::file::html_converter::convert_to_html,
+        controllers::{
+            converters::time_parser::format_laptime, file::html_converter::convert_to_html,
+        },
         data_models::{driver_profile_file::DriverProfileFile, race_result_file::RaceResultFile},
         models::{
+            application::time_format::{DecimalPlaces, TimeFormat, TimeStyle},
             date::RaceDate,
-            driver::session_information::{race_metadata::RaceMetadata, session::Session},
+            driver::{
+                session_information::{race_metadata::RaceMetadata, session::Session},
+                statistics_settings::{StatisticsSettings, StatisticsWindows},
+            },
         },
     };
 
@@ -260,7 +442,7 @@ mod html_converter_should {
         );
 
         // When
-        let markdown = convert_to_html(&driver_profile_file);
+        let markdown = convert_to_html(&driver_profile_file, TimeFormat::default());
 
         // Then
         let markdown_string = markdown.into_string();
@@ -323,7 +505,7 @@ mod html_converter_should {
         );
 
         // When
-        let markdown = convert_to_html(&driver_profile_file);
+        let markdown = convert_to_html(&driver_profile_file, TimeFormat::default());
 
         // Then
         let markdown_string = markdown.into_string();
@@ -360,14 +542,14 @@ mod html_converter_should {
                 "<td data-label=\"Race Position\">{}</td>",
                 &race.race_position
             )));
-            assert!(markdown_string.contains("<td data-label=\"Fastest Lap\">5.00</td>",));
-            assert!(markdown_string.contains("<td data-label=\"Average Lap 5\">15.00</td>",));
+            assert!(markdown_string.contains("<td data-label=\"Fastest Lap\">0:05.000</td>",));
+            assert!(markdown_string.contains("<td data-label=\"Average Lap 5\">0:15.000</td>",));
             assert!(markdown_string.contains("<td data-label=\"Average Lap 10\">N/A</td>",));
             assert!(markdown_string.contains("<td data-label=\"Average Lap 15\">N/A</td>",));
-            assert!(markdown_string.contains("<td data-label=\"Total Lap 5\">1:15.00</td>",));
+            assert!(markdown_string.contains("<td data-label=\"Total Lap 5\">1:15.000</td>",));
             assert!(markdown_string.contains("<td data-label=\"Total Lap 10\">N/A</td>",));
             assert!(markdown_string.contains("<td data-label=\"Total Lap 15\">N/A</td>",));
-            assert!(markdown_string.contains("<td data-label=\"Total Time\">1:45.00</td>",));
+            assert!(markdown_string.contains("<td data-label=\"Total Time\">1:45.000</td>",));
         }
     }
 
@@ -399,7 +581,7 @@ mod html_converter_should {
         );
 
         // When
-        let markdown = convert_to_html(&driver_profile_file);
+        let markdown = convert_to_html(&driver_profile_file, TimeFormat::default());
 
         // Then
         let markdown_string = markdown.into_string();
@@ -417,15 +599,200 @@ mod html_converter_should {
             assert!(markdown_string.contains("<td data-label=\"Race Statistic\">6</td>"));
 
             assert!(markdown_string.contains("<td data-label=\"Race Summary\">Fastest lap</td>"));
-            assert!(markdown_string.contains("<td data-label=\"Race Statistic\">5.00</td>"));
+            assert!(markdown_string.contains("<td data-label=\"Race Statistic\">0:05.000</td>"));
 
             assert!(
                 markdown_string.contains("<td data-label=\"Race Summary\">Average lap (105%)</td>")
             );
-            assert!(markdown_string.contains("<td data-label=\"Race Statistic\">5.00</td>"));
+            assert!(markdown_string.contains("<td data-label=\"Race Statistic\">0:05.000</td>"));
         }
     }
 
+    #[test]
+    fn test_convert_with_time_format() {
+        // Given
+        let driver_profile_file = DriverProfileFile::new(
+            "Derek",
+            vec![RaceResultFile::new(
+                "Three Brothers",
+                vec!["45.123".to_string(), "83.456".to_string()],
+                Default::default(),
+                Session::new(1, 1),
+                RaceDate::new(24, 12, 2025),
+            )],
+        );
+        let time_format = TimeFormat {
+            time_style: TimeStyle::Seconds,
+            decimal_places: DecimalPlaces::One,
+        };
+
+        // When
+        let markdown = convert_to_html(&driver_profile_file, time_format);
+
+        // Then
+        let markdown_string = markdown.into_string();
+
+        assert!(markdown_string.contains("<td data-label=\"Fastest Lap\">45.1</td>"));
+        assert!(markdown_string.contains("<td data-label=\"Time\">83.5</td>"));
+        assert!(markdown_string.contains("<td data-label=\"Total Time\">128.6</td>"));
+    }
+
+    #[test]
+    fn test_convert_personal_bests() {
+        // Given
+        let driver_profile_file = DriverProfileFile::new(
+            "Derek",
+            vec![
+                RaceResultFile::new(
+                    "Three Brothers",
+                    vec!["50.0".to_string()],
+                    Default::default(),
+                    Session::new(1, 1),
+                    RaceDate::new(24, 12, 2025),
+                ),
+                RaceResultFile::new(
+                    "Three Brothers",
+                    vec!["49.5".to_string()],
+                    Default::default(),
+                    Session::new(1, 1),
+                    RaceDate::new(31, 12, 2025),
+                ),
+            ],
+        );
+
+        // When
+        let markdown = convert_to_html(&driver_profile_file, TimeFormat::default());
+
+        // Then
+        let markdown_string = markdown.into_string();
+
+        assert!(markdown_string.contains("<h2>Derek Personal Bests</h2>"));
+        assert!(markdown_string.contains("<td data-label=\"Personal Best\">Fastest Lap</td>"));
+        assert!(markdown_string.contains("<td data-label=\"Time\">0:49.500</td>"));
+        assert!(markdown_string.contains("<td data-label=\"Date\">31/12/2025</td>"));
+        assert!(markdown_string.contains("<h3>Personal Best Progression</h3>"));
+        assert!(markdown_string.contains("<td data-label=\"Improvement\">First</td>"));
+        assert!(markdown_string.contains("<td data-label=\"Improvement\">-0.500</td>"));
+        assert!(
+            markdown_string
+                .contains("<p><mark>Personal Best: Fastest Lap, Average Lap (105%)</mark></p>")
+        );
+    }
+
+    #[test]
+    fn test_convert_with_statistics_settings() {
+        // Given
+        let driver_profile_file = DriverProfileFile::new_with_statistics_settings(
+            "Derek",
+            vec![RaceResultFile::new(
+                "Three Brothers",
+                vec![
+                    "50.0".to_string(),
+                    "52.0".to_string(),
+                    "53.0".to_string(),
+                    "54.0".to_string(),
+                ],
+                Default::default(),
+                Session::new(1, 1),
+                RaceDate::new(24, 12, 2025),
+            )],
+            StatisticsSettings {
+                statistics_windows: StatisticsWindows {
+                    bucket_size: 2,
+                    average_cutoff_percentage: 107,
+                },
+                ..Default::default()
+            },
+        );
+
+        // When
+        let markdown = convert_to_html(&driver_profile_file, TimeFormat::default());
+
+        // Then
+        let markdown_string = markdown.into_string();
+
+        assert!(markdown_string.contains("<th>Fastest Lap</th><th>Average Lap 2</th><th>Average Lap 4</th><th>Average Lap 6</th><th>Total Lap 2</th><th>Total Lap 4</th><th>Total Lap 6</th><th>Total Time</th>"));
+        assert!(markdown_string.contains("<td data-label=\"Total Lap 2\">1:42.000</td>"));
+        assert!(markdown_string.contains("<td data-label=\"Total Lap 6\">N/A</td>"));
+        assert!(
+            markdown_string.contains("<td data-label=\"Race Summary\">Average lap (107%)</td>")
+        );
+        assert!(markdown_string.contains("<td data-label=\"Race Statistic\">0:51.667</td>"));
+    }
+
+    #[test]
+    fn test_convert_consistency_tables() {
+        // Given
+        let driver_profile_file = DriverProfileFile::new(
+            "Derek",
+            vec![RaceResultFile::new(
+                "Three Brothers",
+                vec![
+                    "50.0".to_string(),
+                    "50.0".to_string(),
+                    "50.0".to_string(),
+                    "60.0".to_string(),
+                ],
+                Default::default(),
+                Session::new(1, 1),
+                RaceDate::new(24, 12, 2025),
+            )],
+        );
+
+        // When
+        let markdown = convert_to_html(&driver_profile_file, TimeFormat::default());
+
+        // Then
+        let markdown_string = markdown.into_string();
+
+        assert!(markdown_string.contains("<h2>Derek Consistency by Track</h2>"));
+        assert!(markdown_string.contains("<td data-label=\"Standard Deviation (s)\">4.330</td>"));
+        assert!(markdown_string.contains("<td data-label=\"Laps Within 1%\">75%</td>"));
+        assert!(markdown_string.contains("<td data-label=\"Longest Consistent Run\">3</td>"));
+        assert!(markdown_string.contains("<h3>Consistency</h3>"));
+        assert!(markdown_string.contains("<td data-label=\"Race Statistic\">4.330</td>"));
+        assert!(markdown_string.contains("<td data-label=\"Race Statistic\">0:50.000</td>"));
+    }
+
+    #[test]
+    fn test_convert_pace_trend_table() {
+        // Given
+        let driver_profile_file = DriverProfileFile::new(
+            "Derek",
+            vec![RaceResultFile::new(
+                "Three Brothers",
+                vec![
+                    "50.0".to_string(),
+                    "50.5".to_string(),
+                    "51.0".to_string(),
+                    "75.0".to_string(),
+                    "52.0".to_string(),
+                    "52.5".to_string(),
+                ],
+                Default::default(),
+                Session::new(1, 1),
+                RaceDate::new(24, 12, 2025),
+            )],
+        );
+
+        // When
+        let markdown = convert_to_html(&driver_profile_file, TimeFormat::default());
+
+        // Then
+        let markdown_string = markdown.into_string();
+
+        assert!(markdown_string.contains("<h3>Pace Trend</h3>"));
+        assert!(markdown_string.contains(
+            "<td data-label=\"Pace Trend\">Degradation (s/lap)</td><td data-label=\"Race Statistic\">+0.500</td>"
+        ));
+        assert!(markdown_string.contains(
+            "<td data-label=\"Pace Trend\">Outlier laps</td><td data-label=\"Race Statistic\">1</td>"
+        ));
+        assert!(markdown_string.contains(
+            "<td data-label=\"Pace Trend\">Stint laps 1-5</td><td data-label=\"Race Statistic\">0:50.875</td>"
+        ));
+    }
+
     #[test]
     fn test_convert_race_pace_total_times_table() {
         // Given
@@ -454,7 +821,7 @@ mod html_converter_should {
         );
 
         // When
-        let markdown = convert_to_html(&driver_profile_file);
+        let markdown = convert_to_html(&driver_profile_file, TimeFormat::default());
 
         // Then
         let markdown_string = markdown.into_string();
@@ -468,11 +835,53 @@ mod html_converter_should {
             assert!(markdown_string.contains("<td data-label=\"Lap\">Total Time 5</td>"));
             assert!(markdown_string.contains("<td data-label=\"Lap\">Total Time 6</td>"));
 
-            assert!(markdown_string.contains("<td data-label=\"Pace\">1:15.00</td>"));
-            assert!(markdown_string.contains("<td data-label=\"Pace\">1:45.00</td>"));
+            assert!(markdown_string.contains("<td data-label=\"Pace\">1:15.000</td>"));
+            assert!(markdown_string.contains("<td data-label=\"Pace\">1:45.000</td>"));
         }
     }
 
+    #[test]
+    fn test_convert_race_pace_consecutive_times_table() {
+        // Given
+        let driver_profile_file = DriverProfileFile::new(
+            "Derek",
+            vec![RaceResultFile::new(
+                "Three Brothers",
+                vec![
+                    "10.0".to_string(),
+                    "30.0".to_string(),
+                    "15.0".to_string(),
+                    "20.0".to_string(),
+                    "25.0".to_string(),
+                    "5.0".to_string(),
+                ],
+                RaceMetadata::new(
+                    "Race",
+                    "Dry",
+                    "Mercedes GT3",
+                    "GT World Challenge",
+                    "No comment",
+                ),
+                Session::new(1, 1),
+                RaceDate::new(24, 12, 2025),
+            )],
+        );
+
+        // When
+        let markdown = convert_to_html(&driver_profile_file, TimeFormat::default());
+
+        // Then
+        let markdown_string = markdown.into_string();
+
+        assert!(markdown_string.contains("<h4>Consecutive Times</h4>"));
+        assert!(markdown_string.contains(
+            "<td data-label=\"Lap\">Consecutive Time 5</td><td data-label=\"Pace\">1:35.000</td>"
+        ));
+        assert!(markdown_string.contains(
+            "<td data-label=\"Lap\">Consecutive Time 6</td><td data-label=\"Pace\">1:45.000</td>"
+        ));
+    }
+
     #[test]
     fn test_convert_race_pace_average_times_table() {
         // Given
@@ -501,7 +910,7 @@ mod html_converter_should {
         );
 
         // When
-        let markdown = convert_to_html(&driver_profile_file);
+        let markdown = convert_to_html(&driver_profile_file, TimeFormat::default());
 
         // Then
         let markdown_string = markdown.into_string();
@@ -515,8 +924,8 @@ mod html_converter_should {
             assert!(markdown_string.contains("<td data-label=\"Lap\">Average Time 5</td>"));
             assert!(markdown_string.contains("<td data-label=\"Lap\">Average Time 6</td>"));
 
-            assert!(markdown_string.contains("<td data-label=\"Pace\">15.00</td>"));
-            assert!(markdown_string.contains("<td data-label=\"Pace\">17.50</td>"));
+            assert!(markdown_string.contains("<td data-label=\"Pace\">0:15.000</td>"));
+            assert!(markdown_string.contains("<td data-label=\"Pace\">0:17.500</td>"));
         }
     }
 
@@ -548,7 +957,7 @@ mod html_converter_should {
         );
 
         // When
-        let markdown = convert_to_html(&driver_profile_file);
+        let markdown = convert_to_html(&driver_profile_file, TimeFormat::default());
 
         // Then
         let markdown_string = markdown.into_string();
@@ -573,27 +982,27 @@ mod html_converter_should {
             assert!(markdown_string.contains("<td data-label=\"Lap\">6</td>"));
             assert!(markdown_string.contains(&format!(
                 "<td data-label=\"Time\">{}</td>",
-                race.race_statistics.formatted_laps[0].clone()
+                format_laptime(race.race_statistics.laptimes[0], TimeFormat::default())
             )));
             assert!(markdown_string.contains(&format!(
                 "<td data-label=\"Time\">{}</td>",
-                race.race_statistics.formatted_laps[1].clone()
+                format_laptime(race.race_statistics.laptimes[1], TimeFormat::default())
             )));
             assert!(markdown_string.contains(&format!(
                 "<td data-label=\"Time\">{}</td>",
-                race.race_statistics.formatted_laps[2].clone()
+                format_laptime(race.race_statistics.laptimes[2], TimeFormat::default())
             )));
             assert!(markdown_string.contains(&format!(
                 "<td data-label=\"Time\">{}</td>",
-                race.race_statistics.formatted_laps[3].clone()
+                format_laptime(race.race_statistics.laptimes[3], TimeFormat::default())
             )));
             assert!(markdown_string.contains(&format!(
                 "<td data-label=\"Time\">{}</td>",
-                race.race_statistics.formatted_laps[4].clone()
+                format_laptime(race.race_statistics.laptimes[4], TimeFormat::default())
             )));
             assert!(markdown_string.contains(&format!(
                 "<td data-label=\"Time\">{}</td>",
-                race.race_statistics.formatted_laps[5].clone()
+                format_laptime(race.race_statistics.laptimes[5], TimeFormat::default())
             )));
         }
     }
@@ -626,7 +1035,7 @@ mod html_converter_should {
         );
 
         // When
-        let markdown = convert_to_html(&driver_profile_file);
+        let markdown = convert_to_html(&driver_profile_file, TimeFormat::default());
 
         // Then
         let markdown_string = markdown.into_string();
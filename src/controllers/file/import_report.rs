@@ -0,0 +1,217 @@
+use super::file_io::upsert_text_file;
+use crate::{
+    data_models::race_result_file::RaceResultFile,
+    models::{
+        application::{
+            file_error::FileError,
+            import_report::{ImportOutcome, ImportReport, ImportReportEntry},
+            karting_time::KartingTime,
+        },
+        driver::session_information::race_result::RaceResult,
+    },
+};
+
+const DUPLICATE_REASON: &str =
+    "a race with the same date, track and session is already in the profile";
+
+impl ImportOutcome {
+    pub fn name(&self) -> &str {
+        match self {
+            ImportOutcome::Added => "Added",
+            ImportOutcome::Duplicate => "Duplicate",
+            ImportOutcome::Rejected => "Rejected",
+        }
+    }
+}
+
+impl ImportReport {
+    // Each import command replaces the previous report
+    pub fn open(&mut self) {
+        self.entries.clear();
+        self.is_visible = true;
+    }
+
+    pub fn close(&mut self) {
+        self.is_visible = false;
+    }
+
+    pub fn record(&mut self, file_name: &str, race: &RaceResult, outcome: ImportOutcome) {
+        let reason = match outcome {
+            ImportOutcome::Duplicate => DUPLICATE_REASON,
+            _ => "",
+        };
+
+        self.entries.push(ImportReportEntry {
+            file_name: file_name.to_string(),
+            race: format!(
+                "{} {} session {}",
+                race.race_information.track_name,
+                race.race_information.date,
+                race.race_information.session.session_id
+            ),
+            outcome,
+            reason: reason.to_string(),
+        });
+    }
+
+    pub fn reject(&mut self, file_name: &str, file_error: &FileError) {
+        self.entries.push(ImportReportEntry {
+            file_name: file_name.to_string(),
+            race: Default::default(),
+            outcome: ImportOutcome::Rejected,
+            reason: file_error.to_string(),
+        });
+    }
+
+    pub fn count(&self, outcome: ImportOutcome) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.outcome == outcome)
+            .count()
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "{} added, {} skipped as duplicates, {} rejected",
+            self.count(ImportOutcome::Added),
+            self.count(ImportOutcome::Duplicate),
+            self.count(ImportOutcome::Rejected)
+        )
+    }
+
+    pub fn convert_to_text(&self) -> String {
+        let mut text = format!("Import Report\n{}\n\n", self.summary());
+
+        for entry in &self.entries {
+            text.push_str(&entry.convert_to_text());
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+impl ImportReportEntry {
+    pub fn convert_to_text(&self) -> String {
+        let mut text = format!("{}: {}", self.outcome.name(), self.file_name);
+
+        if !self.race.is_empty() {
+            text.push_str(&format!(" - {}", self.race));
+        }
+
+        if !self.reason.is_empty() {
+            text.push_str(&format!(" ({})", self.reason));
+        }
+
+        text
+    }
+}
+
+impl KartingTime {
+    // Races already in the profile are skipped and the report says which file each race came
+    // from, the profile is checked for data that needs repairing once every file is imported
+    pub fn import_race_files(
+        &mut self,
+        file_name: &str,
+        race_files: Result<Vec<RaceResultFile>, FileError>,
+    ) {
+        let import_report = &mut self.application_state.import_report;
+
+        let race_files = match race_files {
+            Ok(race_files) => race_files,
+            Err(file_error) => {
+                import_report.reject(file_name, &file_error);
+                return;
+            }
+        };
+
+        for race_file in race_files {
+            let race = race_file.convert_to_race_result();
+
+            if race.is_unique_identifier(&self.driver_profile.races) {
+                import_report.record(file_name, &race, ImportOutcome::Added);
+                self.driver_profile.races.push(race);
+            } else {
+                import_report.record(file_name, &race, ImportOutcome::Duplicate);
+            }
+        }
+    }
+
+    pub fn save_import_report(&self, file_path: &str) -> Result<(), FileError> {
+        upsert_text_file(
+            file_path,
+            &self.application_state.import_report.convert_to_text(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod import_report_should {
+    use super::*;
+    use crate::models::{
+        date::RaceDate,
+        driver::{
+            driver_profile::DriverProfile,
+            session_information::{race_metadata::RaceMetadata, session::Session},
+        },
+    };
+
+    fn race_file(session_id: u32) -> RaceResultFile {
+        RaceResultFile::new(
+            "Three Sisters",
+            vec!["50.4".to_string()],
+            RaceMetadata::default(),
+            Session::new(session_id, 1),
+            RaceDate::new(12, 12, 2025),
+        )
+    }
+
+    #[test]
+    fn test_import_race_files_reports_each_outcome() {
+        // Given
+        let mut karting_time = KartingTime::new(DriverProfile::new(
+            "Jack Jackson",
+            vec![race_file(1).convert_to_race_result()],
+        ));
+        let file_error = FileError::validation("laptimes.txt", "no laptimes were found");
+
+        // When
+        karting_time.application_state.import_report.open();
+        karting_time.import_race_files("races.json", Ok(vec![race_file(1), race_file(2)]));
+        karting_time.import_race_files("laptimes.txt", Err(file_error));
+
+        // Then
+        let import_report = &karting_time.application_state.import_report;
+        pretty_assertions::assert_eq!(2, karting_time.driver_profile.races.len());
+        pretty_assertions::assert_eq!(
+            "1 added, 1 skipped as duplicates, 1 rejected",
+            import_report.summary()
+        );
+        pretty_assertions::assert_eq!(
+            format!(
+                "Import Report\n1 added, 1 skipped as duplicates, 1 rejected\n\n\
+                Duplicate: races.json - Three Sisters {} session 1 ({DUPLICATE_REASON})\n\
+                Added: races.json - Three Sisters {} session 2\n\
+                Rejected: laptimes.txt (Couldn't import laptimes.txt: no laptimes were found)\n",
+                RaceDate::new(12, 12, 2025),
+                RaceDate::new(12, 12, 2025)
+            ),
+            import_report.convert_to_text()
+        );
+    }
+
+    #[test]
+    fn test_open_replaces_previous_report() {
+        // Given
+        let mut import_report = ImportReport::default();
+        import_report.reject("race.toml", &FileError::validation("race.toml", "empty"));
+        import_report.close();
+
+        // When
+        import_report.open();
+
+        // Then
+        assert!(import_report.is_visible);
+        assert!(import_report.entries.is_empty());
+    }
+}
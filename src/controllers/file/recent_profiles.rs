@@ -0,0 +1,141 @@
+use crate::models::application::{file_error::FileError, karting_time::KartingTime};
+use std::path::Path;
+
+const DATABASE_EXTENSIONS: [&str; 2] = ["db", "sqlite"];
+
+pub fn is_database_file(profile_path: &str) -> bool {
+    Path::new(profile_path)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| DATABASE_EXTENSIONS.contains(&extension))
+}
+
+impl KartingTime {
+    // A profile that was moved or deleted is taken off the list instead of failing every time
+    pub fn find_recent_profile(&mut self, profile_path: &str) -> bool {
+        if Path::new(profile_path).exists() {
+            return true;
+        }
+
+        self.remove_recent_profile(profile_path);
+        self.application_state.file_errors.push(FileError::Io {
+            file_name: profile_path.to_string(),
+            reason: "the profile was moved or deleted so it was removed from Recent Profiles"
+                .to_string(),
+        });
+
+        false
+    }
+
+    pub fn remove_recent_profile(&mut self, profile_path: &str) {
+        let settings = &mut self.application_state.settings;
+
+        settings
+            .recent_profiles
+            .retain(|recent_profile| recent_profile != profile_path);
+
+        if settings.last_profile.as_deref() == Some(profile_path) {
+            settings.last_profile = None;
+        }
+    }
+
+    pub fn clear_recent_profiles(&mut self) {
+        self.application_state.settings.recent_profiles.clear();
+    }
+
+    pub fn toggle_reopen_last_profile(&mut self) {
+        let settings = &mut self.application_state.settings;
+
+        settings.is_reopening_last_profile = !settings.is_reopening_last_profile;
+    }
+
+    pub fn last_profile_to_reopen(&self) -> Option<String> {
+        let settings = &self.application_state.settings;
+
+        match settings.is_reopening_last_profile {
+            true => settings.last_profile.clone(),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod recent_profiles_should {
+    use super::*;
+    use crate::controllers::file::test_file_guard::TestFileGuard;
+    use rstest::rstest;
+    use std::fs;
+
+    #[rstest]
+    #[case("races.db", true)]
+    #[case("races.sqlite", true)]
+    #[case("profile.toml", false)]
+    #[case("profile_folder", false)]
+    fn test_is_database_file(#[case] profile_path: &str, #[case] expected_is_database: bool) {
+        // Then
+        pretty_assertions::assert_eq!(expected_is_database, is_database_file(profile_path));
+    }
+
+    #[test]
+    fn test_find_recent_profile_that_was_deleted() {
+        // Given
+        let profile_path = "./karting_time_test_recent_profile_deleted.toml";
+        let mut karting_time = KartingTime::default();
+        karting_time.add_recent_profile("profile.toml");
+        karting_time.add_recent_profile(profile_path);
+
+        // When
+        let is_found = karting_time.find_recent_profile(profile_path);
+
+        // Then
+        let settings = &karting_time.application_state.settings;
+        assert!(!is_found);
+        pretty_assertions::assert_eq!(vec!["profile.toml".to_string()], settings.recent_profiles);
+        pretty_assertions::assert_eq!(None, settings.last_profile);
+        pretty_assertions::assert_eq!(1, karting_time.application_state.file_errors.len());
+    }
+
+    #[test]
+    fn test_find_recent_profile() {
+        // Given
+        let profile_path = "./karting_time_test_recent_profile.toml";
+        let mut karting_time = KartingTime::default();
+        karting_time.add_recent_profile(profile_path);
+
+        // When
+        let _guard = TestFileGuard::new(profile_path);
+        fs::write(profile_path, "").unwrap();
+
+        let is_found = karting_time.find_recent_profile(profile_path);
+
+        // Then
+        assert!(is_found);
+        pretty_assertions::assert_eq!(
+            vec![profile_path.to_string()],
+            karting_time.application_state.settings.recent_profiles
+        );
+        assert!(karting_time.application_state.file_errors.is_empty());
+    }
+
+    #[rstest]
+    #[case(true, Some("profile.toml".to_string()))]
+    #[case(false, None)]
+    fn test_last_profile_to_reopen(
+        #[case] is_reopening_last_profile: bool,
+        #[case] expected_profile: Option<String>,
+    ) {
+        // Given
+        let mut karting_time = KartingTime::default();
+        karting_time.add_recent_profile("profile.toml");
+        karting_time
+            .application_state
+            .settings
+            .is_reopening_last_profile = is_reopening_last_profile;
+
+        // When
+        let profile = karting_time.last_profile_to_reopen();
+
+        // Then
+        pretty_assertions::assert_eq!(expected_profile, profile);
+    }
+}
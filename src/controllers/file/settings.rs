@@ -0,0 +1,329 @@
+use super::file_io::{
+    find_settings_file, get_backup_folder, get_settings_file_path, read_settings_file,
+    upsert_settings_file,
+};
+use crate::{
+    data_models::settings_file::SettingsFile,
+    models::application::{karting_time::KartingTime, settings::FileFolder},
+};
+use iced::Size;
+use std::path::Path;
+
+const MAXIMUM_RECENT_PROFILES: usize = 10;
+
+impl KartingTime {
+    pub fn convert_to_settings_file(&self) -> SettingsFile {
+        let settings = &self.application_state.settings;
+
+        SettingsFile {
+            is_light_theme: self.application_state.is_light_theme,
+            page_size: self.driver_profile.filter.pagination.page_size,
+            last_profile: settings.last_profile.clone(),
+            recent_profiles: settings.recent_profiles.clone(),
+            reopen_last_profile: settings.is_reopening_last_profile,
+            window_width: settings.window_size.map(|window_size| window_size.width),
+            window_height: settings.window_size.map(|window_size| window_size.height),
+            profiles_folder: settings.profiles_folder.clone(),
+            imports_folder: settings.imports_folder.clone(),
+            exports_folder: settings.exports_folder.clone(),
+            time_format: self.application_state.time_format,
+            csv_column_mappings: self.application_state.csv_import.saved_mappings.clone(),
+        }
+    }
+
+    pub fn apply_settings_file(&mut self, settings_file: &SettingsFile) {
+        self.application_state.is_light_theme = settings_file.is_light_theme;
+        self.application_state.time_format = settings_file.time_format;
+        self.application_state.csv_import.saved_mappings =
+            settings_file.csv_column_mappings.clone();
+        self.driver_profile
+            .filter
+            .change_page_size(settings_file.page_size);
+
+        let settings = &mut self.application_state.settings;
+
+        settings.last_profile = settings_file.last_profile.clone();
+        settings.recent_profiles = settings_file.recent_profiles.clone();
+        settings.is_reopening_last_profile = settings_file.reopen_last_profile;
+        settings.window_size = settings_file
+            .window_width
+            .zip(settings_file.window_height)
+            .map(|(width, height)| Size::new(width, height));
+        settings.profiles_folder = settings_file.profiles_folder.clone();
+        settings.imports_folder = settings_file.imports_folder.clone();
+        settings.exports_folder = settings_file.exports_folder.clone();
+    }
+
+    pub fn load_settings(&mut self) {
+        let Some(settings_file_path) = find_settings_file() else {
+            return;
+        };
+
+        let result = read_settings_file(&settings_file_path)
+            .map(|settings_file| self.apply_settings_file(&settings_file));
+
+        self.report_file_result(result);
+    }
+
+    pub fn save_settings(&mut self) {
+        let Some(settings_file_path) = get_settings_file_path() else {
+            return;
+        };
+
+        let result = upsert_settings_file(&settings_file_path, &self.convert_to_settings_file());
+
+        self.report_file_result(result);
+    }
+
+    // Loading a profile replaces the whole application so the settings are carried over to it, as
+    // well as a recovery file the user hasn't restored or discarded yet
+    pub fn replace_karting_time(&mut self, karting_time: KartingTime) {
+        let settings_file = self.convert_to_settings_file();
+        let unsaved_changes = std::mem::take(&mut self.application_state.unsaved_changes);
+
+        *self = karting_time;
+        self.application_state.unsaved_changes = unsaved_changes;
+
+        // Profiles saved before mappings were kept in the settings still hold their own
+        let profile_mappings =
+            std::mem::take(&mut self.application_state.csv_import.saved_mappings);
+        self.apply_settings_file(&settings_file);
+        self.application_state
+            .csv_import
+            .add_saved_mappings(profile_mappings);
+    }
+
+    pub fn add_recent_profile(&mut self, profile_path: &str) {
+        let settings = &mut self.application_state.settings;
+
+        settings
+            .recent_profiles
+            .retain(|recent_profile| recent_profile != profile_path);
+        settings.recent_profiles.insert(0, profile_path.to_string());
+        settings.recent_profiles.truncate(MAXIMUM_RECENT_PROFILES);
+        settings.last_profile = Some(profile_path.to_string());
+    }
+
+    pub fn file_folder(&self, file_folder: FileFolder) -> Option<String> {
+        let settings = &self.application_state.settings;
+
+        match file_folder {
+            FileFolder::Profiles => settings.profiles_folder.clone(),
+            FileFolder::Imports => settings.imports_folder.clone(),
+            FileFolder::Exports => settings.exports_folder.clone(),
+        }
+    }
+
+    // Backups of the current profile are next to it, otherwise the picker opens in the profiles
+    // folder
+    pub fn backup_folder(&self) -> Option<String> {
+        self.application_state
+            .settings
+            .last_profile
+            .as_deref()
+            .and_then(|profile_path| get_backup_folder(Path::new(profile_path)))
+            .filter(|backup_folder| backup_folder.is_dir())
+            .and_then(|backup_folder| backup_folder.to_str().map(|folder| folder.to_string()))
+            .or_else(|| self.file_folder(FileFolder::Profiles))
+    }
+
+    // Pickers open where the last file of the same kind was picked
+    pub fn remember_folder(&mut self, file_folder: FileFolder, file_path: &str) {
+        let path = Path::new(file_path);
+
+        let folder = match path.is_dir() {
+            true => Some(path),
+            false => path.parent(),
+        }
+        .and_then(|folder| folder.to_str())
+        .filter(|folder| !folder.is_empty())
+        .map(|folder| folder.to_string());
+
+        let Some(folder) = folder else {
+            return;
+        };
+
+        let settings = &mut self.application_state.settings;
+
+        match file_folder {
+            FileFolder::Profiles => settings.profiles_folder = Some(folder),
+            FileFolder::Imports => settings.imports_folder = Some(folder),
+            FileFolder::Exports => settings.exports_folder = Some(folder),
+        }
+    }
+}
+
+#[cfg(test)]
+mod settings_should {
+    use super::*;
+    use crate::{
+        controllers::file::test_file_guard::TestFileGuard,
+        models::{
+            application::{
+                csv_import::CsvColumnMapping,
+                time_format::{DecimalPlaces, TimeFormat, TimeStyle},
+            },
+            driver::driver_profile::DriverProfile,
+        },
+    };
+    use std::fs;
+
+    #[test]
+    fn test_convert_then_apply_settings_file() {
+        // Given
+        let expected_settings_file = SettingsFile {
+            is_light_theme: true,
+            page_size: 25,
+            last_profile: Some("profile.toml".to_string()),
+            recent_profiles: vec!["profile.toml".to_string()],
+            reopen_last_profile: true,
+            window_width: Some(1280.0),
+            window_height: Some(720.0),
+            profiles_folder: Some("profiles".to_string()),
+            imports_folder: Some("imports".to_string()),
+            exports_folder: Some("exports".to_string()),
+            time_format: TimeFormat {
+                time_style: TimeStyle::Seconds,
+                decimal_places: DecimalPlaces::One,
+            },
+            csv_column_mappings: vec![CsvColumnMapping {
+                source_name: "Club Timing".to_string(),
+                laptime_column: 2,
+                ..Default::default()
+            }],
+        };
+        let mut karting_time = KartingTime::default();
+
+        // When
+        karting_time.apply_settings_file(&expected_settings_file);
+        let settings_file = karting_time.convert_to_settings_file();
+
+        // Then
+        pretty_assertions::assert_eq!(expected_settings_file, settings_file);
+        pretty_assertions::assert_eq!(
+            Some(Size::new(1280.0, 720.0)),
+            karting_time.application_state.settings.window_size
+        );
+    }
+
+    #[test]
+    fn test_replace_karting_time_keeps_settings() {
+        // Given
+        let mut karting_time = KartingTime::default();
+        karting_time.application_state.is_light_theme = true;
+        karting_time.driver_profile.filter.change_page_size(5);
+        karting_time.add_recent_profile("profile.toml");
+        karting_time.application_state.csv_import.saved_mappings = vec![CsvColumnMapping {
+            source_name: "Club Timing".to_string(),
+            laptime_column: 2,
+            ..Default::default()
+        }];
+
+        let mut loaded_karting_time = KartingTime::new(DriverProfile::new("Jack Jackson", vec![]));
+        loaded_karting_time
+            .application_state
+            .csv_import
+            .saved_mappings = vec![
+            CsvColumnMapping {
+                source_name: "Club Timing".to_string(),
+                laptime_column: 3,
+                ..Default::default()
+            },
+            CsvColumnMapping {
+                source_name: "Track Day".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        // When
+        karting_time.replace_karting_time(loaded_karting_time);
+
+        // Then
+        pretty_assertions::assert_eq!("Jack Jackson", karting_time.driver_profile.name);
+        assert!(karting_time.application_state.is_light_theme);
+        pretty_assertions::assert_eq!(5, karting_time.driver_profile.filter.pagination.page_size);
+        pretty_assertions::assert_eq!(
+            vec!["profile.toml".to_string()],
+            karting_time.application_state.settings.recent_profiles
+        );
+
+        let saved_mappings = &karting_time.application_state.csv_import.saved_mappings;
+        pretty_assertions::assert_eq!(2, saved_mappings.len());
+        pretty_assertions::assert_eq!(2, saved_mappings[0].laptime_column);
+        pretty_assertions::assert_eq!("Track Day", saved_mappings[1].source_name);
+    }
+
+    #[test]
+    fn test_add_recent_profile() {
+        // Given
+        let mut karting_time = KartingTime::default();
+
+        for profile_number in 0..12 {
+            karting_time.add_recent_profile(&format!("profile_{profile_number}.toml"));
+        }
+
+        // When
+        karting_time.add_recent_profile("profile_5.toml");
+
+        // Then
+        let settings = &karting_time.application_state.settings;
+        pretty_assertions::assert_eq!(MAXIMUM_RECENT_PROFILES, settings.recent_profiles.len());
+        pretty_assertions::assert_eq!("profile_5.toml", settings.recent_profiles[0]);
+        pretty_assertions::assert_eq!("profile_11.toml", settings.recent_profiles[1]);
+        pretty_assertions::assert_eq!(
+            1,
+            settings
+                .recent_profiles
+                .iter()
+                .filter(|recent_profile| *recent_profile == "profile_5.toml")
+                .count()
+        );
+        pretty_assertions::assert_eq!(Some("profile_5.toml".to_string()), settings.last_profile);
+    }
+
+    #[test]
+    fn test_remember_folder() {
+        // Given
+        let folder = "./karting_time_test_remember_folder";
+        let mut karting_time = KartingTime::default();
+
+        // When
+        let _guard = TestFileGuard::new(folder);
+        fs::create_dir_all(folder).unwrap();
+
+        karting_time.remember_folder(FileFolder::Imports, folder);
+        karting_time.remember_folder(FileFolder::Exports, "./exports/races.html");
+        karting_time.remember_folder(FileFolder::Profiles, "profile.toml");
+
+        // Then
+        pretty_assertions::assert_eq!(
+            Some(folder.to_string()),
+            karting_time.file_folder(FileFolder::Imports)
+        );
+        pretty_assertions::assert_eq!(
+            Some("./exports".to_string()),
+            karting_time.file_folder(FileFolder::Exports)
+        );
+        pretty_assertions::assert_eq!(None, karting_time.file_folder(FileFolder::Profiles));
+    }
+
+    #[test]
+    fn test_backup_folder_is_next_to_the_current_profile() {
+        // Given
+        let folder_location = "./karting_time_test_backup_folder";
+        let _guard = TestFileGuard::new(folder_location);
+        fs::create_dir_all(format!("{folder_location}/backups")).unwrap();
+        let mut karting_time = KartingTime::default();
+        karting_time.application_state.settings.profiles_folder = Some("profiles".to_string());
+
+        // When
+        let folder_without_profile = karting_time.backup_folder();
+        karting_time.application_state.settings.last_profile =
+            Some(format!("{folder_location}/profile.toml"));
+        let backup_folder = karting_time.backup_folder();
+
+        // Then
+        pretty_assertions::assert_eq!(Some("profiles".to_string()), folder_without_profile);
+        pretty_assertions::assert_eq!(Some(format!("{folder_location}/backups")), backup_folder);
+    }
+}
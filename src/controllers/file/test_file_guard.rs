@@ -8,7 +8,9 @@ pub struct TestFileGuard {
 
 impl Drop for TestFileGuard {
     fn drop(&mut self) {
-        if self.file_path.exists() {
+        if self.file_path.is_dir() {
+            fs::remove_dir_all(&self.file_path).unwrap_or_default();
+        } else if self.file_path.exists() {
             fs::remove_file(&self.file_path).unwrap_or_default();
         }
     }
@@ -0,0 +1,78 @@
+use crate::{commands::messages::Message, models::application::unsaved_changes::UnsavedChanges};
+
+impl UnsavedChanges {
+    pub fn mark_changed(&mut self) {
+        self.is_dirty = true;
+    }
+
+    // Holds back a message that would lose unsaved changes until the user confirms it
+    pub fn hold_if_unsaved(&mut self, message: Message) -> bool {
+        if !self.is_dirty {
+            return false;
+        }
+
+        self.pending_message = Some(message);
+
+        true
+    }
+
+    pub fn discard_changes(&mut self) -> Option<Message> {
+        self.is_dirty = false;
+
+        self.pending_message.take()
+    }
+
+    pub fn keep_changes(&mut self) {
+        self.pending_message = None;
+    }
+}
+
+#[cfg(test)]
+mod unsaved_changes_should {
+    use super::*;
+
+    #[test]
+    fn test_proceed_without_changes() {
+        // Given
+        let mut unsaved_changes = UnsavedChanges::default();
+
+        // When
+        let is_held = unsaved_changes.hold_if_unsaved(Message::FileNew);
+
+        // Then
+        assert!(!is_held);
+        assert!(unsaved_changes.pending_message.is_none());
+    }
+
+    #[test]
+    fn test_hold_then_discard_changes() {
+        // Given
+        let mut unsaved_changes = UnsavedChanges::default();
+        unsaved_changes.mark_changed();
+
+        // When
+        let is_held = unsaved_changes.hold_if_unsaved(Message::FileNew);
+        let pending_message = unsaved_changes.discard_changes();
+
+        // Then
+        assert!(is_held);
+        pretty_assertions::assert_eq!(Some(Message::FileNew), pending_message);
+        assert!(!unsaved_changes.is_dirty);
+        assert!(!unsaved_changes.hold_if_unsaved(Message::FileNew));
+    }
+
+    #[test]
+    fn test_hold_then_keep_changes() {
+        // Given
+        let mut unsaved_changes = UnsavedChanges::default();
+        unsaved_changes.mark_changed();
+
+        // When
+        unsaved_changes.hold_if_unsaved(Message::LoadApplicationRequested);
+        unsaved_changes.keep_changes();
+
+        // Then
+        assert!(unsaved_changes.is_dirty);
+        assert!(unsaved_changes.pending_message.is_none());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::models::application::watch_folders::WatchFolders;
+use std::{fs, path::Path};
+
+const MAXIMUM_ANNOUNCEMENTS: usize = 10;
+
+impl WatchFolders {
+    pub fn add_folder(&mut self, folder: &str) {
+        if !self
+            .folders
+            .iter()
+            .any(|watched_folder| watched_folder == folder)
+        {
+            self.folders.push(folder.to_string());
+        }
+    }
+
+    pub fn remove_folder(&mut self, folder: &str) {
+        self.folders
+            .retain(|watched_folder| watched_folder != folder);
+        self.file_modified_times
+            .retain(|file_name, _| Path::new(file_name).parent() != Some(Path::new(folder)));
+    }
+
+    // Returns the files that are new or modified since the folders were last checked
+    pub fn find_changed_files(&mut self) -> Vec<String> {
+        let mut changed_files = vec![];
+
+        for folder in &self.folders {
+            let Ok(entries) = fs::read_dir(folder) else {
+                continue;
+            };
+
+            for entry in entries.flatten() {
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+
+                if !metadata.is_file() {
+                    continue;
+                }
+
+                let Ok(modified_time) = metadata.modified() else {
+                    continue;
+                };
+
+                let Some(file_name) = entry.path().to_str().map(|s| s.to_string()) else {
+                    continue;
+                };
+
+                if self.file_modified_times.get(&file_name) != Some(&modified_time) {
+                    self.file_modified_times
+                        .insert(file_name.clone(), modified_time);
+                    changed_files.push(file_name);
+                }
+            }
+        }
+
+        changed_files.sort();
+        changed_files
+    }
+
+    pub fn announce(&mut self, announcement: String) {
+        self.announcements.insert(0, announcement);
+        self.announcements.truncate(MAXIMUM_ANNOUNCEMENTS);
+    }
+}
+
+#[cfg(test)]
+mod watch_folders_should {
+    use super::*;
+    use crate::controllers::file::test_file_guard::TestFileGuard;
+    use std::{
+        fs::File,
+        time::{Duration, SystemTime},
+    };
+
+    #[test]
+    fn test_find_changed_files() {
+        // Given
+        let folder = "./watch_folder_changed_files_test";
+        let _test_file_guard = TestFileGuard::new(folder);
+        fs::create_dir_all(format!("{folder}/nested")).unwrap();
+        fs::write(format!("{folder}/session_1.json"), "{}").unwrap();
+        fs::write(format!("{folder}/session_2.json"), "{}").unwrap();
+
+        let mut watch_folders = WatchFolders::default();
+        watch_folders.add_folder(folder);
+        watch_folders.add_folder(folder);
+
+        // When
+        let new_files = watch_folders.find_changed_files();
+        let unchanged_files = watch_folders.find_changed_files();
+
+        File::options()
+            .write(true)
+            .open(format!("{folder}/session_2.json"))
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+        let modified_files = watch_folders.find_changed_files();
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![
+                format!("{folder}/session_1.json"),
+                format!("{folder}/session_2.json")
+            ],
+            new_files
+        );
+        assert!(unchanged_files.is_empty());
+        pretty_assertions::assert_eq!(vec![format!("{folder}/session_2.json")], modified_files);
+    }
+
+    #[test]
+    fn test_remove_folder() {
+        // Given
+        let mut watch_folders = WatchFolders::default();
+        watch_folders.add_folder("./results");
+        watch_folders.add_folder("./other_results");
+        watch_folders
+            .file_modified_times
+            .insert("./results/session_1.json".to_string(), SystemTime::now());
+
+        // When
+        watch_folders.remove_folder("./results");
+
+        // Then
+        pretty_assertions::assert_eq!(vec!["./other_results".to_string()], watch_folders.folders);
+        assert!(watch_folders.file_modified_times.is_empty());
+    }
+
+    #[test]
+    fn test_keep_latest_announcements() {
+        // Given
+        let mut watch_folders = WatchFolders::default();
+
+        // When
+        for announcement in 0..12 {
+            watch_folders.announce(announcement.to_string());
+        }
+
+        // Then
+        pretty_assertions::assert_eq!(MAXIMUM_ANNOUNCEMENTS, watch_folders.announcements.len());
+        pretty_assertions::assert_eq!("11", watch_folders.announcements[0]);
+    }
+}
@@ -29,6 +29,11 @@ impl Filter {
         self.update_pagination();
     }
 
+    pub fn change_page_size(&mut self, page_size: usize) {
+        self.pagination.page_size = page_size.max(1);
+        self.pagination.current_page = 0;
+    }
+
     pub fn update_pagination(&mut self) {
         self.update_total_page_count();
 
@@ -62,6 +67,7 @@ mod paginate_race_results_should {
         },
         filters::pagination::Pagination,
     };
+    use rstest::rstest;
 
     #[test]
     fn test_display_page_message_on_previous_page() {
@@ -121,6 +127,22 @@ mod paginate_race_results_should {
         pretty_assertions::assert_eq!("Page 1 of 5".to_string(), current_page)
     }
 
+    #[rstest]
+    #[case(20, 20)]
+    #[case(0, 1)]
+    fn test_change_page_size(#[case] page_size: usize, #[case] expected_page_size: usize) {
+        // Given
+        let mut filter = Filter::default();
+        filter.pagination.current_page = 3;
+
+        // When
+        filter.change_page_size(page_size);
+
+        // Then
+        pretty_assertions::assert_eq!(expected_page_size, filter.pagination.page_size);
+        pretty_assertions::assert_eq!(0, filter.pagination.current_page);
+    }
+
     #[test]
     fn test_next_page() {
         // Given
@@ -139,7 +161,9 @@ mod paginate_race_results_should {
             ),
             race_statistics: Default::default(),
             laptimes: vec![Lap::new(1, 56.5)],
+            gps_trace: Default::default(),
             is_deleting: false,
+            statistics_windows: Default::default(),
         };
         race_result.race_statistics = RaceStatistics::new(&race_result);
 
@@ -265,7 +289,9 @@ mod paginate_race_results_should {
             ),
             race_statistics: Default::default(),
             laptimes: vec![Lap::new(1, 56.5)],
+            gps_trace: Default::default(),
             is_deleting: false,
+            statistics_windows: Default::default(),
         };
         race_result.race_statistics = RaceStatistics::new(&race_result);
 
@@ -386,7 +412,9 @@ mod paginate_race_results_should {
             ),
             race_statistics: Default::default(),
             laptimes: vec![Lap::new(1, 56.5)],
+            gps_trace: Default::default(),
             is_deleting: false,
+            statistics_windows: Default::default(),
         };
         race_result.race_statistics = RaceStatistics::new(&race_result);
 
@@ -511,7 +539,9 @@ mod paginate_race_results_should {
             ),
             race_statistics: Default::default(),
             laptimes: vec![Lap::new(1, 56.5)],
+            gps_trace: Default::default(),
             is_deleting: false,
+            statistics_windows: Default::default(),
         };
         race_result.race_statistics = RaceStatistics::new(&race_result);
 
@@ -639,7 +669,9 @@ mod paginate_race_results_should {
             ),
             race_statistics: Default::default(),
             laptimes: vec![Lap::new(1, 56.5)],
+            gps_trace: Default::default(),
             is_deleting: false,
+            statistics_windows: Default::default(),
         };
         race_result.race_statistics = RaceStatistics::new(&race_result);
 
@@ -765,7 +797,9 @@ mod paginate_race_results_should {
             ),
             race_statistics: Default::default(),
             laptimes: vec![Lap::new(1, 56.5)],
+            gps_trace: Default::default(),
             is_deleting: false,
+            statistics_windows: Default::default(),
         };
         race_result.race_statistics = RaceStatistics::new(&race_result);
 
@@ -3,3 +3,5 @@ pub mod driver_profile;
 pub mod file;
 pub mod filters;
 pub mod race_results;
+pub mod storage;
+pub mod telemetry;
@@ -1,4 +1,7 @@
 pub mod edit_existing_race;
+pub mod gps_statistics;
+pub mod race_consistency;
+pub mod race_pace_trend;
 pub mod race_results_summary;
 pub mod race_time_summaries;
 pub mod upsert_race;
@@ -174,10 +174,7 @@ mod edit_existing_race_should {
                 Default::default(),
                 Default::default(),
             ),
-            vec![Lap {
-                lap_number: 1,
-                time: 10.5,
-            }],
+            vec![Lap::new(1, 10.5)],
         );
 
         let existing_races = vec![
@@ -0,0 +1,216 @@
+use crate::models::driver::session_information::{
+    gps_session::{GpsPoint, GpsStatistics},
+    race_result::RaceResult,
+};
+
+const EARTH_RADIUS: f64 = 6_371_000.0;
+
+pub fn distance_between(from: &GpsPoint, to: &GpsPoint) -> f64 {
+    let latitude_from = from.latitude.to_radians();
+    let latitude_to = to.latitude.to_radians();
+    let latitude_difference = (to.latitude - from.latitude).to_radians();
+    let longitude_difference = (to.longitude - from.longitude).to_radians();
+
+    let haversine = (latitude_difference / 2.0).sin().powi(2)
+        + latitude_from.cos() * latitude_to.cos() * (longitude_difference / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS * haversine.sqrt().asin()
+}
+
+impl RaceResult {
+    pub fn calculate_gps_statistics(&self) -> Option<GpsStatistics> {
+        if self.gps_trace.len() < 2 {
+            return None;
+        }
+
+        let mut distance = 0.0;
+        let mut max_speed: f32 = 0.0;
+
+        for pair in self.gps_trace.windows(2) {
+            let segment_distance = distance_between(&pair[0], &pair[1]);
+            let segment_time = pair[1].elapsed - pair[0].elapsed;
+
+            distance += segment_distance;
+
+            let segment_speed = match pair[1].speed {
+                Some(speed) => speed,
+                None if segment_time > 0.0 => (segment_distance / segment_time * 3.6) as f32,
+                None => 0.0,
+            };
+
+            max_speed = max_speed.max(segment_speed);
+        }
+
+        let elapsed = self.gps_trace[self.gps_trace.len() - 1].elapsed - self.gps_trace[0].elapsed;
+
+        let average_speed = match elapsed > 0.0 {
+            true => (distance / elapsed * 3.6) as f32,
+            false => 0.0,
+        };
+
+        Some(GpsStatistics {
+            distance,
+            lap_distance: self.calculate_lap_distance(),
+            max_speed,
+            average_speed,
+        })
+    }
+
+    // Points of the first complete lap projected on to a unit square, or the whole trace without laps
+    pub fn calculate_track_outline(&self) -> Vec<(f32, f32)> {
+        let lap_points = self.first_complete_lap_points();
+
+        let points = match lap_points.len() < 2 {
+            true => self.gps_trace.clone(),
+            false => lap_points,
+        };
+
+        let Some(first_point) = points.first() else {
+            return vec![];
+        };
+
+        let longitude_scale = first_point.latitude.to_radians().cos();
+
+        let projected: Vec<(f64, f64)> = points
+            .iter()
+            .map(|point| (point.longitude * longitude_scale, -point.latitude))
+            .collect();
+
+        let (mut min_x, mut min_y) = (f64::MAX, f64::MAX);
+        let (mut max_x, mut max_y) = (f64::MIN, f64::MIN);
+
+        for (x, y) in &projected {
+            min_x = min_x.min(*x);
+            min_y = min_y.min(*y);
+            max_x = max_x.max(*x);
+            max_y = max_y.max(*y);
+        }
+
+        let size = (max_x - min_x).max(max_y - min_y);
+        if size <= 0.0 {
+            return vec![];
+        }
+
+        projected
+            .iter()
+            .map(|(x, y)| (((x - min_x) / size) as f32, ((y - min_y) / size) as f32))
+            .collect()
+    }
+
+    fn calculate_lap_distance(&self) -> f64 {
+        self.first_complete_lap_points()
+            .windows(2)
+            .map(|pair| distance_between(&pair[0], &pair[1]))
+            .sum()
+    }
+
+    fn first_complete_lap_points(&self) -> Vec<GpsPoint> {
+        let Some(first_lap_number) = self
+            .gps_trace
+            .iter()
+            .map(|point| point.lap_number)
+            .find(|lap_number| *lap_number != 0)
+        else {
+            return vec![];
+        };
+
+        let mut points: Vec<GpsPoint> = self
+            .gps_trace
+            .iter()
+            .filter(|point| point.lap_number == first_lap_number)
+            .copied()
+            .collect();
+
+        // The lap is only complete when the next one starts, which also closes the outline
+        match self
+            .gps_trace
+            .iter()
+            .find(|point| point.lap_number > first_lap_number)
+        {
+            Some(next_lap_start) => points.push(*next_lap_start),
+            None => points.clear(),
+        }
+
+        points
+    }
+}
+
+#[cfg(test)]
+mod gps_statistics_should {
+    use super::*;
+
+    fn gps_point(elapsed: f64, latitude: f64, longitude: f64, lap_number: u32) -> GpsPoint {
+        GpsPoint {
+            elapsed,
+            latitude,
+            longitude,
+            speed: None,
+            lap_number,
+        }
+    }
+
+    #[test]
+    fn test_distance_between() {
+        // Given
+        let from = gps_point(0.0, 51.0, 0.9, 0);
+        let to = gps_point(0.0, 51.001, 0.9, 0);
+
+        // When
+        let distance = distance_between(&from, &to);
+
+        // Then
+        assert!((distance - 111.19).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_calculate_gps_statistics() {
+        // Given
+        let race = RaceResult {
+            gps_trace: vec![
+                gps_point(0.0, 51.0, 0.9, 1),
+                gps_point(10.0, 51.001, 0.9, 1),
+                gps_point(20.0, 51.0, 0.9, 2),
+                gps_point(25.0, 51.001, 0.9, 2),
+            ],
+            ..Default::default()
+        };
+
+        // When
+        let gps_statistics = race.calculate_gps_statistics().unwrap();
+
+        // Then
+        assert!((gps_statistics.distance - 333.58).abs() < 0.01);
+        assert!((gps_statistics.lap_distance - 222.39).abs() < 0.01);
+        assert!((gps_statistics.max_speed - 80.06).abs() < 0.01);
+        assert!((gps_statistics.average_speed - 48.03).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_calculate_gps_statistics_without_trace() {
+        // When
+        let gps_statistics = RaceResult::default().calculate_gps_statistics();
+
+        // Then
+        assert!(gps_statistics.is_none());
+    }
+
+    #[test]
+    fn test_calculate_track_outline() {
+        // Given
+        let race = RaceResult {
+            gps_trace: vec![
+                gps_point(0.0, 51.0, 0.9, 0),
+                gps_point(10.0, 51.0, 0.9, 1),
+                gps_point(20.0, 51.001, 0.9, 1),
+                gps_point(30.0, 51.0, 0.9, 2),
+            ],
+            ..Default::default()
+        };
+
+        // When
+        let track_outline = race.calculate_track_outline();
+
+        // Then
+        pretty_assertions::assert_eq!(vec![(0.0, 1.0), (0.0, 0.0), (0.0, 1.0)], track_outline);
+    }
+}
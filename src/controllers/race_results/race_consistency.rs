@@ -0,0 +1,179 @@
+use crate::models::driver::session_information::{
+    consistency_statistics::ConsistencyStatistics, race_result::RaceResult,
+};
+use std::cmp::Ordering;
+
+const CONSISTENT_LAP_PERCENTAGE: f32 = 2.0;
+
+impl RaceResult {
+    // Laps that couldn't be read are kept as 0 seconds so they're left out of the spread, and
+    // end a consistent run
+    pub fn calculate_consistency(&self) -> ConsistencyStatistics {
+        let all_laptimes: Vec<f32> = self.laptimes.iter().map(|lap| lap.time).collect();
+        let laptimes: Vec<f32> = all_laptimes
+            .iter()
+            .copied()
+            .filter(|time| is_valid_time(*time))
+            .collect();
+
+        if laptimes.is_empty() {
+            return ConsistencyStatistics::default();
+        }
+
+        let number_of_laps = laptimes.len() as f32;
+        let mean_lap = laptimes.iter().sum::<f32>() / number_of_laps;
+        let variance = laptimes
+            .iter()
+            .map(|time| (time - mean_lap).powi(2))
+            .sum::<f32>()
+            / number_of_laps;
+        let standard_deviation = variance.sqrt();
+
+        let mut sorted_laptimes = laptimes.clone();
+        sorted_laptimes.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let fastest_lap = sorted_laptimes[0];
+
+        ConsistencyStatistics {
+            standard_deviation,
+            coefficient_of_variation: standard_deviation / mean_lap * 100.0,
+            median_lap: percentile(&sorted_laptimes, 0.5),
+            interquartile_range: percentile(&sorted_laptimes, 0.75)
+                - percentile(&sorted_laptimes, 0.25),
+            laps_within_1_percent: percentage_of_laps_within(&laptimes, fastest_lap, 1.0),
+            laps_within_2_percent: percentage_of_laps_within(&laptimes, fastest_lap, 2.0),
+            longest_consistent_run: longest_run_within(
+                &all_laptimes,
+                fastest_lap,
+                CONSISTENT_LAP_PERCENTAGE,
+            ),
+        }
+    }
+}
+
+// Interpolates between the two closest laps when the fraction falls between them
+pub fn percentile(sorted_laptimes: &[f32], fraction: f32) -> f32 {
+    let position = (sorted_laptimes.len() - 1) as f32 * fraction;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+
+    sorted_laptimes[lower]
+        + (sorted_laptimes[upper] - sorted_laptimes[lower]) * (position - lower as f32)
+}
+
+fn is_valid_time(time: f32) -> bool {
+    time > 0.0
+}
+
+fn is_within(time: f32, fastest_lap: f32, percentage: f32) -> bool {
+    time <= fastest_lap * (1.0 + percentage / 100.0)
+}
+
+fn percentage_of_laps_within(laptimes: &[f32], fastest_lap: f32, percentage: f32) -> f32 {
+    let laps_within = laptimes
+        .iter()
+        .filter(|time| is_within(**time, fastest_lap, percentage))
+        .count();
+
+    laps_within as f32 / laptimes.len() as f32 * 100.0
+}
+
+fn longest_run_within(laptimes: &[f32], fastest_lap: f32, percentage: f32) -> u32 {
+    let mut longest_run = 0;
+    let mut current_run = 0;
+
+    for time in laptimes {
+        match is_valid_time(*time) && is_within(*time, fastest_lap, percentage) {
+            true => {
+                current_run += 1;
+                longest_run = longest_run.max(current_run);
+            }
+            false => current_run = 0,
+        }
+    }
+
+    longest_run
+}
+
+#[cfg(test)]
+mod race_consistency_should {
+    use super::*;
+    use crate::models::driver::session_information::lap::Lap;
+
+    fn race(laptimes: &[f32]) -> RaceResult {
+        RaceResult::new(
+            Default::default(),
+            Default::default(),
+            laptimes
+                .iter()
+                .enumerate()
+                .map(|(index, time)| Lap::new(index as u32 + 1, *time))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_calculate_consistency() {
+        // Given
+        let race = race(&[50.0, 50.4, 50.8, 60.0, 50.2, 50.6, 51.5, 50.3]);
+        let expected_consistency = ConsistencyStatistics {
+            standard_deviation: 3.1570356,
+            coefficient_of_variation: 6.103501,
+            median_lap: 50.5,
+            interquartile_range: 0.69999695,
+            laps_within_1_percent: 50.0,
+            laps_within_2_percent: 75.0,
+            longest_consistent_run: 3,
+        };
+
+        // When
+        let consistency = race.calculate_consistency();
+
+        // Then
+        pretty_assertions::assert_eq!(expected_consistency, consistency);
+    }
+
+    #[test]
+    fn test_calculate_consistency_of_identical_laps() {
+        // Given
+        let race = race(&[50.0, 0.0, 50.0, 50.0]);
+        let expected_consistency = ConsistencyStatistics {
+            standard_deviation: 0.0,
+            coefficient_of_variation: 0.0,
+            median_lap: 50.0,
+            interquartile_range: 0.0,
+            laps_within_1_percent: 100.0,
+            laps_within_2_percent: 100.0,
+            longest_consistent_run: 2,
+        };
+
+        // When
+        let consistency = race.calculate_consistency();
+
+        // Then
+        pretty_assertions::assert_eq!(expected_consistency, consistency);
+    }
+
+    #[test]
+    fn test_unreadable_lap_ends_consistent_run() {
+        // Given
+        let race = race(&[50.0, 50.2, 0.0, 50.3, 50.1, 60.0]);
+
+        // When
+        let consistency = race.calculate_consistency();
+
+        // Then
+        pretty_assertions::assert_eq!(2, consistency.longest_consistent_run);
+    }
+
+    #[test]
+    fn test_calculate_no_consistency_without_laps() {
+        // Given
+        let race = race(&[]);
+
+        // When
+        let consistency = race.calculate_consistency();
+
+        // Then
+        pretty_assertions::assert_eq!(ConsistencyStatistics::default(), consistency);
+    }
+}
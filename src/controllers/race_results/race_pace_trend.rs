@@ -0,0 +1,203 @@
+use crate::{
+    controllers::race_results::race_consistency::percentile,
+    models::driver::session_information::{
+        lap::Lap,
+        pace_trend::{PaceTrend, StintSplit},
+        race_result::RaceResult,
+    },
+};
+use std::cmp::Ordering;
+
+const OUTLIER_FENCE: f32 = 1.5;
+
+impl RaceResult {
+    // Spins, pit laps and laps that couldn't be read are left out of the trend so one slow lap
+    // doesn't read as the whole race fading
+    pub fn calculate_pace_trend(&self) -> PaceTrend {
+        let mut laps: Vec<&Lap> = self.laptimes.iter().collect();
+        laps.sort_by_key(|lap| lap.lap_number);
+
+        let outlier_laps = find_outlier_laps(&laps);
+        let clean_laps: Vec<&Lap> = laps
+            .iter()
+            .copied()
+            .filter(|lap| !outlier_laps.contains(&lap.lap_number))
+            .collect();
+
+        if clean_laps.is_empty() {
+            return PaceTrend {
+                outlier_laps,
+                ..Default::default()
+            };
+        }
+
+        let (degradation_per_lap, intercept) = linear_regression(&clean_laps);
+        let (first_half, second_half) = clean_laps.split_at(clean_laps.len() / 2);
+
+        PaceTrend {
+            degradation_per_lap,
+            intercept,
+            first_half_average: average_lap(first_half),
+            second_half_average: average_lap(second_half),
+            stints: self.split_stints(&laps, &outlier_laps),
+            outlier_laps,
+        }
+    }
+
+    // Stints follow the laps per bucket of the race's statistics windows
+    fn split_stints(&self, laps: &[&Lap], outlier_laps: &[u32]) -> Vec<StintSplit> {
+        laps.chunks(self.statistics_windows.laps_per_bucket())
+            .filter_map(|stint| {
+                let clean_laps: Vec<&Lap> = stint
+                    .iter()
+                    .copied()
+                    .filter(|lap| !outlier_laps.contains(&lap.lap_number))
+                    .collect();
+
+                match clean_laps.is_empty() {
+                    true => None,
+                    false => Some(StintSplit {
+                        first_lap: stint[0].lap_number,
+                        last_lap: stint[stint.len() - 1].lap_number,
+                        average_lap: average_lap(&clean_laps),
+                    }),
+                }
+            })
+            .collect()
+    }
+}
+
+// Laps outside 1.5 interquartile ranges of the middle half of the race are outliers
+fn find_outlier_laps(laps: &[&Lap]) -> Vec<u32> {
+    let mut sorted_laptimes: Vec<f32> = laps
+        .iter()
+        .map(|lap| lap.time)
+        .filter(|time| *time > 0.0)
+        .collect();
+    sorted_laptimes.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    if sorted_laptimes.is_empty() {
+        return laps.iter().map(|lap| lap.lap_number).collect();
+    }
+
+    let lower_quartile = percentile(&sorted_laptimes, 0.25);
+    let upper_quartile = percentile(&sorted_laptimes, 0.75);
+    let fence = (upper_quartile - lower_quartile) * OUTLIER_FENCE;
+
+    laps.iter()
+        .filter(|lap| {
+            lap.time <= 0.0
+                || lap.time < lower_quartile - fence
+                || lap.time > upper_quartile + fence
+        })
+        .map(|lap| lap.lap_number)
+        .collect()
+}
+
+// Least squares fit of lap time against lap number, returns the slope and the intercept
+fn linear_regression(laps: &[&Lap]) -> (f32, f32) {
+    let number_of_laps = laps.len() as f32;
+    let mean_lap_number =
+        laps.iter().map(|lap| lap.lap_number as f32).sum::<f32>() / number_of_laps;
+    let mean_time = average_lap(laps);
+
+    let (covariance, variance) = laps.iter().fold((0.0, 0.0), |(covariance, variance), lap| {
+        let lap_number_offset = lap.lap_number as f32 - mean_lap_number;
+
+        (
+            covariance + lap_number_offset * (lap.time - mean_time),
+            variance + lap_number_offset.powi(2),
+        )
+    });
+
+    let slope = match variance > 0.0 {
+        true => covariance / variance,
+        false => 0.0,
+    };
+
+    (slope, mean_time - slope * mean_lap_number)
+}
+
+fn average_lap(laps: &[&Lap]) -> f32 {
+    match laps.is_empty() {
+        true => 0.0,
+        false => laps.iter().map(|lap| lap.time).sum::<f32>() / laps.len() as f32,
+    }
+}
+
+#[cfg(test)]
+mod race_pace_trend_should {
+    use crate::models::driver::session_information::{
+        lap::Lap, pace_trend::StintSplit, race_result::RaceResult,
+    };
+
+    fn race(laptimes: &[f32]) -> RaceResult {
+        RaceResult::new(
+            Default::default(),
+            Default::default(),
+            laptimes
+                .iter()
+                .enumerate()
+                .map(|(index, time)| Lap::new(index as u32 + 1, *time))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_calculate_degradation_excluding_outliers() {
+        // Given
+        let race = race(&[50.0, 50.5, 51.0, 75.0, 52.0, 52.5, 0.0, 53.5]);
+
+        // When
+        let pace_trend = race.calculate_pace_trend();
+
+        // Then
+        pretty_assertions::assert_eq!(vec![4, 7], pace_trend.outlier_laps);
+        pretty_assertions::assert_eq!(0.5, pace_trend.degradation_per_lap);
+        pretty_assertions::assert_eq!(49.5, pace_trend.intercept);
+        pretty_assertions::assert_eq!(50.5, pace_trend.first_half_average);
+        pretty_assertions::assert_eq!(52.666668, pace_trend.second_half_average);
+    }
+
+    #[test]
+    fn test_split_stints() {
+        // Given
+        let race = race(&[
+            50.0, 50.0, 50.0, 50.0, 50.0, 51.0, 51.0, 51.0, 51.0, 51.0, 52.0,
+        ]);
+
+        // When
+        let pace_trend = race.calculate_pace_trend();
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![
+                StintSplit {
+                    first_lap: 1,
+                    last_lap: 5,
+                    average_lap: 50.0,
+                },
+                StintSplit {
+                    first_lap: 6,
+                    last_lap: 10,
+                    average_lap: 51.0,
+                },
+                StintSplit {
+                    first_lap: 11,
+                    last_lap: 11,
+                    average_lap: 52.0,
+                },
+            ],
+            pace_trend.stints
+        );
+    }
+
+    #[test]
+    fn test_calculate_pace_trend_without_laps() {
+        // When
+        let pace_trend = race(&[]).calculate_pace_trend();
+
+        // Then
+        pretty_assertions::assert_eq!(Default::default(), pace_trend);
+    }
+}
@@ -22,10 +22,11 @@ impl RaceResult {
 
     pub fn get_average_lap(&self) -> f32 {
         let fastest_lap_time = self.get_fastest_lap();
+        let average_cutoff = self.statistics_windows.cutoff_percentage() as f32 / 100.0;
         let valid_average_laps: Vec<&Lap> = self
             .laptimes
             .iter()
-            .filter(|lap| lap.time <= fastest_lap_time * 1.05)
+            .filter(|lap| lap.time <= fastest_lap_time * average_cutoff)
             .collect();
 
         match valid_average_laps.is_empty() {
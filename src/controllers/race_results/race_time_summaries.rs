@@ -1,6 +1,12 @@
 use std::{cmp::Ordering, collections::HashMap};
 
-use crate::models::driver::session_information::{lap::Lap, race_result::RaceResult};
+use crate::{
+    controllers::converters::time_parser::format_laptime,
+    models::{
+        application::time_format::TimeFormat,
+        driver::session_information::{lap::Lap, race_result::RaceResult},
+    },
+};
 
 impl RaceResult {
     pub fn calculate_total_times(&self) -> HashMap<usize, f32> {
@@ -9,12 +15,13 @@ impl RaceResult {
         let mut current_lap = 0;
 
         let fastest_laps = self.order_by_fastest_lap();
+        let laps_per_bucket = self.statistics_windows.laps_per_bucket();
 
         for lap in &fastest_laps {
             current_sum += lap.time;
             current_lap += 1;
 
-            if current_lap % 5 == 0 || current_lap == fastest_laps.len() {
+            if current_lap % laps_per_bucket == 0 || current_lap == fastest_laps.len() {
                 total_times.insert(current_lap, current_sum);
             }
         }
@@ -22,18 +29,63 @@ impl RaceResult {
         total_times
     }
 
-    pub fn get_time_by_key(times: &[(usize, String)], key_to_find: usize) -> String {
+    // Unlike the totals these are real stints, the fastest run of N laps in the order they were
+    // driven, a run with a lap that couldn't be read isn't counted
+    pub fn calculate_consecutive_times(&self) -> HashMap<usize, f32> {
+        let mut consecutive_times = HashMap::new();
+
+        let laps = self.order_by_lap_number();
+        let laps_per_bucket = self.statistics_windows.laps_per_bucket();
+
+        let window_sizes = (laps_per_bucket..=laps.len())
+            .step_by(laps_per_bucket)
+            .chain(std::iter::once(laps.len()))
+            .filter(|window_size| *window_size > 0);
+
+        for window_size in window_sizes {
+            let mut window_sum: f32 = laps[..window_size].iter().map(|lap| lap.time).sum();
+            let mut invalid_laps = laps[..window_size]
+                .iter()
+                .filter(|lap| is_invalid_lap(lap))
+                .count();
+            let mut fastest_sum = (invalid_laps == 0).then_some(window_sum);
+
+            for index in window_size..laps.len() {
+                window_sum += laps[index].time - laps[index - window_size].time;
+                invalid_laps += usize::from(is_invalid_lap(&laps[index]));
+                invalid_laps -= usize::from(is_invalid_lap(&laps[index - window_size]));
+
+                if invalid_laps == 0 {
+                    fastest_sum = Some(
+                        fastest_sum.map_or(window_sum, |fastest_sum| fastest_sum.min(window_sum)),
+                    );
+                }
+            }
+
+            if let Some(fastest_sum) = fastest_sum {
+                consecutive_times.insert(window_size, fastest_sum);
+            }
+        }
+
+        consecutive_times
+    }
+
+    pub fn get_time_by_key(
+        times: &[(usize, f32)],
+        key_to_find: usize,
+        time_format: TimeFormat,
+    ) -> String {
         times
             .iter()
             .find(|(k, _)| *k == key_to_find)
-            .map(|(_, v)| v.clone())
+            .map(|(_, v)| format_laptime(*v, time_format))
             .unwrap_or_else(|| "N/A".to_string())
     }
 
-    pub fn get_last_time(times: &[(usize, String)]) -> String {
+    pub fn get_last_time(times: &[(usize, f32)], time_format: TimeFormat) -> String {
         times
             .last()
-            .map(|(_, v)| v.clone())
+            .map(|(_, v)| format_laptime(*v, time_format))
             .unwrap_or_else(|| "N/A".to_string())
     }
 
@@ -60,11 +112,24 @@ impl RaceResult {
         sorted_laps.sort_by(|a, b| a.time.partial_cmp(&b.time).unwrap_or(Ordering::Greater));
         sorted_laps
     }
+
+    fn order_by_lap_number(&self) -> Vec<Lap> {
+        let mut sorted_laps = self.laptimes.clone();
+        sorted_laps.sort_by_key(|lap| lap.lap_number);
+        sorted_laps
+    }
+}
+
+fn is_invalid_lap(lap: &Lap) -> bool {
+    lap.time <= 0.0
 }
 
 #[cfg(test)]
 mod race_time_summaries_should {
-    use crate::models::driver::session_information::{lap::Lap, race_result::RaceResult};
+    use crate::models::{
+        application::time_format::TimeFormat,
+        driver::session_information::{lap::Lap, race_result::RaceResult},
+    };
 
     #[test]
     fn test_calculate_total_times() {
@@ -117,32 +182,96 @@ mod race_time_summaries_should {
         pretty_assertions::assert_eq!(83.299995, total_6_laps);
     }
 
+    #[test]
+    fn test_calculate_consecutive_times() {
+        // Given
+        let race = RaceResult::new(
+            Default::default(),
+            Default::default(),
+            vec![
+                Lap::new(1, 14.0),
+                Lap::new(2, 12.0),
+                Lap::new(3, 15.0),
+                Lap::new(4, 13.0),
+                Lap::new(5, 13.0),
+                Lap::new(6, 13.0),
+                Lap::new(7, 13.0),
+                Lap::new(8, 13.0),
+                Lap::new(9, 12.0),
+                Lap::new(10, 16.0),
+                Lap::new(11, 16.0),
+            ],
+        );
+
+        // When
+        let consecutive_times = race.calculate_consecutive_times();
+
+        // Then
+        pretty_assertions::assert_eq!(64.0, *consecutive_times.get(&5).unwrap());
+        pretty_assertions::assert_eq!(134.0, *consecutive_times.get(&10).unwrap());
+        pretty_assertions::assert_eq!(150.0, *consecutive_times.get(&11).unwrap());
+        pretty_assertions::assert_eq!(3, consecutive_times.len());
+    }
+
+    #[test]
+    fn test_calculate_consecutive_times_skips_unreadable_laps() {
+        // Given
+        let race = RaceResult::new(
+            Default::default(),
+            Default::default(),
+            vec![
+                Lap::new(1, 13.0),
+                Lap::new(2, 13.0),
+                Lap::new(3, 13.0),
+                Lap::new(4, 13.0),
+                Lap::new(5, 13.0),
+                Lap::new(6, 0.0),
+                Lap::new(7, 12.0),
+                Lap::new(8, 12.0),
+                Lap::new(9, 12.0),
+                Lap::new(10, 12.0),
+                Lap::new(11, 12.0),
+            ],
+        );
+
+        // When
+        let consecutive_times = race.calculate_consecutive_times();
+
+        // Then
+        pretty_assertions::assert_eq!(60.0, *consecutive_times.get(&5).unwrap());
+        pretty_assertions::assert_eq!(1, consecutive_times.len());
+    }
+
     #[test]
     fn test_get_time_by_key() {
         // Given
-        let total_times = vec![
-            (5, "1:40.00".to_string()),
-            (10, "3:20.00".to_string()),
-            (15, "5:00.00".to_string()),
-        ];
+        let total_times = vec![(5, 100.0), (10, 200.0), (15, 300.0)];
 
         // Then
-        pretty_assertions::assert_eq!("1:40.00", RaceResult::get_time_by_key(&total_times, 5));
-        pretty_assertions::assert_eq!("3:20.00", RaceResult::get_time_by_key(&total_times, 10));
-        pretty_assertions::assert_eq!("5:00.00", RaceResult::get_time_by_key(&total_times, 15));
+        pretty_assertions::assert_eq!(
+            "1:40.000",
+            RaceResult::get_time_by_key(&total_times, 5, TimeFormat::default())
+        );
+        pretty_assertions::assert_eq!(
+            "3:20.000",
+            RaceResult::get_time_by_key(&total_times, 10, TimeFormat::default())
+        );
+        pretty_assertions::assert_eq!(
+            "N/A",
+            RaceResult::get_time_by_key(&total_times, 20, TimeFormat::default())
+        );
     }
 
     #[test]
     fn test_get_last_time() {
         // Given
-        let total_times = vec![
-            (5, "1:40.00".to_string()),
-            (10, "3:20.00".to_string()),
-            (15, "5:00.00".to_string()),
-        ];
+        let total_times = vec![(5, 100.0), (10, 200.0), (15, 300.0)];
 
         // Then
-        pretty_assertions::assert_eq!("5:00.00", RaceResult::get_last_time(&total_times));
+        pretty_assertions::assert_eq!(
+            "5:00.000",
+            RaceResult::get_last_time(&total_times, TimeFormat::default())
+        );
     }
 
     #[test]
@@ -0,0 +1,3 @@
+pub mod profile_storage;
+pub mod sqlite_storage;
+pub mod storage_application;
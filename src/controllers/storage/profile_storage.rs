@@ -0,0 +1,188 @@
+use crate::{
+    controllers::file::file_io::{
+        read_application_state, read_profile_folder, upsert_application_state,
+        upsert_profile_folder,
+    },
+    data_models::karting_time_file::KartingTimeFile,
+    models::{
+        application::file_error::FileError,
+        driver::{
+            profile_statistics::ProfileStatistics, session_information::race_result::RaceResult,
+        },
+        filters::filter::Filter,
+    },
+};
+
+pub trait ProfileStorage {
+    fn save_profile(&self, karting_time_file: &KartingTimeFile) -> Result<(), FileError>;
+
+    fn load_profile(&self) -> Result<KartingTimeFile, FileError>;
+
+    // Races matching the filter in the order they are listed, sorted by date then track then session
+    fn query_races(&self, filter: &Filter) -> Result<Vec<RaceResult>, FileError> {
+        let karting_time_file = self.load_profile()?;
+
+        let mut driver_profile = karting_time_file
+            .driver_profile_file
+            .convert_to_driver_profile();
+
+        driver_profile.filter = Filter::new(
+            filter.is_filter_visible,
+            &filter.track_query,
+            &filter.date_query,
+            &filter.car_used_query,
+            &filter.championship_query,
+            &filter.session_type_query,
+            vec![],
+        );
+        driver_profile.sort_races();
+        driver_profile.update_filtering();
+
+        Ok(driver_profile.filter.filtered_races)
+    }
+
+    fn query_race_count(&self, filter: &Filter) -> Result<usize, FileError> {
+        self.query_races(filter).map(|races| races.len())
+    }
+
+    // The races on the filter's current page
+    fn query_race_page(&self, filter: &Filter) -> Result<Vec<RaceResult>, FileError> {
+        let start_index = filter.pagination.current_page * filter.pagination.page_size;
+
+        Ok(self
+            .query_races(filter)?
+            .into_iter()
+            .skip(start_index)
+            .take(filter.pagination.page_size)
+            .collect())
+    }
+
+    fn query_profile_statistics(&self, filter: &Filter) -> Result<ProfileStatistics, FileError> {
+        self.query_races(filter).map(ProfileStatistics::new)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TomlFileStorage {
+    pub file_path: String,
+}
+
+impl TomlFileStorage {
+    pub fn new(file_path: &str) -> Self {
+        Self {
+            file_path: file_path.to_string(),
+        }
+    }
+}
+
+impl ProfileStorage for TomlFileStorage {
+    fn save_profile(&self, karting_time_file: &KartingTimeFile) -> Result<(), FileError> {
+        upsert_application_state(&self.file_path, karting_time_file)
+    }
+
+    fn load_profile(&self) -> Result<KartingTimeFile, FileError> {
+        read_application_state(&self.file_path)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileFolderStorage {
+    pub folder_location: String,
+}
+
+impl ProfileFolderStorage {
+    pub fn new(folder_location: &str) -> Self {
+        Self {
+            folder_location: folder_location.to_string(),
+        }
+    }
+}
+
+impl ProfileStorage for ProfileFolderStorage {
+    fn save_profile(&self, karting_time_file: &KartingTimeFile) -> Result<(), FileError> {
+        upsert_profile_folder(&self.folder_location, karting_time_file)
+    }
+
+    fn load_profile(&self) -> Result<KartingTimeFile, FileError> {
+        read_profile_folder(&self.folder_location)
+    }
+}
+
+#[cfg(test)]
+mod profile_storage_should {
+    use super::*;
+    use crate::{
+        controllers::file::test_file_guard::TestFileGuard,
+        data_models::driver_profile_file::DriverProfileFile,
+        models::{
+            date::RaceDate,
+            driver::session_information::{
+                lap::Lap, race_information::RaceInformation, race_metadata::RaceMetadata,
+                session::Session,
+            },
+        },
+    };
+
+    #[test]
+    fn test_query_toml_file_storage() {
+        // Given
+        let file_name = "./karting_time_test_storage_1.toml";
+        let _guard = TestFileGuard::new(file_name);
+        let races = vec![
+            RaceResult::new(
+                RaceInformation::new(
+                    "Trafford Park",
+                    RaceDate::new(15, 1, 2024),
+                    Session::new(2, 3),
+                ),
+                RaceMetadata::default(),
+                vec![Lap::new(1, 56.8)],
+            ),
+            RaceResult::new(
+                RaceInformation::new(
+                    "Three Sisters",
+                    RaceDate::new(12, 12, 2025),
+                    Session::new(1, 1),
+                ),
+                RaceMetadata::default(),
+                vec![Lap::new(1, 50.4)],
+            ),
+        ];
+        let toml_file_storage = TomlFileStorage::new(file_name);
+        let filter = Filter {
+            is_filter_visible: true,
+            date_query: "2025".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        toml_file_storage
+            .save_profile(&KartingTimeFile::new(DriverProfileFile::new(
+                "Jack Jackson",
+                races
+                    .iter()
+                    .map(|race| race.convert_to_race_file())
+                    .collect(),
+            )))
+            .unwrap();
+
+        let all_races = toml_file_storage.query_races(&Filter::default()).unwrap();
+        let filtered_races = toml_file_storage.query_races(&filter).unwrap();
+        let profile_statistics = toml_file_storage.query_profile_statistics(&filter).unwrap();
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![
+                races[1].race_information.clone(),
+                races[0].race_information.clone()
+            ],
+            all_races
+                .into_iter()
+                .map(|race| race.race_information)
+                .collect::<Vec<_>>()
+        );
+        pretty_assertions::assert_eq!(1, filtered_races.len());
+        pretty_assertions::assert_eq!(1, toml_file_storage.query_race_count(&filter).unwrap());
+        pretty_assertions::assert_eq!(1, profile_statistics.wins);
+    }
+}
@@ -0,0 +1,624 @@
+use super::profile_storage::ProfileStorage;
+use crate::{
+    data_models::{
+        karting_time_file::KartingTimeFile, profile_manifest_file::ProfileManifestFile,
+        race_result_file::RaceResultFile,
+    },
+    models::{
+        application::file_error::FileError,
+        driver::{
+            profile_statistics::ProfileStatistics, session_information::race_result::RaceResult,
+        },
+        filters::filter::Filter,
+    },
+};
+use rusqlite::{Connection, params, params_from_iter};
+use std::collections::{HashMap, HashSet};
+
+const CREATE_TABLES: &str = "
+    CREATE TABLE IF NOT EXISTS profile (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        manifest TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS races (
+        unique_race_identifier TEXT PRIMARY KEY,
+        track_name TEXT NOT NULL,
+        race_date TEXT NOT NULL,
+        day INTEGER NOT NULL,
+        month INTEGER NOT NULL,
+        year INTEGER NOT NULL,
+        session_id INTEGER NOT NULL,
+        race_position INTEGER NOT NULL,
+        session_type TEXT NOT NULL,
+        car_used TEXT NOT NULL,
+        championship TEXT NOT NULL,
+        race_file TEXT NOT NULL
+    );";
+
+// Matches the in-memory filters, an empty query matches every race
+const FILTER_CLAUSE: &str = "
+    WHERE instr(lower(track_name), lower(?1)) > 0
+    AND instr(lower(race_date), lower(?2)) > 0
+    AND instr(lower(car_used), lower(?3)) > 0
+    AND instr(lower(championship), lower(?4)) > 0
+    AND instr(lower(session_type), lower(?5)) > 0";
+
+const ORDER_CLAUSE: &str =
+    "ORDER BY year DESC, month DESC, day DESC, track_name ASC, session_id ASC";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqliteStorage {
+    pub database_path: String,
+    // The races as they were last written, so an edit only writes the races it changed
+    pub stored_races: HashMap<String, RaceResult>,
+}
+
+impl SqliteStorage {
+    pub fn new(database_path: &str) -> Self {
+        Self {
+            database_path: database_path.to_string(),
+            stored_races: HashMap::new(),
+        }
+    }
+
+    pub fn remember_races(&mut self, races: &[RaceResult]) {
+        self.stored_races = races
+            .iter()
+            .map(|race| {
+                (
+                    race.race_information.unique_race_identifier.clone(),
+                    race.clone(),
+                )
+            })
+            .collect();
+    }
+
+    pub fn save_changed_races(
+        &mut self,
+        manifest: &ProfileManifestFile,
+        races: &[RaceResult],
+    ) -> Result<(), FileError> {
+        self.upsert_changed_races(manifest, races)
+            .map_err(|error| FileError::database(&self.database_path, &error))
+    }
+
+    fn open_connection(&self) -> rusqlite::Result<Connection> {
+        let connection = Connection::open(&self.database_path)?;
+
+        connection.execute_batch(CREATE_TABLES)?;
+
+        Ok(connection)
+    }
+
+    fn upsert_profile(&self, karting_time_file: &KartingTimeFile) -> rusqlite::Result<()> {
+        let mut connection = self.open_connection()?;
+
+        let transaction = connection.transaction()?;
+
+        upsert_manifest(&transaction, &ProfileManifestFile::new(karting_time_file))?;
+
+        let mut race_identifiers = HashSet::new();
+
+        for race_file in &karting_time_file.driver_profile_file.races {
+            let race = race_file.convert_to_race_result();
+
+            upsert_race(&transaction, &race, race_file)?;
+
+            race_identifiers.insert(race.race_information.unique_race_identifier);
+        }
+
+        let stored_identifiers = transaction
+            .prepare("SELECT unique_race_identifier FROM races")?
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<Vec<String>>>()?;
+
+        for identifier in stored_identifiers {
+            if !race_identifiers.contains(&identifier) {
+                delete_race(&transaction, &identifier)?;
+            }
+        }
+
+        transaction.commit()
+    }
+
+    fn upsert_changed_races(
+        &mut self,
+        manifest: &ProfileManifestFile,
+        races: &[RaceResult],
+    ) -> rusqlite::Result<()> {
+        let mut connection = self.open_connection()?;
+
+        let transaction = connection.transaction()?;
+
+        upsert_manifest(&transaction, manifest)?;
+
+        let mut changed_races = vec![];
+
+        for race in races {
+            let is_stored = self
+                .stored_races
+                .get(&race.race_information.unique_race_identifier)
+                .is_some_and(|stored_race| is_same_stored_race(stored_race, race));
+
+            if !is_stored {
+                upsert_race(&transaction, race, &race.convert_to_race_file())?;
+                changed_races.push(race);
+            }
+        }
+
+        let race_identifiers: HashSet<&String> = races
+            .iter()
+            .map(|race| &race.race_information.unique_race_identifier)
+            .collect();
+        let deleted_identifiers: Vec<String> = self
+            .stored_races
+            .keys()
+            .filter(|identifier| !race_identifiers.contains(identifier))
+            .cloned()
+            .collect();
+
+        for identifier in &deleted_identifiers {
+            delete_race(&transaction, identifier)?;
+        }
+
+        transaction.commit()?;
+
+        // Only remembered once they're written so a failed write is retried with the next edit
+        for race in changed_races {
+            self.stored_races.insert(
+                race.race_information.unique_race_identifier.clone(),
+                race.clone(),
+            );
+        }
+
+        for identifier in deleted_identifiers {
+            self.stored_races.remove(&identifier);
+        }
+
+        Ok(())
+    }
+
+    // A race that can't be read fails the whole query so a profile is never shown missing races
+    fn query_race_files(
+        &self,
+        filter: &Filter,
+        limit_clause: &str,
+    ) -> Result<Vec<RaceResultFile>, FileError> {
+        let sql = format!(
+            "SELECT unique_race_identifier, race_file FROM races {} {ORDER_CLAUSE} {limit_clause}",
+            where_clause(filter)
+        );
+
+        let rows = self
+            .open_connection()
+            .and_then(|connection| {
+                connection
+                    .prepare(&sql)?
+                    .query_map(params_from_iter(filter_parameters(filter)), |row| {
+                        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+                    })?
+                    .collect::<rusqlite::Result<Vec<(String, String)>>>()
+            })
+            .map_err(|error| FileError::database(&self.database_path, &error))?;
+
+        rows.iter()
+            .map(|(identifier, race_toml)| {
+                toml::from_str(race_toml).map_err(|error| {
+                    FileError::toml(
+                        &format!("{} race {identifier}", self.database_path),
+                        race_toml,
+                        &error,
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+impl ProfileStorage for SqliteStorage {
+    fn save_profile(&self, karting_time_file: &KartingTimeFile) -> Result<(), FileError> {
+        self.upsert_profile(karting_time_file)
+            .map_err(|error| FileError::database(&self.database_path, &error))
+    }
+
+    fn load_profile(&self) -> Result<KartingTimeFile, FileError> {
+        let connection = self
+            .open_connection()
+            .map_err(|error| FileError::database(&self.database_path, &error))?;
+
+        let manifest: String =
+            match connection.query_row("SELECT manifest FROM profile WHERE id = 1", [], |row| {
+                row.get(0)
+            }) {
+                Ok(manifest) => manifest,
+                Err(rusqlite::Error::QueryReturnedNoRows) => {
+                    return Err(FileError::validation(
+                        &self.database_path,
+                        "no driver profile is stored in the database",
+                    ));
+                }
+                Err(error) => return Err(FileError::database(&self.database_path, &error)),
+            };
+
+        let manifest: ProfileManifestFile = toml::from_str(&manifest)
+            .map_err(|error| FileError::toml(&self.database_path, &manifest, &error))?;
+
+        let races = self.query_race_files(&Filter::default(), "")?;
+
+        Ok(manifest.convert_to_karting_time_file(races))
+    }
+
+    fn query_races(&self, filter: &Filter) -> Result<Vec<RaceResult>, FileError> {
+        self.query_race_files(filter, "")
+            .map(RaceResultFile::convert_to_race_results)
+    }
+
+    fn query_race_count(&self, filter: &Filter) -> Result<usize, FileError> {
+        self.open_connection()
+            .and_then(|connection| {
+                connection.query_row(
+                    &format!("SELECT COUNT(*) FROM races {}", where_clause(filter)),
+                    params_from_iter(filter_parameters(filter)),
+                    |row| row.get::<_, i64>(0),
+                )
+            })
+            .map(|race_count| race_count as usize)
+            .map_err(|error| FileError::database(&self.database_path, &error))
+    }
+
+    fn query_race_page(&self, filter: &Filter) -> Result<Vec<RaceResult>, FileError> {
+        let limit_clause = format!(
+            "LIMIT {} OFFSET {}",
+            filter.pagination.page_size,
+            filter.pagination.current_page * filter.pagination.page_size
+        );
+
+        self.query_race_files(filter, &limit_clause)
+            .map(RaceResultFile::convert_to_race_results)
+    }
+
+    fn query_profile_statistics(&self, filter: &Filter) -> Result<ProfileStatistics, FileError> {
+        let sql = format!(
+            "SELECT
+                COUNT(*),
+                COALESCE(SUM(race_position = 1), 0),
+                COALESCE(SUM(race_position <= 3), 0),
+                COALESCE(SUM(race_position <= 5), 0),
+                COALESCE(SUM(race_position <= 10), 0),
+                COUNT(DISTINCT lower(trim(track_name))),
+                COUNT(DISTINCT lower(trim(car_used)))
+            FROM races {}",
+            where_clause(filter)
+        );
+
+        self.open_connection()
+            .and_then(|connection| {
+                connection.query_row(&sql, params_from_iter(filter_parameters(filter)), |row| {
+                    Ok(ProfileStatistics {
+                        races: row.get(0)?,
+                        wins: row.get(1)?,
+                        podiums: row.get(2)?,
+                        top_5: row.get(3)?,
+                        top_10: row.get(4)?,
+                        unique_tracks: row.get(5)?,
+                        unique_cars: row.get(6)?,
+                    })
+                })
+            })
+            .map_err(|error| FileError::database(&self.database_path, &error))
+    }
+}
+
+fn upsert_manifest(
+    connection: &Connection,
+    manifest: &ProfileManifestFile,
+) -> rusqlite::Result<()> {
+    let manifest = toml::to_string_pretty(manifest).unwrap_or_default();
+
+    connection.execute(
+        "INSERT INTO profile (id, manifest) VALUES (1, ?1)
+        ON CONFLICT(id) DO UPDATE SET manifest = excluded.manifest",
+        params![manifest],
+    )?;
+
+    Ok(())
+}
+
+fn upsert_race(
+    connection: &Connection,
+    race: &RaceResult,
+    race_file: &RaceResultFile,
+) -> rusqlite::Result<()> {
+    let race_toml = toml::to_string_pretty(race_file).unwrap_or_default();
+
+    // Only races whose contents changed are rewritten
+    connection.execute(
+        "INSERT INTO races (
+            unique_race_identifier, track_name, race_date, day, month, year,
+            session_id, race_position, session_type, car_used, championship, race_file
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+        ON CONFLICT(unique_race_identifier) DO UPDATE SET
+            race_position = excluded.race_position,
+            session_type = excluded.session_type,
+            car_used = excluded.car_used,
+            championship = excluded.championship,
+            race_file = excluded.race_file
+        WHERE races.race_file != excluded.race_file",
+        params![
+            race.race_information.unique_race_identifier,
+            race.race_information.track_name,
+            race.race_information.date.to_string(),
+            race.race_information.date.day,
+            race.race_information.date.month,
+            race.race_information.date.year,
+            race.race_information.session.session_id,
+            race.race_information.session.race_position,
+            race.race_metadata.session_type,
+            race.race_metadata.car_used,
+            race.race_metadata.championship,
+            race_toml,
+        ],
+    )?;
+
+    Ok(())
+}
+
+fn delete_race(connection: &Connection, identifier: &str) -> rusqlite::Result<()> {
+    connection.execute(
+        "DELETE FROM races WHERE unique_race_identifier = ?1",
+        params![identifier],
+    )?;
+
+    Ok(())
+}
+
+// Statistics and delete confirmations aren't stored so they don't make a race rewritten
+fn is_same_stored_race(stored_race: &RaceResult, race: &RaceResult) -> bool {
+    stored_race.race_information == race.race_information
+        && stored_race.race_metadata == race.race_metadata
+        && stored_race.laptimes == race.laptimes
+        && stored_race.gps_trace == race.gps_trace
+}
+
+fn where_clause(filter: &Filter) -> &'static str {
+    match filter.is_filter_visible {
+        true => FILTER_CLAUSE,
+        false => "",
+    }
+}
+
+fn filter_parameters(filter: &Filter) -> Vec<&str> {
+    match filter.is_filter_visible {
+        true => vec![
+            filter.track_query.as_str(),
+            filter.date_query.as_str(),
+            filter.car_used_query.as_str(),
+            filter.championship_query.as_str(),
+            filter.session_type_query.as_str(),
+        ],
+        false => vec![],
+    }
+}
+
+#[cfg(test)]
+mod sqlite_storage_should {
+    use super::*;
+    use crate::{
+        controllers::file::test_file_guard::TestFileGuard,
+        data_models::driver_profile_file::DriverProfileFile,
+        models::{
+            date::RaceDate,
+            driver::session_information::{
+                lap::Lap, race_information::RaceInformation, race_metadata::RaceMetadata,
+                session::Session,
+            },
+        },
+    };
+
+    fn karting_time_file() -> KartingTimeFile {
+        let races = vec![
+            RaceResult::new(
+                RaceInformation::new(
+                    "Three Sisters",
+                    RaceDate::new(12, 12, 2025),
+                    Session::new(1, 1),
+                ),
+                RaceMetadata::new("Race", "Dry", "Sodi RT10", "Championship", "Notes"),
+                vec![Lap::new(1, 50.4), Lap::new(2, 55.5)],
+            ),
+            RaceResult::new(
+                RaceInformation::new(
+                    "Trafford Park",
+                    RaceDate::new(15, 1, 2024),
+                    Session::new(2, 4),
+                ),
+                RaceMetadata::new("Qualifying", "Wet", "Sodi SR5", "", ""),
+                vec![Lap::new(1, 56.8), Lap::new(2, 58.7)],
+            ),
+            RaceResult::new(
+                RaceInformation::new(
+                    "Three Sisters",
+                    RaceDate::new(1, 3, 2025),
+                    Session::new(1, 12),
+                ),
+                RaceMetadata::new("Race", "Dry", "Sodi RT10", "", ""),
+                vec![Lap::new(1, 51.2)],
+            ),
+        ];
+
+        KartingTimeFile::new(DriverProfileFile::new(
+            "Jack Jackson",
+            races
+                .iter()
+                .map(|race| race.convert_to_race_file())
+                .collect(),
+        ))
+    }
+
+    #[test]
+    fn test_load_non_existent_profile() {
+        // Given
+        let database_path = "./karting_time_test_database_1.db";
+        let _guard = TestFileGuard::new(database_path);
+
+        // When
+        let karting_time_file = SqliteStorage::new(database_path).load_profile();
+
+        // Then
+        assert!(karting_time_file.is_err());
+    }
+
+    #[test]
+    fn test_save_then_load_profile_acceptance() {
+        // Given
+        let database_path = "./karting_time_test_database_2.db";
+        let _guard = TestFileGuard::new(database_path);
+        let mut expected_karting_time_file = karting_time_file();
+        let sqlite_storage = SqliteStorage::new(database_path);
+
+        // When
+        sqlite_storage.save_profile(&karting_time_file()).unwrap();
+
+        expected_karting_time_file
+            .driver_profile_file
+            .races
+            .remove(1);
+        sqlite_storage
+            .save_profile(&expected_karting_time_file)
+            .unwrap();
+
+        let karting_time_file = sqlite_storage.load_profile();
+
+        // Then
+        assert!(karting_time_file.is_ok(), "Unexpectedly returned an error");
+        pretty_assertions::assert_eq!(
+            expected_karting_time_file.convert_to_karting_time(),
+            karting_time_file.unwrap().convert_to_karting_time()
+        );
+    }
+
+    #[test]
+    fn test_query_filtered_races() {
+        // Given
+        let database_path = "./karting_time_test_database_3.db";
+        let _guard = TestFileGuard::new(database_path);
+        let sqlite_storage = SqliteStorage::new(database_path);
+        let mut filter = Filter {
+            is_filter_visible: true,
+            track_query: "three".to_string(),
+            ..Default::default()
+        };
+        filter.pagination.page_size = 1;
+        filter.pagination.current_page = 1;
+
+        // When
+        sqlite_storage.save_profile(&karting_time_file()).unwrap();
+
+        let races = sqlite_storage.query_races(&filter).unwrap();
+        let race_count = sqlite_storage.query_race_count(&filter).unwrap();
+        let race_page = sqlite_storage.query_race_page(&filter).unwrap();
+
+        // Then
+        pretty_assertions::assert_eq!(2, races.len());
+        pretty_assertions::assert_eq!(2, race_count);
+        pretty_assertions::assert_eq!(RaceDate::new(12, 12, 2025), races[0].race_information.date);
+        pretty_assertions::assert_eq!(vec![races[1].clone()], race_page);
+    }
+
+    #[test]
+    fn test_query_profile_statistics_matches_in_memory_statistics() {
+        // Given
+        let database_path = "./karting_time_test_database_4.db";
+        let _guard = TestFileGuard::new(database_path);
+        let sqlite_storage = SqliteStorage::new(database_path);
+        let karting_time_file = karting_time_file();
+        let expected_profile_statistics = karting_time_file
+            .driver_profile_file
+            .convert_to_driver_profile()
+            .profile_statistics;
+
+        // When
+        sqlite_storage.save_profile(&karting_time_file).unwrap();
+
+        let profile_statistics = sqlite_storage.query_profile_statistics(&Filter::default());
+
+        // Then
+        pretty_assertions::assert_eq!(Ok(expected_profile_statistics), profile_statistics);
+    }
+
+    #[test]
+    fn test_save_changed_races() {
+        // Given
+        let database_path = "./karting_time_test_database_5.db";
+        let _guard = TestFileGuard::new(database_path);
+        let karting_time_file = karting_time_file();
+        let mut races = karting_time_file
+            .driver_profile_file
+            .convert_to_driver_profile()
+            .races;
+        let mut sqlite_storage = SqliteStorage::new(database_path);
+        sqlite_storage.save_profile(&karting_time_file).unwrap();
+        sqlite_storage.remember_races(&races);
+
+        // When
+        races[0].race_metadata.notes = "Edited".to_string();
+        races.remove(1);
+        sqlite_storage
+            .save_changed_races(&ProfileManifestFile::new(&karting_time_file), &races)
+            .unwrap();
+
+        let mut stored_notes: Vec<(String, String)> = sqlite_storage
+            .query_races(&Filter::default())
+            .unwrap()
+            .into_iter()
+            .map(|race| {
+                (
+                    race.race_information.unique_race_identifier,
+                    race.race_metadata.notes,
+                )
+            })
+            .collect();
+
+        // Then
+        let mut expected_notes: Vec<(String, String)> = races
+            .iter()
+            .map(|race| {
+                (
+                    race.race_information.unique_race_identifier.clone(),
+                    race.race_metadata.notes.clone(),
+                )
+            })
+            .collect();
+        expected_notes.sort();
+        stored_notes.sort();
+
+        pretty_assertions::assert_eq!(expected_notes, stored_notes);
+        pretty_assertions::assert_eq!(2, sqlite_storage.stored_races.len());
+    }
+
+    #[test]
+    fn test_load_profile_with_an_unreadable_race() {
+        // Given
+        let database_path = "./karting_time_test_database_6.db";
+        let _guard = TestFileGuard::new(database_path);
+        let sqlite_storage = SqliteStorage::new(database_path);
+        sqlite_storage.save_profile(&karting_time_file()).unwrap();
+
+        // When
+        sqlite_storage
+            .open_connection()
+            .unwrap()
+            .execute(
+                "UPDATE races SET race_file = 'track_name = ' WHERE session_id = 1",
+                [],
+            )
+            .unwrap();
+
+        let karting_time_file = sqlite_storage.load_profile();
+        let races = sqlite_storage.query_races(&Filter::default());
+
+        // Then
+        assert!(karting_time_file.is_err());
+        assert!(races.is_err());
+    }
+}
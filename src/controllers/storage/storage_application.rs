@@ -0,0 +1,253 @@
+use super::{profile_storage::ProfileStorage, sqlite_storage::SqliteStorage};
+use crate::models::{
+    application::{file_error::FileError, karting_time::KartingTime},
+    driver::{
+        consistency_summary::ConsistencySummary, driver_profile::DriverProfile,
+        personal_bests::PersonalBests,
+    },
+    filters::pagination::Pagination,
+};
+use std::collections::HashSet;
+
+impl KartingTime {
+    pub fn save_database(&mut self, file_path: &str) -> Result<(), FileError> {
+        let mut database = SqliteStorage::new(file_path);
+
+        database.save_profile(&self.convert_to_karting_time_file())?;
+        database.remember_races(&self.driver_profile.races);
+
+        self.application_state.database = Some(database);
+        self.check_data_issues();
+
+        Ok(())
+    }
+
+    pub fn open_database(&mut self, file_name: &str) -> Result<(), FileError> {
+        let mut database = SqliteStorage::new(file_name);
+
+        let karting_time_file = database.load_profile()?;
+
+        self.replace_karting_time(karting_time_file.convert_to_karting_time());
+        database.remember_races(&self.driver_profile.races);
+        self.application_state.database = Some(database);
+
+        Ok(())
+    }
+
+    // Races were added, edited or deleted so an open database is updated before querying it,
+    // otherwise the profile has unsaved changes
+    pub fn update_races(&mut self) {
+        let manifest = self.convert_to_profile_manifest_file();
+
+        let result = match &mut self.application_state.database {
+            Some(database) => database.save_changed_races(&manifest, &self.driver_profile.races),
+            None => {
+                self.application_state.unsaved_changes.mark_changed();
+                Ok(())
+            }
+        };
+
+        // The edit is kept in memory so it can still be saved elsewhere
+        if let Err(file_error) = result {
+            self.application_state.file_errors.push(file_error);
+            self.application_state.unsaved_changes.mark_changed();
+        }
+
+        if self.application_state.data_repair.is_visible {
+            self.application_state.data_repair.refresh(
+                &self.driver_profile.races,
+                self.application_state.time_format,
+            );
+        }
+
+        self.update_race_statistics();
+        self.update_race_filtering();
+    }
+
+    // Statistics over every race don't depend on the filter, so they're only rebuilt when races
+    // or the statistics settings change
+    fn update_race_statistics(&mut self) {
+        self.driver_profile.apply_statistics_settings();
+        self.driver_profile.personal_bests = PersonalBests::new(&self.driver_profile.races);
+        self.driver_profile.consistency_summary =
+            ConsistencySummary::new(&self.driver_profile.races);
+    }
+
+    pub fn update_race_filtering(&mut self) {
+        let Some(database) = &self.application_state.database else {
+            self.driver_profile.update_filtering();
+            self.driver_profile.filter.update_pagination();
+            return;
+        };
+
+        let filter = &mut self.driver_profile.filter;
+
+        // Only the page being shown is loaded from the database
+        filter.filtered_races.clear();
+        filter.pagination = Pagination {
+            page_size: filter.pagination.page_size,
+            ..Default::default()
+        };
+
+        let result = database
+            .query_profile_statistics(filter)
+            .and_then(|profile_statistics| {
+                self.driver_profile.profile_statistics = profile_statistics;
+                update_database_pagination(&mut self.driver_profile, database)
+            });
+
+        self.report_file_result(result);
+    }
+
+    pub fn next_page(&mut self) {
+        let Some(database) = &self.application_state.database else {
+            self.driver_profile.filter.next_page();
+            return;
+        };
+
+        let result = database
+            .query_race_count(&self.driver_profile.filter)
+            .and_then(|race_count| {
+                let pagination = &mut self.driver_profile.filter.pagination;
+                pagination.total_pages = race_count / pagination.page_size;
+
+                if pagination.current_page < pagination.total_pages {
+                    pagination.current_page += 1;
+                }
+
+                update_database_pagination(&mut self.driver_profile, database)
+            });
+
+        self.report_file_result(result);
+    }
+
+    pub fn previous_page(&mut self) {
+        let Some(database) = &self.application_state.database else {
+            self.driver_profile.filter.previous_page();
+            return;
+        };
+
+        let pagination = &mut self.driver_profile.filter.pagination;
+
+        if pagination.current_page > 0 {
+            pagination.current_page -= 1;
+        }
+
+        let result = update_database_pagination(&mut self.driver_profile, database);
+
+        self.report_file_result(result);
+    }
+}
+
+fn update_database_pagination(
+    driver_profile: &mut DriverProfile,
+    database: &SqliteStorage,
+) -> Result<(), FileError> {
+    let race_count = database.query_race_count(&driver_profile.filter)?;
+    let mut page_races = database.query_race_page(&driver_profile.filter)?;
+    driver_profile
+        .statistics_settings
+        .apply_to_races(&mut page_races);
+
+    // Races waiting on a delete confirmation aren't stored so the flag is copied over
+    let deleting_identifiers: HashSet<&String> = driver_profile
+        .races
+        .iter()
+        .filter(|race| race.is_deleting)
+        .map(|race| &race.race_information.unique_race_identifier)
+        .collect();
+
+    for race in page_races.iter_mut() {
+        race.is_deleting =
+            deleting_identifiers.contains(&race.race_information.unique_race_identifier);
+    }
+
+    let pagination = &mut driver_profile.filter.pagination;
+    pagination.total_pages = race_count / pagination.page_size;
+    pagination.start_index = pagination.current_page * pagination.page_size;
+    pagination.end_index = race_count.min(pagination.start_index + pagination.page_size);
+    pagination.paginated_races = page_races;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod storage_application_should {
+    use crate::{
+        controllers::file::test_file_guard::TestFileGuard,
+        models::{
+            application::karting_time::KartingTime,
+            date::RaceDate,
+            driver::{
+                driver_profile::DriverProfile,
+                session_information::{
+                    lap::Lap, race_information::RaceInformation, race_metadata::RaceMetadata,
+                    race_result::RaceResult, session::Session,
+                },
+            },
+        },
+    };
+
+    fn races() -> Vec<RaceResult> {
+        (1..=12)
+            .map(|session_id| {
+                RaceResult::new(
+                    RaceInformation::new(
+                        "Three Sisters",
+                        RaceDate::new(12, 12, 2025),
+                        Session::new(session_id, session_id),
+                    ),
+                    RaceMetadata::default(),
+                    vec![Lap::new(1, 50.4)],
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_save_then_open_database_acceptance() {
+        // Given
+        let file_name = "./karting_time_test_storage_application_1.db";
+        let _guard = TestFileGuard::new(file_name);
+        let mut expected = KartingTime::new(DriverProfile::new("Jack Jackson", races()));
+        let mut karting_time = KartingTime::new(DriverProfile::new("Jack Jackson", races()));
+
+        // When
+        karting_time.save_database(file_name).unwrap();
+        karting_time.open_database(file_name).unwrap();
+
+        // Then
+        expected.save_database(file_name).unwrap();
+        pretty_assertions::assert_eq!(expected, karting_time);
+    }
+
+    #[test]
+    fn test_database_filtering_and_pagination() {
+        // Given
+        let file_name = "./karting_time_test_storage_application_2.db";
+        let _guard = TestFileGuard::new(file_name);
+        let mut karting_time = KartingTime::new(DriverProfile::new("Jack Jackson", races()));
+        let mut expected = KartingTime::new(DriverProfile::new("Jack Jackson", races()));
+        expected.driver_profile.sort_races();
+        expected.driver_profile.races.remove(0);
+        expected.driver_profile.update_filtering();
+        expected.driver_profile.filter.next_page();
+
+        // When
+        karting_time.save_database(file_name).unwrap();
+        karting_time.driver_profile.races.remove(0);
+        karting_time.update_races();
+        karting_time.next_page();
+
+        // Then
+        pretty_assertions::assert_eq!(
+            expected.driver_profile.filter.pagination,
+            karting_time.driver_profile.filter.pagination
+        );
+        assert!(karting_time.driver_profile.filter.filtered_races.is_empty());
+        pretty_assertions::assert_eq!(
+            expected.driver_profile.profile_statistics,
+            karting_time.driver_profile.profile_statistics
+        );
+    }
+}
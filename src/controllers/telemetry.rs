@@ -0,0 +1,10 @@
+pub mod acc_broadcasting;
+pub mod f1_udp;
+pub mod live_telemetry;
+pub mod packet_reader;
+pub mod packet_replay;
+pub mod telemetry_recorder;
+pub mod udp_listener;
+
+#[cfg(test)]
+pub mod test_packets;
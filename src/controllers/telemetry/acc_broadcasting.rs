@@ -0,0 +1,272 @@
+use crate::{
+    controllers::telemetry::packet_reader::PacketReader,
+    models::application::telemetry::{AccBroadcastingDecoder, TelemetryEvent},
+};
+
+const BROADCASTING_PROTOCOL_VERSION: u8 = 4;
+const UPDATE_INTERVAL_MILLISECONDS: i32 = 250;
+
+const REGISTER_COMMAND_APPLICATION: u8 = 1;
+const REQUEST_TRACK_DATA: u8 = 11;
+
+const REGISTRATION_RESULT: u8 = 1;
+const REALTIME_UPDATE: u8 = 2;
+const REALTIME_CAR_UPDATE: u8 = 3;
+const TRACK_DATA: u8 = 5;
+
+const SESSION_PHASE_SESSION: u8 = 5;
+const SESSION_PHASE_SESSION_OVER: u8 = 6;
+
+pub fn registration_request(connection_password: &str) -> Vec<u8> {
+    let mut request = vec![REGISTER_COMMAND_APPLICATION, BROADCASTING_PROTOCOL_VERSION];
+    write_string(&mut request, "Karting Time");
+    write_string(&mut request, connection_password);
+    request.extend_from_slice(&UPDATE_INTERVAL_MILLISECONDS.to_le_bytes());
+    write_string(&mut request, "");
+
+    request
+}
+
+pub fn track_data_request(connection_id: i32) -> Vec<u8> {
+    let mut request = vec![REQUEST_TRACK_DATA];
+    request.extend_from_slice(&connection_id.to_le_bytes());
+
+    request
+}
+
+pub fn parse_connection_id(packet: &[u8]) -> Option<i32> {
+    let mut packet_reader = PacketReader::new(packet);
+
+    if packet_reader.read_u8()? != REGISTRATION_RESULT {
+        return None;
+    }
+
+    let connection_id = packet_reader.read_i32()?;
+    let is_success = packet_reader.read_u8()? != 0;
+
+    is_success.then_some(connection_id)
+}
+
+fn write_string(request: &mut Vec<u8>, value: &str) {
+    request.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    request.extend_from_slice(value.as_bytes());
+}
+
+fn session_type_name(session_type: u8) -> &'static str {
+    match session_type {
+        0 => "Practice",
+        4 => "Qualifying",
+        9 => "Superpole",
+        10 => "Race",
+        11 => "Hotlap",
+        12 => "Hotstint",
+        13 => "Hotlap Superpole",
+        _ => "N/A",
+    }
+}
+
+struct LapInfo {
+    laptime_milliseconds: i32,
+    splits: Vec<i32>,
+}
+
+impl AccBroadcastingDecoder {
+    pub fn decode(&mut self, packet: &[u8]) -> Vec<TelemetryEvent> {
+        let mut packet_reader = PacketReader::new(packet);
+
+        match packet_reader.read_u8() {
+            Some(TRACK_DATA) => {
+                self.decode_track_data(&mut packet_reader);
+                vec![]
+            }
+            Some(REALTIME_UPDATE) => self
+                .decode_realtime_update(&mut packet_reader)
+                .unwrap_or_default(),
+            Some(REALTIME_CAR_UPDATE) => self
+                .decode_realtime_car_update(&mut packet_reader)
+                .unwrap_or_default(),
+            _ => vec![],
+        }
+    }
+
+    fn decode_track_data(&mut self, packet_reader: &mut PacketReader) -> Option<()> {
+        packet_reader.read_i32()?;
+        self.track_name = packet_reader.read_string()?;
+
+        Some(())
+    }
+
+    fn decode_realtime_update(
+        &mut self,
+        packet_reader: &mut PacketReader,
+    ) -> Option<Vec<TelemetryEvent>> {
+        packet_reader.read_u16()?;
+        let session_index = packet_reader.read_u16()?;
+        let session_type = packet_reader.read_u8()?;
+        let phase = packet_reader.read_u8()?;
+        packet_reader.read_f32()?;
+        packet_reader.read_f32()?;
+        self.focused_car_index = packet_reader.read_i32()?;
+
+        let mut events = vec![];
+
+        if self.is_session_running
+            && (phase >= SESSION_PHASE_SESSION_OVER || self.session_index != Some(session_index))
+        {
+            self.is_session_running = false;
+            events.push(TelemetryEvent::SessionEnded);
+        }
+
+        if !self.is_session_running
+            && phase == SESSION_PHASE_SESSION
+            && self.session_index != Some(session_index)
+        {
+            self.is_session_running = true;
+            self.session_index = Some(session_index);
+            self.completed_laps = None;
+            events.push(TelemetryEvent::SessionStarted {
+                track_name: self.track_name.clone(),
+                session_type: session_type_name(session_type).to_string(),
+            });
+        }
+
+        Some(events)
+    }
+
+    fn decode_realtime_car_update(
+        &mut self,
+        packet_reader: &mut PacketReader,
+    ) -> Option<Vec<TelemetryEvent>> {
+        let car_index = packet_reader.read_u16()?;
+
+        if !self.is_session_running || car_index as i32 != self.focused_car_index {
+            return Some(vec![]);
+        }
+
+        // driver index, driver count, gear, world x, world y, yaw, car location, speed
+        packet_reader.skip(2 + 1 + 1 + 4 + 4 + 4 + 1 + 2)?;
+        let race_position = packet_reader.read_u16()?;
+        // cup position, track position, spline position
+        packet_reader.skip(2 + 2 + 4)?;
+        let completed_laps = packet_reader.read_u16()?;
+        packet_reader.read_i32()?;
+        Self::read_lap_info(packet_reader)?;
+        let last_lap = Self::read_lap_info(packet_reader)?;
+
+        let previous_completed_laps = self.completed_laps.replace(completed_laps);
+
+        match previous_completed_laps {
+            Some(previous_completed_laps)
+                if completed_laps > previous_completed_laps
+                    && last_lap.laptime_milliseconds > 0 =>
+            {
+                Some(vec![TelemetryEvent::LapCompleted {
+                    laptime: last_lap.laptime_milliseconds as f32 / 1000.0,
+                    sectors: last_lap
+                        .splits
+                        .iter()
+                        .filter(|split| **split > 0)
+                        .map(|split| *split as f32 / 1000.0)
+                        .collect(),
+                    race_position: race_position as u32,
+                }])
+            }
+            _ => Some(vec![]),
+        }
+    }
+
+    fn read_lap_info(packet_reader: &mut PacketReader) -> Option<LapInfo> {
+        let laptime_milliseconds = packet_reader.read_i32()?;
+        // car index, driver index
+        packet_reader.skip(2 + 2)?;
+        let split_count = packet_reader.read_u8()?;
+
+        let mut splits = vec![];
+        for _ in 0..split_count {
+            splits.push(packet_reader.read_i32()?);
+        }
+
+        // is invalid, is valid for best, is out lap, is in lap
+        packet_reader.skip(4)?;
+
+        Some(LapInfo {
+            laptime_milliseconds,
+            splits,
+        })
+    }
+}
+
+#[cfg(test)]
+mod acc_broadcasting_should {
+    use super::*;
+    use crate::controllers::telemetry::test_packets::{
+        acc_realtime_car_update, acc_realtime_update, acc_track_data,
+    };
+
+    #[test]
+    fn test_registration_request() {
+        // When
+        let request = registration_request("asd");
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![
+                1, 4, 12, 0, b'K', b'a', b'r', b't', b'i', b'n', b'g', b' ', b'T', b'i', b'm',
+                b'e', 3, 0, b'a', b's', b'd', 250, 0, 0, 0, 0, 0
+            ],
+            request
+        );
+    }
+
+    #[test]
+    fn test_parse_connection_id() {
+        // Then
+        pretty_assertions::assert_eq!(Some(7), parse_connection_id(&[1, 7, 0, 0, 0, 1, 0]));
+        pretty_assertions::assert_eq!(None, parse_connection_id(&[1, 7, 0, 0, 0, 0, 0]));
+        pretty_assertions::assert_eq!(None, parse_connection_id(&[2]));
+    }
+
+    #[test]
+    fn test_decode_session() {
+        // Given
+        let mut decoder = AccBroadcastingDecoder::default();
+
+        // When
+        let mut events = decoder.decode(&acc_track_data("Spa"));
+        events.append(&mut decoder.decode(&acc_realtime_update(1, 10, 5, 3)));
+        events.append(&mut decoder.decode(&acc_realtime_car_update(3, 4, 0, 0, &[])));
+        events.append(&mut decoder.decode(&acc_realtime_car_update(5, 1, 1, 128_456, &[])));
+        events.append(&mut decoder.decode(&acc_realtime_car_update(3, 3, 1, 138_456, &[])));
+        events.append(&mut decoder.decode(&acc_realtime_car_update(3, 3, 1, 138_456, &[])));
+        events.append(&mut decoder.decode(&acc_realtime_car_update(
+            3,
+            2,
+            2,
+            137_901,
+            &[45_001, 50_100, 42_800],
+        )));
+        events.append(&mut decoder.decode(&acc_realtime_update(1, 10, 6, 3)));
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![
+                TelemetryEvent::SessionStarted {
+                    track_name: "Spa".to_string(),
+                    session_type: "Race".to_string()
+                },
+                TelemetryEvent::LapCompleted {
+                    laptime: 138.456,
+                    sectors: vec![],
+                    race_position: 3
+                },
+                TelemetryEvent::LapCompleted {
+                    laptime: 137.901,
+                    sectors: vec![45.001, 50.1, 42.8],
+                    race_position: 2
+                },
+                TelemetryEvent::SessionEnded,
+            ],
+            events
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use crate::{
+    controllers::telemetry::packet_reader::PacketReader,
+    models::application::telemetry::{F1Decoder, TelemetryEvent},
+};
+
+pub const HEADER_SIZE: usize = 29;
+pub const NUMBER_OF_CARS: usize = 22;
+pub const MINIMUM_PACKET_FORMAT: u16 = 2024;
+
+const SESSION_PACKET: u8 = 1;
+const LAP_DATA_PACKET: u8 = 2;
+const EVENT_PACKET: u8 = 3;
+
+const CAR_POSITION_OFFSET: usize = 32;
+const CURRENT_LAP_NUMBER_OFFSET: usize = 33;
+
+const TRACK_NAMES: [&str; 33] = [
+    "Melbourne",
+    "Paul Ricard",
+    "Shanghai",
+    "Sakhir",
+    "Catalunya",
+    "Monaco",
+    "Montreal",
+    "Silverstone",
+    "Hockenheim",
+    "Hungaroring",
+    "Spa",
+    "Monza",
+    "Singapore",
+    "Suzuka",
+    "Abu Dhabi",
+    "Texas",
+    "Brazil",
+    "Austria",
+    "Sochi",
+    "Mexico",
+    "Baku",
+    "Sakhir Short",
+    "Silverstone Short",
+    "Texas Short",
+    "Suzuka Short",
+    "Hanoi",
+    "Zandvoort",
+    "Imola",
+    "Portimao",
+    "Jeddah",
+    "Miami",
+    "Las Vegas",
+    "Losail",
+];
+
+struct PacketHeader {
+    packet_id: u8,
+    session_uid: u64,
+    player_car_index: u8,
+}
+
+fn read_header(packet_reader: &mut PacketReader) -> Option<PacketHeader> {
+    let packet_format = packet_reader.read_u16()?;
+
+    if packet_format < MINIMUM_PACKET_FORMAT {
+        return None;
+    }
+
+    // game year, game major version, game minor version, packet version
+    packet_reader.skip(4)?;
+    let packet_id = packet_reader.read_u8()?;
+    let session_uid = packet_reader.read_u64()?;
+    // session time, frame identifier, overall frame identifier
+    packet_reader.skip(4 + 4 + 4)?;
+    let player_car_index = packet_reader.read_u8()?;
+    packet_reader.read_u8()?;
+
+    Some(PacketHeader {
+        packet_id,
+        session_uid,
+        player_car_index,
+    })
+}
+
+fn session_type_name(session_type: u8) -> &'static str {
+    match session_type {
+        1..=4 => "Practice",
+        5..=14 => "Qualifying",
+        15..=17 => "Race",
+        18 => "Time Trial",
+        _ => "N/A",
+    }
+}
+
+pub fn track_name(track_id: i8) -> String {
+    usize::try_from(track_id)
+        .ok()
+        .and_then(|track_id| TRACK_NAMES.get(track_id))
+        .map(|track_name| track_name.to_string())
+        .unwrap_or_else(|| format!("Track {track_id}"))
+}
+
+impl F1Decoder {
+    pub fn decode(&mut self, packet: &[u8]) -> Vec<TelemetryEvent> {
+        let mut packet_reader = PacketReader::new(packet);
+
+        let Some(header) = read_header(&mut packet_reader) else {
+            return vec![];
+        };
+
+        match header.packet_id {
+            SESSION_PACKET => self
+                .decode_session(&header, &mut packet_reader)
+                .unwrap_or_default(),
+            LAP_DATA_PACKET => self.decode_lap_data(&header, packet).unwrap_or_default(),
+            EVENT_PACKET => self.decode_event(&mut packet_reader).unwrap_or_default(),
+            _ => vec![],
+        }
+    }
+
+    fn decode_session(
+        &mut self,
+        header: &PacketHeader,
+        packet_reader: &mut PacketReader,
+    ) -> Option<Vec<TelemetryEvent>> {
+        if self.session_uid == Some(header.session_uid) {
+            return Some(vec![]);
+        }
+
+        // weather, track temperature, air temperature, total laps, track length
+        packet_reader.skip(1 + 1 + 1 + 1 + 2)?;
+        let session_type = packet_reader.read_u8()?;
+        let track_id = packet_reader.read_u8()? as i8;
+
+        let mut events = vec![];
+
+        if self.is_session_running {
+            events.push(TelemetryEvent::SessionEnded);
+        }
+
+        self.session_uid = Some(header.session_uid);
+        self.current_lap_number = 0;
+        self.is_session_running = true;
+
+        events.push(TelemetryEvent::SessionStarted {
+            track_name: track_name(track_id),
+            session_type: session_type_name(session_type).to_string(),
+        });
+
+        Some(events)
+    }
+
+    fn decode_lap_data(
+        &mut self,
+        header: &PacketHeader,
+        packet: &[u8],
+    ) -> Option<Vec<TelemetryEvent>> {
+        if !self.is_session_running || self.session_uid != Some(header.session_uid) {
+            return Some(vec![]);
+        }
+
+        // The lap data size changes between game years, the packet always ends with two time trial car indexes
+        let lap_data_size = packet.len().checked_sub(HEADER_SIZE + 2)? / NUMBER_OF_CARS;
+        let player_lap_data =
+            &packet.get(HEADER_SIZE + header.player_car_index as usize * lap_data_size..)?;
+
+        let mut packet_reader = PacketReader::new(player_lap_data);
+        let last_laptime_milliseconds = packet_reader.read_u32()?;
+        let race_position = *player_lap_data.get(CAR_POSITION_OFFSET)?;
+        let current_lap_number = *player_lap_data.get(CURRENT_LAP_NUMBER_OFFSET)?;
+
+        let previous_lap_number = self.current_lap_number;
+        self.current_lap_number = current_lap_number;
+
+        if previous_lap_number == 0
+            || current_lap_number <= previous_lap_number
+            || last_laptime_milliseconds == 0
+        {
+            return Some(vec![]);
+        }
+
+        Some(vec![TelemetryEvent::LapCompleted {
+            laptime: last_laptime_milliseconds as f32 / 1000.0,
+            sectors: vec![],
+            race_position: race_position as u32,
+        }])
+    }
+
+    fn decode_event(&mut self, packet_reader: &mut PacketReader) -> Option<Vec<TelemetryEvent>> {
+        let event_code = [
+            packet_reader.read_u8()?,
+            packet_reader.read_u8()?,
+            packet_reader.read_u8()?,
+            packet_reader.read_u8()?,
+        ];
+
+        if &event_code != b"SEND" || !self.is_session_running {
+            return Some(vec![]);
+        }
+
+        self.is_session_running = false;
+
+        Some(vec![TelemetryEvent::SessionEnded])
+    }
+}
+
+#[cfg(test)]
+mod f1_udp_should {
+    use super::*;
+    use crate::controllers::telemetry::test_packets::{f1_event, f1_lap_data, f1_session};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(10, "Spa")]
+    #[case(7, "Silverstone")]
+    #[case(40, "Track 40")]
+    #[case(-1, "Track -1")]
+    fn test_track_name(#[case] track_id: i8, #[case] expected_track_name: &str) {
+        // Then
+        pretty_assertions::assert_eq!(expected_track_name, track_name(track_id));
+    }
+
+    #[test]
+    fn test_decode_session() {
+        // Given
+        let mut decoder = F1Decoder::default();
+
+        // When
+        let mut events = decoder.decode(&f1_lap_data(1, 0, 1, 5));
+        events.append(&mut decoder.decode(&f1_session(1, 15, 7)));
+        events.append(&mut decoder.decode(&f1_session(1, 15, 7)));
+        events.append(&mut decoder.decode(&f1_lap_data(1, 0, 1, 5)));
+        events.append(&mut decoder.decode(&f1_lap_data(1, 0, 1, 5)));
+        events.append(&mut decoder.decode(&f1_lap_data(1, 91_234, 2, 4)));
+        events.append(&mut decoder.decode(&f1_lap_data(1, 89_876, 3, 3)));
+        events.append(&mut decoder.decode(&f1_event(1, b"SEND")));
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec![
+                TelemetryEvent::SessionStarted {
+                    track_name: "Silverstone".to_string(),
+                    session_type: "Race".to_string()
+                },
+                TelemetryEvent::LapCompleted {
+                    laptime: 91.234,
+                    sectors: vec![],
+                    race_position: 4
+                },
+                TelemetryEvent::LapCompleted {
+                    laptime: 89.876,
+                    sectors: vec![],
+                    race_position: 3
+                },
+                TelemetryEvent::SessionEnded,
+            ],
+            events
+        );
+    }
+
+    #[test]
+    fn test_ignore_older_packet_formats() {
+        // Given
+        let mut decoder = F1Decoder::default();
+        let mut packet = f1_session(1, 15, 7);
+        packet[0..2].copy_from_slice(&2023_u16.to_le_bytes());
+
+        // When
+        let events = decoder.decode(&packet);
+
+        // Then
+        assert!(events.is_empty());
+    }
+}
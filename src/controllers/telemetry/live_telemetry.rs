@@ -0,0 +1,164 @@
+use crate::models::{
+    application::{
+        file_error::FileError,
+        karting_time::KartingTime,
+        telemetry::{TelemetryGame, TelemetryRecorder},
+    },
+    driver::session_information::race_result::RaceResult,
+};
+
+impl KartingTime {
+    pub fn select_telemetry_game(&mut self, game: TelemetryGame) {
+        self.finish_live_session();
+
+        let telemetry = &mut self.application_state.telemetry;
+        telemetry.game = game;
+        telemetry.port = game.default_port();
+        telemetry.recorded_packets.clear();
+        telemetry.recorder = TelemetryRecorder::new(game);
+    }
+
+    pub fn toggle_telemetry_listening(&mut self) {
+        let telemetry = &mut self.application_state.telemetry;
+
+        match telemetry.is_listening {
+            true => {
+                telemetry.is_listening = false;
+                self.finish_live_session();
+            }
+            false => {
+                telemetry.is_listening = true;
+                telemetry.recorded_packets.clear();
+                telemetry.recorder = TelemetryRecorder::new(telemetry.game);
+            }
+        }
+    }
+
+    // The socket couldn't be opened so the listen button goes back to Listen
+    pub fn fail_telemetry_listening(&mut self, reason: &str) {
+        let telemetry = &mut self.application_state.telemetry;
+        telemetry.is_listening = false;
+
+        self.application_state.file_errors.push(FileError::Io {
+            file_name: format!("telemetry port {}", telemetry.port),
+            reason: reason.to_string(),
+        });
+    }
+
+    // The races a packet finishes are returned so they're added as one undo step
+    pub fn receive_telemetry_packet(&mut self, packet: Vec<u8>) -> Vec<RaceResult> {
+        let telemetry = &mut self.application_state.telemetry;
+
+        let races = telemetry
+            .recorder
+            .handle_packet(&packet, &self.driver_profile.races);
+
+        if telemetry.is_recording_packets {
+            telemetry.recorded_packets.push(packet);
+        }
+
+        races
+    }
+
+    pub fn finish_live_session(&mut self) {
+        let race = self
+            .application_state
+            .telemetry
+            .recorder
+            .finish_session(&self.driver_profile.races);
+
+        self.driver_profile.races.extend(race);
+    }
+}
+
+#[cfg(test)]
+mod live_telemetry_should {
+    use super::*;
+    use crate::controllers::telemetry::test_packets::{
+        acc_realtime_car_update, acc_realtime_update, acc_track_data,
+    };
+
+    #[test]
+    fn test_fail_telemetry_listening() {
+        // Given
+        let mut karting_time = KartingTime::default();
+        karting_time.toggle_telemetry_listening();
+
+        // When
+        karting_time.fail_telemetry_listening("Address already in use");
+
+        // Then
+        assert!(!karting_time.application_state.telemetry.is_listening);
+        pretty_assertions::assert_eq!(
+            vec![FileError::Io {
+                file_name: format!(
+                    "telemetry port {}",
+                    karting_time.application_state.telemetry.port
+                ),
+                reason: "Address already in use".to_string(),
+            }],
+            karting_time.application_state.file_errors
+        );
+    }
+
+    #[test]
+    fn test_receive_telemetry_packets() {
+        // Given
+        let mut karting_time = KartingTime::default();
+        karting_time
+            .application_state
+            .telemetry
+            .is_recording_packets = true;
+        karting_time.toggle_telemetry_listening();
+
+        let packets = vec![
+            acc_track_data("Spa"),
+            acc_realtime_update(1, 10, 5, 3),
+            acc_realtime_car_update(3, 4, 0, 0, &[]),
+            acc_realtime_car_update(3, 3, 1, 138_456, &[]),
+        ];
+
+        // When
+        let races_while_driving: usize = packets
+            .clone()
+            .into_iter()
+            .map(|packet| karting_time.receive_telemetry_packet(packet).len())
+            .sum();
+
+        karting_time.toggle_telemetry_listening();
+
+        // Then
+        pretty_assertions::assert_eq!(0, races_while_driving);
+        pretty_assertions::assert_eq!(
+            packets,
+            karting_time.application_state.telemetry.recorded_packets
+        );
+        pretty_assertions::assert_eq!(1, karting_time.driver_profile.races.len());
+
+        let race = &karting_time.driver_profile.races[0];
+        pretty_assertions::assert_eq!("Spa", race.race_information.track_name);
+        pretty_assertions::assert_eq!(1, race.laptimes.len());
+        pretty_assertions::assert_eq!(3, race.race_information.session.race_position);
+    }
+
+    #[test]
+    fn test_select_telemetry_game() {
+        // Given
+        let mut karting_time = KartingTime::default();
+        karting_time.application_state.telemetry.port = 1234;
+
+        // When
+        karting_time.select_telemetry_game(TelemetryGame::F1);
+
+        // Then
+        pretty_assertions::assert_eq!(
+            TelemetryGame::F1,
+            karting_time.application_state.telemetry.game
+        );
+        pretty_assertions::assert_eq!(20777, karting_time.application_state.telemetry.port);
+        pretty_assertions::assert_eq!(
+            TelemetryGame::F1,
+            karting_time.application_state.telemetry.recorder.game
+        );
+    }
+}
@@ -0,0 +1,76 @@
+pub struct PacketReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> PacketReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    pub fn skip(&mut self, length: usize) -> Option<()> {
+        self.read_bytes(length).map(|_| ())
+    }
+
+    pub fn read_u8(&mut self) -> Option<u8> {
+        self.read_array::<1>().map(u8::from_le_bytes)
+    }
+
+    pub fn read_u16(&mut self) -> Option<u16> {
+        self.read_array::<2>().map(u16::from_le_bytes)
+    }
+
+    pub fn read_i32(&mut self) -> Option<i32> {
+        self.read_array::<4>().map(i32::from_le_bytes)
+    }
+
+    pub fn read_u32(&mut self) -> Option<u32> {
+        self.read_array::<4>().map(u32::from_le_bytes)
+    }
+
+    pub fn read_u64(&mut self) -> Option<u64> {
+        self.read_array::<8>().map(u64::from_le_bytes)
+    }
+
+    pub fn read_f32(&mut self) -> Option<f32> {
+        self.read_array::<4>().map(f32::from_le_bytes)
+    }
+
+    // Strings are a u16 byte length followed by UTF-8
+    pub fn read_string(&mut self) -> Option<String> {
+        let length = self.read_u16()? as usize;
+
+        self.read_bytes(length)
+            .map(|bytes| String::from_utf8_lossy(bytes).to_string())
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.read_bytes(N)?.try_into().ok()
+    }
+
+    pub fn read_bytes(&mut self, length: usize) -> Option<&'a [u8]> {
+        let bytes = self.bytes.get(self.position..self.position + length)?;
+        self.position += length;
+
+        Some(bytes)
+    }
+}
+
+#[cfg(test)]
+mod packet_reader_should {
+    use super::*;
+
+    #[test]
+    fn test_read_values() {
+        // Given
+        let bytes = [7, 0xE8, 0x07, 3, 0, b'S', b'p', b'a', 0, 0, 0x80, 0x3F];
+        let mut packet_reader = PacketReader::new(&bytes);
+
+        // Then
+        pretty_assertions::assert_eq!(Some(7), packet_reader.read_u8());
+        pretty_assertions::assert_eq!(Some(2024), packet_reader.read_u16());
+        pretty_assertions::assert_eq!(Some("Spa".to_string()), packet_reader.read_string());
+        pretty_assertions::assert_eq!(Some(1.0), packet_reader.read_f32());
+        pretty_assertions::assert_eq!(None, packet_reader.read_u8());
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    controllers::telemetry::packet_reader::PacketReader,
+    models::application::telemetry::TelemetryGame,
+};
+
+const REPLAY_HEADER: &[u8; 4] = b"KTPR";
+
+// A replay is the header, the game identifier then every packet as a u32 byte length followed by the packet
+pub fn write_packet_replay(game: TelemetryGame, packets: &[Vec<u8>]) -> Vec<u8> {
+    let mut replay = REPLAY_HEADER.to_vec();
+    replay.push(game_identifier(game));
+
+    for packet in packets {
+        replay.extend_from_slice(&(packet.len() as u32).to_le_bytes());
+        replay.extend_from_slice(packet);
+    }
+
+    replay
+}
+
+pub fn read_packet_replay(replay: &[u8]) -> Option<(TelemetryGame, Vec<Vec<u8>>)> {
+    if !replay.starts_with(REPLAY_HEADER) {
+        return None;
+    }
+
+    let mut packet_reader = PacketReader::new(&replay[REPLAY_HEADER.len()..]);
+    let game = game_from_identifier(packet_reader.read_u8()?)?;
+
+    let mut packets = vec![];
+    while let Some(length) = packet_reader.read_u32() {
+        let Some(packet) = packet_reader.read_bytes(length as usize) else {
+            break;
+        };
+
+        packets.push(packet.to_vec());
+    }
+
+    Some((game, packets))
+}
+
+fn game_identifier(game: TelemetryGame) -> u8 {
+    match game {
+        TelemetryGame::AccBroadcasting => 0,
+        TelemetryGame::F1 => 1,
+    }
+}
+
+fn game_from_identifier(identifier: u8) -> Option<TelemetryGame> {
+    match identifier {
+        0 => Some(TelemetryGame::AccBroadcasting),
+        1 => Some(TelemetryGame::F1),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod packet_replay_should {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(TelemetryGame::AccBroadcasting, vec![vec![5, 1, 0, 0, 0], vec![2]])]
+    #[case(TelemetryGame::F1, vec![vec![0xE8, 0x07], vec![], vec![1, 2, 3]])]
+    fn test_round_trip_packet_replay(#[case] game: TelemetryGame, #[case] packets: Vec<Vec<u8>>) {
+        // When
+        let replay = write_packet_replay(game, &packets);
+
+        // Then
+        pretty_assertions::assert_eq!(Some((game, packets)), read_packet_replay(&replay));
+    }
+
+    #[test]
+    fn test_read_truncated_packet_replay() {
+        // Given
+        let mut replay = write_packet_replay(TelemetryGame::F1, &[vec![1, 2, 3], vec![4, 5, 6]]);
+        replay.truncate(replay.len() - 1);
+
+        // Then
+        pretty_assertions::assert_eq!(
+            Some((TelemetryGame::F1, vec![vec![1, 2, 3]])),
+            read_packet_replay(&replay)
+        );
+    }
+
+    #[rstest]
+    #[case(vec![])]
+    #[case(b"KTPR".to_vec())]
+    #[case(vec![b'K', b'T', b'P', b'R', 7])]
+    #[case(vec![1, 2, 3, 4, 5])]
+    fn test_read_invalid_packet_replay(#[case] replay: Vec<u8>) {
+        // Then
+        pretty_assertions::assert_eq!(None, read_packet_replay(&replay));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::models::{
+    application::telemetry::{TelemetryEvent, TelemetryGame, TelemetryRecorder},
+    date::RaceDate,
+    driver::session_information::{
+        lap::Lap, race_information::RaceInformation, race_metadata::RaceMetadata,
+        race_result::RaceResult, session::Session,
+    },
+};
+
+impl TelemetryRecorder {
+    pub fn new(game: TelemetryGame) -> Self {
+        Self {
+            game,
+            ..Default::default()
+        }
+    }
+
+    // Returns the races completed by this packet, ready to be added to the driver profile
+    pub fn handle_packet(&mut self, packet: &[u8], races: &[RaceResult]) -> Vec<RaceResult> {
+        let events = match self.game {
+            TelemetryGame::AccBroadcasting => self.acc_broadcasting_decoder.decode(packet),
+            TelemetryGame::F1 => self.f1_decoder.decode(packet),
+        };
+
+        let mut completed_races = vec![];
+
+        for event in events {
+            match event {
+                TelemetryEvent::SessionStarted {
+                    track_name,
+                    session_type,
+                } => {
+                    completed_races.extend(self.finish_session(races));
+                    self.start_session(&track_name, &session_type);
+                }
+                TelemetryEvent::LapCompleted {
+                    laptime,
+                    sectors,
+                    race_position,
+                } => self.add_lap(laptime, sectors, race_position),
+                TelemetryEvent::SessionEnded => completed_races.extend(self.finish_session(races)),
+            }
+        }
+
+        completed_races
+    }
+
+    pub fn finish_session(&mut self, races: &[RaceResult]) -> Option<RaceResult> {
+        let mut race = self.live_race.take()?;
+
+        if race.laptimes.is_empty() {
+            return None;
+        }
+
+        while races.iter().any(|existing_race| {
+            existing_race.race_information.unique_race_identifier
+                == race.race_information.unique_race_identifier
+        }) {
+            race.race_information.session.session_id += 1;
+            race.race_information.update_unique_identifier();
+        }
+
+        race.update_race_result();
+
+        Some(race)
+    }
+
+    fn start_session(&mut self, track_name: &str, session_type: &str) {
+        let track_name = match track_name.is_empty() {
+            true => "Default",
+            false => track_name,
+        };
+
+        self.live_race = Some(RaceResult::new(
+            RaceInformation::new(track_name, RaceDate::today(), Session::new(1, 1)),
+            RaceMetadata::new(
+                session_type,
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                &format!("Recorded from {} telemetry", self.game),
+            ),
+            vec![],
+        ));
+    }
+
+    fn add_lap(&mut self, laptime: f32, sectors: Vec<f32>, race_position: u32) {
+        let Some(race) = &mut self.live_race else {
+            return;
+        };
+
+        let mut lap = Lap::new(race.laptimes.len() as u32 + 1, laptime);
+        lap.sectors = sectors;
+
+        race.laptimes.push(lap);
+        race.race_information.session.race_position = race_position.max(1);
+        race.update_race_result();
+    }
+}
+
+#[cfg(test)]
+mod telemetry_recorder_should {
+    use super::*;
+    use crate::controllers::telemetry::test_packets::{f1_event, f1_lap_data, f1_session};
+
+    #[test]
+    fn test_build_race_lap_by_lap() {
+        // Given
+        let mut recorder = TelemetryRecorder::new(TelemetryGame::F1);
+        let existing_race = RaceResult::new(
+            RaceInformation::new("Silverstone", RaceDate::today(), Session::new(1, 1)),
+            RaceMetadata::default(),
+            vec![Lap::new(1, 90.0)],
+        );
+        let races = vec![existing_race];
+
+        // When
+        let mut completed_races = recorder.handle_packet(&f1_session(1, 15, 7), &races);
+        completed_races.extend(recorder.handle_packet(&f1_lap_data(1, 0, 1, 5), &races));
+        completed_races.extend(recorder.handle_packet(&f1_lap_data(1, 91_234, 2, 4), &races));
+
+        let live_laps = recorder
+            .live_race
+            .as_ref()
+            .map(|race| race.laptimes.len())
+            .unwrap_or_default();
+
+        completed_races.extend(recorder.handle_packet(&f1_lap_data(1, 89_876, 3, 3), &races));
+        completed_races.extend(recorder.handle_packet(&f1_event(1, b"SEND"), &races));
+
+        // Then
+        pretty_assertions::assert_eq!(1, live_laps);
+        pretty_assertions::assert_eq!(1, completed_races.len());
+        assert!(recorder.live_race.is_none());
+
+        let race = &completed_races[0];
+        pretty_assertions::assert_eq!("Silverstone", race.race_information.track_name);
+        pretty_assertions::assert_eq!(2, race.race_information.session.session_id);
+        pretty_assertions::assert_eq!(3, race.race_information.session.race_position);
+        pretty_assertions::assert_eq!("Race", race.race_metadata.session_type);
+        pretty_assertions::assert_eq!(
+            vec![Lap::new(1, 91.234), Lap::new(2, 89.876)],
+            race.laptimes
+        );
+    }
+
+    #[test]
+    fn test_discard_session_without_laps() {
+        // Given
+        let mut recorder = TelemetryRecorder::new(TelemetryGame::F1);
+
+        // When
+        let mut completed_races = recorder.handle_packet(&f1_session(1, 15, 7), &[]);
+        completed_races.extend(recorder.handle_packet(&f1_event(1, b"SEND"), &[]));
+
+        // Then
+        assert!(completed_races.is_empty());
+    }
+}
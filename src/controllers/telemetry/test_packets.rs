@@ -0,0 +1,111 @@
+use crate::controllers::telemetry::f1_udp::{HEADER_SIZE, NUMBER_OF_CARS};
+
+const F1_PLAYER_CAR_INDEX: u8 = 0;
+const F1_LAP_DATA_SIZE: usize = 57;
+
+fn write_string(packet: &mut Vec<u8>, value: &str) {
+    packet.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    packet.extend_from_slice(value.as_bytes());
+}
+
+fn write_acc_lap_info(packet: &mut Vec<u8>, laptime_milliseconds: i32, splits: &[i32]) {
+    packet.extend_from_slice(&laptime_milliseconds.to_le_bytes());
+    packet.extend_from_slice(&[0; 4]);
+    packet.push(splits.len() as u8);
+    for split in splits {
+        packet.extend_from_slice(&split.to_le_bytes());
+    }
+    packet.extend_from_slice(&[0; 4]);
+}
+
+pub fn acc_track_data(track_name: &str) -> Vec<u8> {
+    let mut packet = vec![5];
+    packet.extend_from_slice(&1_i32.to_le_bytes());
+    write_string(&mut packet, track_name);
+    packet.extend_from_slice(&[0; 8]);
+
+    packet
+}
+
+pub fn acc_realtime_update(
+    session_index: u16,
+    session_type: u8,
+    phase: u8,
+    focused_car_index: i32,
+) -> Vec<u8> {
+    let mut packet = vec![2];
+    packet.extend_from_slice(&0_u16.to_le_bytes());
+    packet.extend_from_slice(&session_index.to_le_bytes());
+    packet.extend_from_slice(&[session_type, phase]);
+    packet.extend_from_slice(&[0; 8]);
+    packet.extend_from_slice(&focused_car_index.to_le_bytes());
+
+    packet
+}
+
+pub fn acc_realtime_car_update(
+    car_index: u16,
+    race_position: u16,
+    completed_laps: u16,
+    last_laptime_milliseconds: i32,
+    splits: &[i32],
+) -> Vec<u8> {
+    let mut packet = vec![3];
+    packet.extend_from_slice(&car_index.to_le_bytes());
+    packet.extend_from_slice(&[0; 19]);
+    packet.extend_from_slice(&race_position.to_le_bytes());
+    packet.extend_from_slice(&[0; 8]);
+    packet.extend_from_slice(&completed_laps.to_le_bytes());
+    packet.extend_from_slice(&[0; 4]);
+    write_acc_lap_info(&mut packet, 0, &[]);
+    write_acc_lap_info(&mut packet, last_laptime_milliseconds, splits);
+    write_acc_lap_info(&mut packet, 0, &[]);
+
+    packet
+}
+
+fn f1_header(packet_id: u8, session_uid: u64) -> Vec<u8> {
+    let mut packet = vec![];
+    packet.extend_from_slice(&2024_u16.to_le_bytes());
+    packet.extend_from_slice(&[24, 1, 0, 1, packet_id]);
+    packet.extend_from_slice(&session_uid.to_le_bytes());
+    packet.extend_from_slice(&[0; 12]);
+    packet.extend_from_slice(&[F1_PLAYER_CAR_INDEX, 255]);
+
+    packet
+}
+
+pub fn f1_session(session_uid: u64, session_type: u8, track_id: i8) -> Vec<u8> {
+    let mut packet = f1_header(1, session_uid);
+    packet.extend_from_slice(&[0; 6]);
+    packet.extend_from_slice(&[session_type, track_id as u8]);
+    packet.extend_from_slice(&[0; 64]);
+
+    packet
+}
+
+pub fn f1_lap_data(
+    session_uid: u64,
+    last_laptime_milliseconds: u32,
+    current_lap_number: u8,
+    race_position: u8,
+) -> Vec<u8> {
+    let mut packet = f1_header(2, session_uid);
+    packet.resize(HEADER_SIZE + F1_LAP_DATA_SIZE * NUMBER_OF_CARS + 2, 0);
+
+    let player_offset = HEADER_SIZE + F1_PLAYER_CAR_INDEX as usize * F1_LAP_DATA_SIZE;
+    packet[player_offset..player_offset + 4]
+        .copy_from_slice(&last_laptime_milliseconds.to_le_bytes());
+    packet[player_offset + 32] = race_position;
+    packet[player_offset + 33] = current_lap_number;
+
+    packet
+}
+
+pub fn f1_event(session_uid: u64, event_code: &[u8; 4]) -> Vec<u8> {
+    let mut packet = f1_header(3, session_uid);
+    packet.extend_from_slice(event_code);
+    packet.extend_from_slice(&[0; 12]);
+
+    packet
+}
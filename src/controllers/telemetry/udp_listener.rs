@@ -0,0 +1,68 @@
+use crate::{
+    commands::messages::Message,
+    controllers::telemetry::acc_broadcasting::{
+        parse_connection_id, registration_request, track_data_request,
+    },
+    models::application::telemetry::TelemetryGame,
+};
+use iced::futures::{SinkExt, Stream, channel::mpsc::Sender};
+use tokio::net::UdpSocket;
+
+const MAXIMUM_PACKET_SIZE: usize = 4096;
+const ACC_CONNECTION_PASSWORD: &str = "asd";
+
+pub fn listen_for_telemetry(
+    &(game, port): &(TelemetryGame, u16),
+) -> impl Stream<Item = Message> + use<> {
+    iced::stream::channel(100, async move |mut output: Sender<Message>| {
+        let socket = match connect(game, port).await {
+            Ok(socket) => socket,
+            Err(error) => {
+                output
+                    .send(Message::TelemetryListenFailed(error.to_string()))
+                    .await
+                    .unwrap_or_default();
+                return;
+            }
+        };
+
+        let mut buffer = vec![0; MAXIMUM_PACKET_SIZE];
+
+        while let Ok(length) = socket.recv(&mut buffer).await {
+            let packet = buffer[..length].to_vec();
+
+            if game == TelemetryGame::AccBroadcasting
+                && let Some(connection_id) = parse_connection_id(&packet)
+            {
+                socket
+                    .send(&track_data_request(connection_id))
+                    .await
+                    .unwrap_or_default();
+            }
+
+            if output
+                .send(Message::TelemetryPacketReceived(packet))
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+    })
+}
+
+// ACC sends to whoever registers with its broadcasting port, the F1 games send to a port we listen on
+async fn connect(game: TelemetryGame, port: u16) -> std::io::Result<UdpSocket> {
+    match game {
+        TelemetryGame::AccBroadcasting => {
+            let socket = UdpSocket::bind(("0.0.0.0", 0)).await?;
+            socket.connect(("127.0.0.1", port)).await?;
+            socket
+                .send(&registration_request(ACC_CONNECTION_PASSWORD))
+                .await?;
+
+            Ok(socket)
+        }
+        TelemetryGame::F1 => UdpSocket::bind(("0.0.0.0", port)).await,
+    }
+}
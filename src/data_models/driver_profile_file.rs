@@ -1,4 +1,8 @@
-use crate::models::driver::{driver_profile::DriverProfile, profile_statistics::ProfileStatistics};
+use crate::models::driver::{
+    consistency_summary::ConsistencySummary, driver_profile::DriverProfile,
+    personal_bests::PersonalBests, profile_statistics::ProfileStatistics,
+    statistics_settings::StatisticsSettings,
+};
 
 use super::race_result_file::RaceResultFile;
 use serde::{Deserialize, Serialize};
@@ -7,20 +11,46 @@ use serde::{Deserialize, Serialize};
 pub struct DriverProfileFile {
     pub name: String,
     pub races: Vec<RaceResultFile>,
+    #[serde(default, skip_serializing_if = "StatisticsSettings::is_default")]
+    pub statistics_settings: StatisticsSettings,
     #[serde(skip)]
     pub profile_statistics: ProfileStatistics,
+    #[serde(skip)]
+    pub personal_bests: PersonalBests,
+    #[serde(skip)]
+    pub consistency_summary: ConsistencySummary,
 }
 
 impl DriverProfileFile {
     pub fn new(name: &str, races: Vec<RaceResultFile>) -> Self {
+        DriverProfileFile::new_with_statistics_settings(name, races, Default::default())
+    }
+
+    // Each race's statistics follow the windows of its session type
+    pub fn new_with_statistics_settings(
+        name: &str,
+        races: Vec<RaceResultFile>,
+        statistics_settings: StatisticsSettings,
+    ) -> Self {
+        let mut race_results = RaceResultFile::convert_to_race_results(races.clone());
+        statistics_settings.apply_to_races(&mut race_results);
+
         let mut driver_profile_file = Self {
             name: name.to_string(),
-            races: races.clone(),
+            races,
+            statistics_settings,
             profile_statistics: Default::default(),
+            personal_bests: Default::default(),
+            consistency_summary: Default::default(),
         };
 
-        driver_profile_file.profile_statistics =
-            ProfileStatistics::new(RaceResultFile::convert_to_race_results(races.clone()));
+        for (race_file, race_result) in driver_profile_file.races.iter_mut().zip(&race_results) {
+            race_file.race_statistics = race_result.race_statistics.clone();
+        }
+
+        driver_profile_file.personal_bests = PersonalBests::new(&race_results);
+        driver_profile_file.consistency_summary = ConsistencySummary::new(&race_results);
+        driver_profile_file.profile_statistics = ProfileStatistics::new(race_results);
 
         driver_profile_file
     }
@@ -32,7 +62,12 @@ impl DriverProfileFile {
             races.push(race_file.convert_to_race_result());
         }
 
-        DriverProfile::new(&self.name, races)
+        self.statistics_settings.apply_to_races(&mut races);
+
+        let mut driver_profile = DriverProfile::new(&self.name, races);
+        driver_profile.statistics_settings = self.statistics_settings.clone();
+
+        driver_profile
     }
 }
 
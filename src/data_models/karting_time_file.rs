@@ -1,21 +1,44 @@
 use super::driver_profile_file::DriverProfileFile;
-use crate::models::application::karting_time::KartingTime;
+use crate::models::application::{csv_import::CsvColumnMapping, karting_time::KartingTime};
 use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, time::SystemTime};
 
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq)]
 pub struct KartingTimeFile {
     pub driver_profile_file: DriverProfileFile,
+    // Only read from profiles saved before CSV column mappings were kept in the settings file
+    #[serde(default, skip_serializing)]
+    pub csv_column_mappings: Vec<CsvColumnMapping>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub watched_folders: Vec<String>,
+    // Files already imported from the watched folders and when they were last modified, so
+    // they aren't imported again after the profile is reopened
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub watched_files: BTreeMap<String, SystemTime>,
 }
 
 impl KartingTimeFile {
     pub fn new(driver_profile_file: DriverProfileFile) -> Self {
         Self {
             driver_profile_file,
+            csv_column_mappings: Default::default(),
+            watched_folders: Default::default(),
+            watched_files: Default::default(),
         }
     }
 
     pub fn convert_to_karting_time(&self) -> KartingTime {
-        KartingTime::new(self.driver_profile_file.convert_to_driver_profile())
+        let mut karting_time =
+            KartingTime::new(self.driver_profile_file.convert_to_driver_profile());
+
+        karting_time.application_state.csv_import.saved_mappings = self.csv_column_mappings.clone();
+        karting_time.application_state.watch_folders.folders = self.watched_folders.clone();
+        karting_time
+            .application_state
+            .watch_folders
+            .file_modified_times = self.watched_files.clone().into_iter().collect();
+
+        karting_time
     }
 }
 
@@ -1,3 +1,5 @@
 pub mod driver_profile_file;
 pub mod karting_time_file;
+pub mod profile_manifest_file;
 pub mod race_result_file;
+pub mod settings_file;
@@ -0,0 +1,97 @@
+use super::{
+    driver_profile_file::DriverProfileFile, karting_time_file::KartingTimeFile,
+    race_result_file::RaceResultFile,
+};
+use crate::models::{
+    application::csv_import::CsvColumnMapping, driver::statistics_settings::StatisticsSettings,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, time::SystemTime};
+
+// Races are stored one file each next to the manifest, so adding a race doesn't touch it
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq)]
+pub struct ProfileManifestFile {
+    pub name: String,
+    // Only read from profiles saved before CSV column mappings were kept in the settings file
+    #[serde(default, skip_serializing)]
+    pub csv_column_mappings: Vec<CsvColumnMapping>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub watched_folders: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub watched_files: BTreeMap<String, SystemTime>,
+    #[serde(default, skip_serializing_if = "StatisticsSettings::is_default")]
+    pub statistics_settings: StatisticsSettings,
+}
+
+impl ProfileManifestFile {
+    pub fn new(karting_time_file: &KartingTimeFile) -> Self {
+        Self {
+            name: karting_time_file.driver_profile_file.name.clone(),
+            csv_column_mappings: karting_time_file.csv_column_mappings.clone(),
+            watched_folders: karting_time_file.watched_folders.clone(),
+            watched_files: karting_time_file.watched_files.clone(),
+            statistics_settings: karting_time_file
+                .driver_profile_file
+                .statistics_settings
+                .clone(),
+        }
+    }
+
+    pub fn convert_to_karting_time_file(&self, races: Vec<RaceResultFile>) -> KartingTimeFile {
+        let mut karting_time_file =
+            KartingTimeFile::new(DriverProfileFile::new_with_statistics_settings(
+                &self.name,
+                races,
+                self.statistics_settings.clone(),
+            ));
+
+        karting_time_file.csv_column_mappings = self.csv_column_mappings.clone();
+        karting_time_file.watched_folders = self.watched_folders.clone();
+        karting_time_file.watched_files = self.watched_files.clone();
+
+        karting_time_file
+    }
+}
+
+#[cfg(test)]
+mod profile_manifest_file_should {
+    use super::*;
+    use crate::models::{
+        date::RaceDate,
+        driver::session_information::{race_metadata::RaceMetadata, session::Session},
+    };
+
+    #[test]
+    fn test_convert_to_karting_time_file() {
+        // Given
+        let races = vec![RaceResultFile::new(
+            "Three Ponies",
+            vec!["50.662".to_string(), "51.877".to_string()],
+            RaceMetadata::new(
+                Default::default(),
+                Default::default(),
+                "Kart",
+                "Championship",
+                "Notes",
+            ),
+            Session::new(1, 2),
+            RaceDate::new(15, 10, 2024),
+        )];
+        let mut expected_karting_time_file =
+            KartingTimeFile::new(DriverProfileFile::new("Karl Chadwick", races.clone()));
+        expected_karting_time_file.watched_folders = vec!["./results".to_string()];
+        expected_karting_time_file.watched_files.insert(
+            "./results/session_1.json".to_string(),
+            SystemTime::UNIX_EPOCH,
+        );
+
+        let profile_manifest_file = ProfileManifestFile::new(&expected_karting_time_file);
+
+        // When
+        let karting_time_file = profile_manifest_file.convert_to_karting_time_file(races);
+
+        // Then
+        pretty_assertions::assert_eq!("Karl Chadwick", profile_manifest_file.name);
+        pretty_assertions::assert_eq!(expected_karting_time_file, karting_time_file)
+    }
+}
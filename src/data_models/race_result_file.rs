@@ -3,6 +3,7 @@ use crate::{
     models::{
         date::RaceDate,
         driver::session_information::{
+            gps_session::GpsPoint, lap::Lap, lap_channels::LapChannels,
             race_information::RaceInformation, race_metadata::RaceMetadata,
             race_result::RaceResult, race_statistics::RaceStatistics, session::Session,
         },
@@ -24,15 +25,30 @@ pub struct RaceResultFile {
     pub car_used: Option<String>,
     pub championship: Option<String>,
     pub notes: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sector_times: Vec<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lap_channels: Vec<LapChannels>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_time: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gps_trace: Vec<GpsPoint>,
     #[serde(skip)]
     pub race_statistics: RaceStatistics,
 }
 
 impl RaceResultFile {
     pub fn new_from_laptime_file(laptimes: Vec<String>) -> Self {
+        let date = RaceDate::default();
+
         Self {
             track_name: "Default".to_string(),
             laptimes,
+            day: date.day,
+            month: date.month,
+            year: date.year,
+            session_id: 1,
+            race_position: 1,
             ..Default::default()
         }
     }
@@ -82,6 +98,10 @@ impl RaceResultFile {
             car_used,
             championship,
             notes,
+            sector_times: Default::default(),
+            lap_channels: Default::default(),
+            session_time: Default::default(),
+            gps_trace: Default::default(),
             race_statistics: Default::default(),
         };
 
@@ -101,45 +121,52 @@ impl RaceResultFile {
     }
 
     pub fn convert_to_race_result(&self) -> RaceResult {
-        RaceResult::new(
+        let mut race = RaceResult::new(
             self.convert_to_race_information(),
             self.convert_to_race_metadata(),
-            convert_string_laps_to_laps(self.laptimes.clone()),
-        )
+            self.convert_to_laps(),
+        );
+
+        race.gps_trace = self.gps_trace.clone();
+
+        race
     }
 
-    fn convert_to_race_information(&self) -> RaceInformation {
-        let mut session_id = self.session_id;
-        if session_id == 0 {
-            session_id = 1
-        }
+    pub fn unique_race_identifier(&self) -> String {
+        self.convert_to_race_information().unique_race_identifier
+    }
 
-        let mut race_position = self.race_position;
-        if race_position == 0 {
-            race_position = 1
-        }
+    fn convert_to_laps(&self) -> Vec<Lap> {
+        let mut laps = convert_string_laps_to_laps(self.laptimes.clone());
 
-        let mut day = self.day;
-        if day == 0 {
-            day = 1
-        }
+        for lap in &mut laps {
+            if let Some(sector_times) = self.sector_times.get(lap.lap_number as usize - 1) {
+                lap.sectors = convert_string_laps_to_laps(sector_times.clone())
+                    .into_iter()
+                    .map(|sector| sector.time)
+                    .collect();
+            }
 
-        let mut month = self.month;
-        if month == 0 {
-            month = 1
+            if let Some(lap_channels) = self.lap_channels.get(lap.lap_number as usize - 1) {
+                lap.channels = *lap_channels;
+            }
         }
 
-        let mut year = self.year;
-        if year == 0 {
-            year = 2000
-        }
+        laps
+    }
 
+    // Impossible values are kept as they are so the data repair check can report them
+    fn convert_to_race_information(&self) -> RaceInformation {
         RaceInformation::new(
             &self.track_name.clone(),
-            RaceDate { day, month, year },
+            RaceDate {
+                day: self.day,
+                month: self.month,
+                year: self.year,
+            },
             Session {
-                session_id,
-                race_position,
+                session_id: self.session_id,
+                race_position: self.race_position,
             },
         )
     }
@@ -181,6 +208,7 @@ impl RaceResultFile {
             car_used,
             notes,
             championship,
+            session_time: self.session_time.clone().unwrap_or_default(),
         }
     }
 }
@@ -188,10 +216,7 @@ impl RaceResultFile {
 #[cfg(test)]
 mod race_file_should {
     use super::*;
-    use crate::{
-        data_models::race_result_file::RaceResultFile,
-        models::{date::RaceDate, driver::session_information::lap::Lap},
-    };
+    use crate::{data_models::race_result_file::RaceResultFile, models::date::RaceDate};
 
     #[test]
     fn test_convert_to_race() {
@@ -240,4 +265,53 @@ mod race_file_should {
         // Then
         pretty_assertions::assert_eq!(expected_race, race)
     }
+
+    #[test]
+    fn test_convert_to_race_with_sector_times() {
+        // Given
+        let mut race_file =
+            RaceResultFile::new_from_laptime_file(vec!["50.662".to_string(), "51.877".to_string()]);
+        race_file.sector_times = vec![
+            vec!["25.1".to_string(), "25.562".to_string()],
+            vec!["26.1".to_string(), "25.877".to_string()],
+        ];
+
+        // When
+        let race = race_file.convert_to_race_result();
+
+        // Then
+        pretty_assertions::assert_eq!(vec![25.1, 25.562], race.laptimes[0].sectors);
+        pretty_assertions::assert_eq!(vec![26.1, 25.877], race.laptimes[1].sectors);
+        pretty_assertions::assert_eq!(
+            race_file.sector_times,
+            race.convert_to_race_file().sector_times
+        );
+    }
+
+    #[test]
+    fn test_convert_to_race_with_lap_channels_and_session_time() {
+        // Given
+        let mut race_file =
+            RaceResultFile::new_from_laptime_file(vec!["50.662".to_string(), "51.877".to_string()]);
+        race_file.lap_channels = vec![
+            LapChannels {
+                max_rpm: Some(14050.0),
+                water_temperature: Some(50.3),
+                ..Default::default()
+            },
+            LapChannels::default(),
+        ];
+        race_file.session_time = Some("10:32".to_string());
+
+        // When
+        let race = race_file.convert_to_race_result();
+
+        // Then
+        pretty_assertions::assert_eq!(race_file.lap_channels[0], race.laptimes[0].channels);
+        pretty_assertions::assert_eq!("10:32", race.race_metadata.session_time);
+
+        let converted_race_file = race.convert_to_race_file();
+        pretty_assertions::assert_eq!(race_file.lap_channels, converted_race_file.lap_channels);
+        pretty_assertions::assert_eq!(race_file.session_time, converted_race_file.session_time);
+    }
 }
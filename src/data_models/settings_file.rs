@@ -0,0 +1,49 @@
+use crate::models::{
+    application::{csv_import::CsvColumnMapping, time_format::TimeFormat},
+    filters::pagination::Pagination,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SettingsFile {
+    pub is_light_theme: bool,
+    pub page_size: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_profile: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recent_profiles: Vec<String>,
+    pub reopen_last_profile: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_width: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_height: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profiles_folder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imports_folder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exports_folder: Option<String>,
+    pub time_format: TimeFormat,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub csv_column_mappings: Vec<CsvColumnMapping>,
+}
+
+impl Default for SettingsFile {
+    fn default() -> Self {
+        Self {
+            is_light_theme: Default::default(),
+            page_size: Pagination::default().page_size,
+            last_profile: Default::default(),
+            recent_profiles: Default::default(),
+            reopen_last_profile: Default::default(),
+            window_width: Default::default(),
+            window_height: Default::default(),
+            profiles_folder: Default::default(),
+            imports_folder: Default::default(),
+            exports_folder: Default::default(),
+            time_format: Default::default(),
+            csv_column_mappings: Default::default(),
+        }
+    }
+}
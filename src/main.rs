@@ -14,6 +14,8 @@ mod views;
 pub fn main() -> iced::Result {
     iced::application(KartingTime::boot, KartingTime::update, KartingTime::view)
         .theme(KartingTime::theme)
+        .subscription(KartingTime::subscription)
+        .exit_on_close_request(false)
         .antialiasing(true)
         .settings(Settings {
             id: Some("Karting Time".to_string()),
@@ -1,9 +1,28 @@
-use super::race_editor::RaceEditor;
-use crate::commands::tab_identifiers::TabIdentifier;
+use super::{
+    csv_import::CsvImport, data_repair::DataRepair, file_error::FileError,
+    import_report::ImportReport, profile_merge::ProfileMerge, race_editor::RaceEditor,
+    settings::Settings, telemetry::Telemetry, time_format::TimeFormat, undo_history::UndoHistory,
+    unsaved_changes::UnsavedChanges, watch_folders::WatchFolders,
+};
+use crate::{
+    commands::tab_identifiers::TabIdentifier, controllers::storage::sqlite_storage::SqliteStorage,
+};
 
 #[derive(Default, PartialEq, Debug)]
 pub struct ApplicationState {
     pub tab_identifier: TabIdentifier,
     pub is_light_theme: bool,
     pub race_editor: RaceEditor,
+    pub csv_import: CsvImport,
+    pub profile_merge: ProfileMerge,
+    pub telemetry: Telemetry,
+    pub watch_folders: WatchFolders,
+    pub database: Option<SqliteStorage>,
+    pub unsaved_changes: UnsavedChanges,
+    pub undo_history: UndoHistory,
+    pub file_errors: Vec<FileError>,
+    pub import_report: ImportReport,
+    pub data_repair: DataRepair,
+    pub time_format: TimeFormat,
+    pub settings: Settings,
 }
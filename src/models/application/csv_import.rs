@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CsvDelimiter {
+    #[default]
+    Comma,
+    Semicolon,
+    Tab,
+}
+
+impl CsvDelimiter {
+    pub const ALL: [CsvDelimiter; 3] = [
+        CsvDelimiter::Comma,
+        CsvDelimiter::Semicolon,
+        CsvDelimiter::Tab,
+    ];
+
+    pub fn to_char(self) -> char {
+        match self {
+            CsvDelimiter::Comma => ',',
+            CsvDelimiter::Semicolon => ';',
+            CsvDelimiter::Tab => '\t',
+        }
+    }
+}
+
+impl Display for CsvDelimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvDelimiter::Comma => write!(f, "Comma (,)"),
+            CsvDelimiter::Semicolon => write!(f, "Semicolon (;)"),
+            CsvDelimiter::Tab => write!(f, "Tab"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecimalSeparator {
+    #[default]
+    Point,
+    Comma,
+}
+
+impl DecimalSeparator {
+    pub const ALL: [DecimalSeparator; 2] = [DecimalSeparator::Point, DecimalSeparator::Comma];
+}
+
+impl Display for DecimalSeparator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecimalSeparator::Point => write!(f, "Point (62.345)"),
+            DecimalSeparator::Comma => write!(f, "Comma (62,345)"),
+        }
+    }
+}
+
+// Column numbers are one-based to match what a spreadsheet shows, zero means unused
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CsvColumnMapping {
+    pub source_name: String,
+    pub header: String,
+    pub delimiter: CsvDelimiter,
+    pub decimal_separator: DecimalSeparator,
+    pub has_header_row: bool,
+    pub lap_number_column: u32,
+    pub laptime_column: u32,
+    pub sector_columns: Vec<u32>,
+    pub validity_column: u32,
+}
+
+impl Default for CsvColumnMapping {
+    fn default() -> Self {
+        Self {
+            source_name: Default::default(),
+            header: Default::default(),
+            delimiter: Default::default(),
+            decimal_separator: Default::default(),
+            has_header_row: true,
+            lap_number_column: 0,
+            laptime_column: 1,
+            sector_columns: Default::default(),
+            validity_column: 0,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CsvImport {
+    pub file_path: Option<String>,
+    pub preview: Vec<String>,
+    pub mapping: CsvColumnMapping,
+    pub sector_columns_query: String,
+    pub saved_mappings: Vec<CsvColumnMapping>,
+}
@@ -0,0 +1,24 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DataIssueKind {
+    ImpossibleDate,
+    MissingSession,
+    InvalidLap,
+    OutlandishLap,
+    DuplicateRace,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataIssue {
+    pub race_identifier: String,
+    pub race_occurrence: usize,
+    pub lap_number: Option<u32>,
+    pub kind: DataIssueKind,
+    pub description: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DataRepair {
+    pub is_visible: bool,
+    pub issues: Vec<DataIssue>,
+    pub kept_issues: Vec<DataIssue>,
+}
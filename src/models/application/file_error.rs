@@ -0,0 +1,16 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileError {
+    Io {
+        file_name: String,
+        reason: String,
+    },
+    Parse {
+        file_name: String,
+        line: Option<usize>,
+        reason: String,
+    },
+    Validation {
+        file_name: String,
+        reason: String,
+    },
+}
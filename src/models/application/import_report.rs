@@ -0,0 +1,20 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImportOutcome {
+    Added,
+    Duplicate,
+    Rejected,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportReportEntry {
+    pub file_name: String,
+    pub race: String,
+    pub outcome: ImportOutcome,
+    pub reason: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ImportReport {
+    pub is_visible: bool,
+    pub entries: Vec<ImportReportEntry>,
+}
@@ -1,6 +1,7 @@
 use super::application_state::ApplicationState;
 use crate::{
-    data_models::karting_time_file::KartingTimeFile, models::driver::driver_profile::DriverProfile,
+    data_models::{karting_time_file::KartingTimeFile, profile_manifest_file::ProfileManifestFile},
+    models::driver::driver_profile::DriverProfile,
 };
 
 #[derive(Debug, Default, PartialEq)]
@@ -18,7 +19,36 @@ impl KartingTime {
     }
 
     pub fn convert_to_karting_time_file(&self) -> KartingTimeFile {
-        KartingTimeFile::new(self.driver_profile.convert_to_driver_profile_file())
+        let mut karting_time_file =
+            KartingTimeFile::new(self.driver_profile.convert_to_driver_profile_file());
+
+        karting_time_file.watched_folders = self.application_state.watch_folders.folders.clone();
+        karting_time_file.watched_files = self
+            .application_state
+            .watch_folders
+            .file_modified_times
+            .clone()
+            .into_iter()
+            .collect();
+
+        karting_time_file
+    }
+
+    // The profile without its races, so it can be saved without converting every race
+    pub fn convert_to_profile_manifest_file(&self) -> ProfileManifestFile {
+        ProfileManifestFile {
+            name: self.driver_profile.name.clone(),
+            csv_column_mappings: Default::default(),
+            watched_folders: self.application_state.watch_folders.folders.clone(),
+            watched_files: self
+                .application_state
+                .watch_folders
+                .file_modified_times
+                .clone()
+                .into_iter()
+                .collect(),
+            statistics_settings: self.driver_profile.statistics_settings.clone(),
+        }
     }
 }
 
@@ -85,4 +115,33 @@ mod karting_time_should {
         // Then
         pretty_assertions::assert_eq!(expected_karting_time_file, karting_time_file)
     }
+
+    #[test]
+    fn test_convert_to_profile_manifest_file() {
+        // Given
+        let mut karting_time = KartingTime::new(DriverProfile::new(
+            "Karl Chadwick",
+            vec![RaceResult::new(
+                RaceInformation::new(
+                    "Three Ponies",
+                    RaceDate::new(15, 10, 2024),
+                    Session::new(1, 2),
+                ),
+                RaceMetadata::default(),
+                vec![Lap::new(1, 50.662)],
+            )],
+        ));
+        karting_time
+            .application_state
+            .watch_folders
+            .add_folder("./watched");
+        let expected_manifest =
+            ProfileManifestFile::new(&karting_time.convert_to_karting_time_file());
+
+        // When
+        let manifest = karting_time.convert_to_profile_manifest_file();
+
+        // Then
+        pretty_assertions::assert_eq!(expected_manifest, manifest)
+    }
 }
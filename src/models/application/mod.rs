@@ -1,3 +1,14 @@
 pub mod application_state;
+pub mod csv_import;
+pub mod data_repair;
+pub mod file_error;
+pub mod import_report;
 pub mod karting_time;
+pub mod profile_merge;
 pub mod race_editor;
+pub mod settings;
+pub mod telemetry;
+pub mod time_format;
+pub mod undo_history;
+pub mod unsaved_changes;
+pub mod watch_folders;
@@ -0,0 +1,55 @@
+use crate::models::driver::session_information::race_result::RaceResult;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RaceMergeStatus {
+    New,
+    Changed,
+    Conflict,
+}
+
+impl Display for RaceMergeStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RaceMergeStatus::New => write!(f, "New"),
+            RaceMergeStatus::Changed => write!(f, "Changed"),
+            RaceMergeStatus::Conflict => write!(f, "Conflict"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MergeChoice {
+    #[default]
+    Current,
+    Incoming,
+}
+
+impl MergeChoice {
+    pub const ALL: [MergeChoice; 2] = [MergeChoice::Current, MergeChoice::Incoming];
+}
+
+impl Display for MergeChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MergeChoice::Current => write!(f, "Keep Current"),
+            MergeChoice::Incoming => write!(f, "Use Incoming"),
+        }
+    }
+}
+
+// Changed races only differ in their details, conflicting races have different laps
+#[derive(Debug, Clone, PartialEq)]
+pub struct RaceMerge {
+    pub status: RaceMergeStatus,
+    pub current: Option<RaceResult>,
+    pub incoming: RaceResult,
+    pub choice: MergeChoice,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ProfileMerge {
+    pub file_path: Option<String>,
+    pub driver_name: String,
+    pub race_merges: Vec<RaceMerge>,
+}
@@ -1,15 +1,51 @@
-use crate::models::driver::session_information::race_result::RaceResult;
+use crate::{
+    controllers::converters::{
+        cumulative_time_converter::convert_to_laptimes, laptime_parser::parse_laptime,
+    },
+    models::driver::session_information::race_result::RaceResult,
+};
 use iced::widget::text_editor::{self, Action, Content, Edit};
+use std::fmt::Display;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LapEntryMode {
+    #[default]
+    Laptimes,
+    CumulativeTimes,
+    CrossingTimestamps,
+}
+
+impl LapEntryMode {
+    pub const ALL: [LapEntryMode; 3] = [
+        LapEntryMode::Laptimes,
+        LapEntryMode::CumulativeTimes,
+        LapEntryMode::CrossingTimestamps,
+    ];
+}
+
+impl Display for LapEntryMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LapEntryMode::Laptimes => write!(f, "Laptimes"),
+            LapEntryMode::CumulativeTimes => write!(f, "Cumulative Race Times"),
+            LapEntryMode::CrossingTimestamps => write!(f, "Crossing Timestamps (Time of Day)"),
+        }
+    }
+}
 
 #[derive(Default, Debug)]
 pub struct RaceEditor {
     pub text_editor: text_editor::Content,
+    pub lap_entry_mode: LapEntryMode,
+    pub timing_warnings: Vec<String>,
 }
 
 impl Clone for RaceEditor {
     fn clone(&self) -> Self {
         Self {
             text_editor: Default::default(),
+            lap_entry_mode: self.lap_entry_mode,
+            timing_warnings: self.timing_warnings.clone(),
         }
     }
 }
@@ -27,13 +63,80 @@ impl RaceEditor {
 
     pub fn clear_text_editor(&mut self) {
         self.text_editor = Content::new();
+        self.timing_warnings.clear();
     }
 
     pub fn paste_laptimes(&mut self, race: &RaceResult) {
+        self.lap_entry_mode = LapEntryMode::Laptimes;
         self.text_editor.perform(Action::Edit(Edit::Paste(
             race.convert_laps_to_string().into(),
         )));
     }
+
+    pub fn paste_timing_sheet(&mut self, times: &[String], lap_entry_mode: LapEntryMode) {
+        self.clear_text_editor();
+        self.lap_entry_mode = lap_entry_mode;
+        self.text_editor.perform(Action::Edit(Edit::Paste(
+            format!("{}\n", times.join("\n")).into(),
+        )));
+        self.update_timing_warnings();
+    }
+
+    pub fn select_lap_entry_mode(&mut self, lap_entry_mode: LapEntryMode) {
+        self.lap_entry_mode = lap_entry_mode;
+        self.update_timing_warnings();
+    }
+
+    pub fn get_laptimes_from_text_editor(&self) -> String {
+        let (laptimes, _) = self.parse_laptimes();
+        laptimes
+    }
+
+    pub fn update_timing_warnings(&mut self) {
+        let (_, timing_warnings) = self.parse_laptimes();
+        self.timing_warnings = timing_warnings;
+    }
+
+    // Returns the laptimes along with a warning for each line that isn't a time, lines that
+    // aren't a time are left out of the race
+    fn parse_laptimes(&self) -> (String, Vec<String>) {
+        let text = self.get_text_from_text_editor();
+
+        let mut timing_warnings: Vec<String> = (1..)
+            .zip(text.lines())
+            .filter(|(_, line)| !line.trim().is_empty())
+            .filter_map(|(line_number, line)| {
+                parse_laptime(line, None)
+                    .err()
+                    .map(|error| format!("Line {line_number}: {error}"))
+            })
+            .collect();
+
+        if self.lap_entry_mode == LapEntryMode::Laptimes {
+            return (text, timing_warnings);
+        }
+
+        let times: Vec<String> = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.to_string())
+            .collect();
+
+        let (laptimes, crossing_issues) = convert_to_laptimes(&times, self.lap_entry_mode);
+
+        timing_warnings.extend(
+            crossing_issues
+                .iter()
+                .map(|crossing_issue| crossing_issue.to_string()),
+        );
+
+        let laptimes = laptimes
+            .iter()
+            .map(|laptime| format!("{laptime}\n"))
+            .collect();
+
+        (laptimes, timing_warnings)
+    }
 }
 
 #[cfg(test)]
@@ -46,7 +149,9 @@ mod race_editor_should {
     fn test_clone() {
         // Given
         let expected_race_editor = RaceEditor {
-            text_editor: Default::default(),
+            lap_entry_mode: LapEntryMode::CumulativeTimes,
+            timing_warnings: vec!["Lap 2: crossing is missing".to_string()],
+            ..Default::default()
         };
 
         // When
@@ -60,9 +165,7 @@ mod race_editor_should {
     fn test_get_text_from_text_editor() {
         // Given
         let expected_text = "Hello There".to_string();
-        let mut race_editor = RaceEditor {
-            text_editor: Default::default(),
-        };
+        let mut race_editor = RaceEditor::default();
         race_editor
             .text_editor
             .perform(Action::Edit(Edit::Paste(expected_text.to_string().into())));
@@ -78,9 +181,7 @@ mod race_editor_should {
     pub fn test_clear_text_editor() {
         // Given
         let expected_text = "";
-        let mut race_editor = RaceEditor {
-            text_editor: Default::default(),
-        };
+        let mut race_editor = RaceEditor::default();
         race_editor
             .text_editor
             .perform(Action::Edit(Edit::Paste("Hello There".to_string().into())));
@@ -100,9 +201,7 @@ mod race_editor_should {
             Default::default(),
             vec![Lap::new(1, 40.965), Lap::new(2, 41.875)],
         );
-        let mut race_editor = RaceEditor {
-            text_editor: Default::default(),
-        };
+        let mut race_editor = RaceEditor::default();
 
         // When
         race_editor.paste_laptimes(&race);
@@ -110,4 +209,65 @@ mod race_editor_should {
         // Then
         pretty_assertions::assert_eq!("40.965\n41.875\n", race_editor.text_editor.text())
     }
+
+    #[test]
+    fn test_get_laptimes_from_text_editor_cumulative_times() {
+        // Given
+        let mut race_editor = RaceEditor::default();
+        race_editor.paste_timing_sheet(
+            &[
+                "45.0".to_string(),
+                "1:30.0".to_string(),
+                "3:00.0".to_string(),
+                "3:45.0".to_string(),
+            ],
+            LapEntryMode::CumulativeTimes,
+        );
+
+        // When
+        let laptimes = race_editor.get_laptimes_from_text_editor();
+
+        // Then
+        pretty_assertions::assert_eq!("45\n45\n90\n45\n", laptimes);
+        pretty_assertions::assert_eq!(1, race_editor.timing_warnings.len());
+    }
+
+    #[test]
+    fn test_update_timing_warnings_reports_invalid_lines() {
+        // Given
+        let mut race_editor = RaceEditor::default();
+        race_editor.text_editor.perform(Action::Edit(Edit::Paste(
+            "1'02,5\n\n3:boop\n62.3s\n".to_string().into(),
+        )));
+
+        // When
+        race_editor.update_timing_warnings();
+
+        // Then
+        pretty_assertions::assert_eq!(
+            vec!["Line 3: unexpected 'b' at character 3".to_string()],
+            race_editor.timing_warnings
+        );
+    }
+
+    #[test]
+    fn test_select_lap_entry_mode_laptimes_clears_warnings() {
+        // Given
+        let mut race_editor = RaceEditor::default();
+        race_editor.paste_timing_sheet(
+            &[
+                "45.0".to_string(),
+                "1:30.0".to_string(),
+                "3:00.0".to_string(),
+            ],
+            LapEntryMode::CumulativeTimes,
+        );
+
+        // When
+        race_editor.select_lap_entry_mode(LapEntryMode::Laptimes);
+
+        // Then
+        pretty_assertions::assert_eq!(LapEntryMode::Laptimes, race_editor.lap_entry_mode);
+        assert!(race_editor.timing_warnings.is_empty());
+    }
 }
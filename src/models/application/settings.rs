@@ -0,0 +1,21 @@
+use iced::Size;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileFolder {
+    Profiles,
+    Imports,
+    Exports,
+}
+
+// Preferences that belong to the application rather than a profile, the theme, time format and
+// page size are kept with the state that uses them
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Settings {
+    pub last_profile: Option<String>,
+    pub recent_profiles: Vec<String>,
+    pub is_reopening_last_profile: bool,
+    pub window_size: Option<Size>,
+    pub profiles_folder: Option<String>,
+    pub imports_folder: Option<String>,
+    pub exports_folder: Option<String>,
+}
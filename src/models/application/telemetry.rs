@@ -0,0 +1,90 @@
+use crate::models::driver::session_information::race_result::RaceResult;
+use std::fmt::Display;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TelemetryGame {
+    #[default]
+    AccBroadcasting,
+    F1,
+}
+
+impl TelemetryGame {
+    pub const ALL: [TelemetryGame; 2] = [TelemetryGame::AccBroadcasting, TelemetryGame::F1];
+
+    pub fn default_port(&self) -> u16 {
+        match self {
+            TelemetryGame::AccBroadcasting => 9000,
+            TelemetryGame::F1 => 20777,
+        }
+    }
+}
+
+impl Display for TelemetryGame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TelemetryGame::AccBroadcasting => write!(f, "ACC Broadcasting"),
+            TelemetryGame::F1 => write!(f, "F1 24/25"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TelemetryEvent {
+    SessionStarted {
+        track_name: String,
+        session_type: String,
+    },
+    LapCompleted {
+        laptime: f32,
+        sectors: Vec<f32>,
+        race_position: u32,
+    },
+    SessionEnded,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AccBroadcastingDecoder {
+    pub track_name: String,
+    pub focused_car_index: i32,
+    pub session_index: Option<u16>,
+    pub completed_laps: Option<u16>,
+    pub is_session_running: bool,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct F1Decoder {
+    pub session_uid: Option<u64>,
+    pub current_lap_number: u8,
+    pub is_session_running: bool,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TelemetryRecorder {
+    pub game: TelemetryGame,
+    pub live_race: Option<RaceResult>,
+    pub acc_broadcasting_decoder: AccBroadcastingDecoder,
+    pub f1_decoder: F1Decoder,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Telemetry {
+    pub game: TelemetryGame,
+    pub port: u16,
+    pub is_listening: bool,
+    pub is_recording_packets: bool,
+    pub recorded_packets: Vec<Vec<u8>>,
+    pub recorder: TelemetryRecorder,
+}
+
+impl Default for Telemetry {
+    fn default() -> Self {
+        Self {
+            game: Default::default(),
+            port: TelemetryGame::default().default_port(),
+            is_listening: false,
+            is_recording_packets: false,
+            recorded_packets: Default::default(),
+            recorder: Default::default(),
+        }
+    }
+}
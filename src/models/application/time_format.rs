@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeStyle {
+    Seconds,
+    #[default]
+    Minutes,
+}
+
+impl Display for TimeStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeStyle::Seconds => write!(f, "Seconds (83.456)"),
+            TimeStyle::Minutes => write!(f, "Minutes (1:23.456)"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecimalPlaces {
+    One,
+    Two,
+    #[default]
+    Three,
+}
+
+impl DecimalPlaces {
+    pub fn to_digits(self) -> u32 {
+        match self {
+            DecimalPlaces::One => 1,
+            DecimalPlaces::Two => 2,
+            DecimalPlaces::Three => 3,
+        }
+    }
+}
+
+impl Display for DecimalPlaces {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecimalPlaces::One => write!(f, "1 Decimal Place"),
+            DecimalPlaces::Two => write!(f, "2 Decimal Places"),
+            DecimalPlaces::Three => write!(f, "3 Decimal Places"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeFormat {
+    pub time_style: TimeStyle,
+    pub decimal_places: DecimalPlaces,
+}
@@ -0,0 +1,25 @@
+use crate::models::driver::{
+    session_information::race_result::RaceResult, statistics_settings::StatisticsSettings,
+};
+
+// Text boxes send an edit on every keystroke so consecutive edits to the same one are one step
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TypedEdit {
+    DriverName,
+    StatisticsSettings,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ProfileSnapshot {
+    pub name: String,
+    pub statistics_settings: StatisticsSettings,
+    // Typing doesn't change the races so they're left out of the snapshots taken for it
+    pub races: Option<Vec<RaceResult>>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct UndoHistory {
+    pub undo_snapshots: Vec<ProfileSnapshot>,
+    pub redo_snapshots: Vec<ProfileSnapshot>,
+    pub typed_edit: Option<TypedEdit>,
+}
@@ -0,0 +1,8 @@
+use crate::commands::messages::Message;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct UnsavedChanges {
+    pub is_dirty: bool,
+    pub pending_message: Option<Message>,
+    pub recovery_file_path: Option<String>,
+}
@@ -0,0 +1,8 @@
+use std::{collections::HashMap, time::SystemTime};
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WatchFolders {
+    pub folders: Vec<String>,
+    pub file_modified_times: HashMap<String, SystemTime>,
+    pub announcements: Vec<String>,
+}
@@ -1,6 +1,6 @@
 use crate::views::application::input_parser::parse_input_u32;
-use chrono::{Datelike, Local, NaiveDate};
-use std::{cmp::Ordering, fmt::Display};
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+use std::{cmp::Ordering, fmt::Display, time::SystemTime};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RaceDate {
@@ -20,6 +20,12 @@ impl RaceDate {
 
         RaceDate::new(today.day(), today.month(), today.year())
     }
+
+    pub fn from_system_time(time: SystemTime) -> RaceDate {
+        let date = DateTime::<Local>::from(time).date_naive();
+
+        RaceDate::new(date.day(), date.month(), date.year())
+    }
 }
 
 impl Default for RaceDate {
@@ -0,0 +1,30 @@
+use crate::{
+    controllers::driver_profile::consistency_summary::summarise_consistency,
+    models::driver::session_information::race_result::RaceResult,
+};
+
+// Spreads and percentages are averaged over the races at the track, the run is the longest one
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrackConsistency {
+    pub track_name: String,
+    pub races: u32,
+    pub standard_deviation: f32,
+    pub coefficient_of_variation: f32,
+    pub interquartile_range: f32,
+    pub laps_within_1_percent: f32,
+    pub laps_within_2_percent: f32,
+    pub longest_consistent_run: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConsistencySummary {
+    pub tracks: Vec<TrackConsistency>,
+}
+
+impl ConsistencySummary {
+    pub fn new(races: &[RaceResult]) -> Self {
+        Self {
+            tracks: summarise_consistency(races),
+        }
+    }
+}
@@ -2,7 +2,9 @@ use crate::{
     data_models::driver_profile_file::DriverProfileFile,
     models::{
         driver::{
+            consistency_summary::ConsistencySummary, personal_bests::PersonalBests,
             profile_statistics::ProfileStatistics, session_information::race_result::RaceResult,
+            statistics_settings::StatisticsSettings,
         },
         filters::filter::Filter,
     },
@@ -15,6 +17,9 @@ pub struct DriverProfile {
     pub races: Vec<RaceResult>,
     pub filter: Filter,
     pub profile_statistics: ProfileStatistics,
+    pub personal_bests: PersonalBests,
+    pub consistency_summary: ConsistencySummary,
+    pub statistics_settings: StatisticsSettings,
 }
 
 impl DriverProfile {
@@ -25,10 +30,15 @@ impl DriverProfile {
             races: races.clone(),
             filter: Default::default(),
             profile_statistics: Default::default(),
+            personal_bests: Default::default(),
+            consistency_summary: Default::default(),
+            statistics_settings: Default::default(),
         };
 
         driver_profile.filter = Filter::new_initial_state(races.clone());
         driver_profile.profile_statistics = ProfileStatistics::new(races.clone());
+        driver_profile.personal_bests = PersonalBests::new(&races);
+        driver_profile.consistency_summary = ConsistencySummary::new(&races);
 
         driver_profile
     }
@@ -61,7 +71,11 @@ impl DriverProfile {
             race_files.push(race.convert_to_race_file())
         }
 
-        DriverProfileFile::new(&self.name, race_files)
+        DriverProfileFile::new_with_statistics_settings(
+            &self.name,
+            race_files,
+            self.statistics_settings.clone(),
+        )
     }
 }
 
@@ -72,6 +86,9 @@ impl Default for DriverProfile {
             new_race: Default::default(),
             races: Default::default(),
             profile_statistics: Default::default(),
+            personal_bests: Default::default(),
+            consistency_summary: Default::default(),
+            statistics_settings: Default::default(),
             filter: Default::default(),
         }
     }
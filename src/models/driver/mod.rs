@@ -1,3 +1,6 @@
+pub mod consistency_summary;
 pub mod driver_profile;
+pub mod personal_bests;
 pub mod profile_statistics;
 pub mod session_information;
+pub mod statistics_settings;
@@ -0,0 +1,72 @@
+use crate::{
+    controllers::driver_profile::personal_bests::find_personal_bests,
+    models::{date::RaceDate, driver::session_information::race_result::RaceResult},
+};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PersonalBestCategory {
+    FastestLap,
+    AverageLap(u32),
+    TotalLap(usize),
+}
+
+impl Display for PersonalBestCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PersonalBestCategory::FastestLap => write!(f, "Fastest Lap"),
+            PersonalBestCategory::AverageLap(cutoff_percentage) => {
+                write!(f, "Average Lap ({cutoff_percentage}%)")
+            }
+            PersonalBestCategory::TotalLap(number_of_laps) => {
+                write!(f, "Total Lap {number_of_laps}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersonalBest {
+    pub category: PersonalBestCategory,
+    pub time: f32,
+    pub improvement: Option<f32>,
+    pub unique_race_identifier: String,
+    pub date: RaceDate,
+}
+
+// Records are kept per track and car, the track name includes its layout
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrackPersonalBests {
+    pub track_name: String,
+    pub car_used: String,
+    pub personal_bests: Vec<PersonalBest>,
+    pub progression: Vec<PersonalBest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PersonalBests {
+    pub tracks: Vec<TrackPersonalBests>,
+}
+
+impl PersonalBests {
+    pub fn new(races: &[RaceResult]) -> Self {
+        Self {
+            tracks: find_personal_bests(races),
+        }
+    }
+
+    pub fn badge(&self, unique_race_identifier: &str) -> Option<String> {
+        let categories: Vec<String> = self
+            .tracks
+            .iter()
+            .flat_map(|track| &track.progression)
+            .filter(|personal_best| personal_best.unique_race_identifier == unique_race_identifier)
+            .map(|personal_best| personal_best.category.to_string())
+            .collect();
+
+        match categories.is_empty() {
+            true => None,
+            false => Some(format!("Personal Best: {}", categories.join(", "))),
+        }
+    }
+}
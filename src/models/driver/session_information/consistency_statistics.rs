@@ -0,0 +1,12 @@
+// Spreads are in seconds, the coefficient of variation and the laps within a margin of the
+// fastest lap are percentages
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ConsistencyStatistics {
+    pub standard_deviation: f32,
+    pub coefficient_of_variation: f32,
+    pub median_lap: f32,
+    pub interquartile_range: f32,
+    pub laps_within_1_percent: f32,
+    pub laps_within_2_percent: f32,
+    pub longest_consistent_run: u32,
+}
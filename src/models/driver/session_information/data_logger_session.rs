@@ -0,0 +1,361 @@
+use crate::{
+    controllers::converters::{
+        csv_parser::{detect_delimiter, find_column, parse_csv_line},
+        time_parser::convert_session_time_to_session_id,
+    },
+    models::{
+        date::RaceDate,
+        driver::session_information::{
+            lap_channels::LapChannels, timing_system_session::parse_date,
+        },
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DataLogger {
+    AimMyChron,
+    Alfano,
+}
+
+impl DataLogger {
+    pub fn detect(line: &str) -> Option<DataLogger> {
+        let line = line.to_lowercase();
+
+        if line.contains("alfano") {
+            Some(DataLogger::Alfano)
+        } else if line.contains("aim") || line.contains("mychron") || line.contains("race studio") {
+            Some(DataLogger::AimMyChron)
+        } else {
+            None
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DataLogger::AimMyChron => "AiM MyChron",
+            DataLogger::Alfano => "Alfano",
+        }
+    }
+
+    fn laptime_columns(&self) -> &'static [&'static str] {
+        match self {
+            DataLogger::AimMyChron => &["Lap Time", "Time"],
+            DataLogger::Alfano => &["Time", "Lap Time"],
+        }
+    }
+
+    fn max_rpm_columns(&self) -> &'static [&'static str] {
+        match self {
+            DataLogger::AimMyChron => &["RPM", "Max RPM", "Engine RPM"],
+            DataLogger::Alfano => &["RPM Max", "Max RPM", "RPM"],
+        }
+    }
+
+    fn max_speed_columns(&self) -> &'static [&'static str] {
+        match self {
+            DataLogger::AimMyChron => &["GPS_Speed", "Speed", "Max Speed"],
+            DataLogger::Alfano => &["Speed Max", "Max Speed", "Speed"],
+        }
+    }
+
+    fn water_temperature_columns(&self) -> &'static [&'static str] {
+        match self {
+            DataLogger::AimMyChron => &["Water_Temp", "Water Temp"],
+            DataLogger::Alfano => &["T1 Max", "T1", "Water Temp"],
+        }
+    }
+
+    fn exhaust_gas_temperature_columns(&self) -> &'static [&'static str] {
+        match self {
+            DataLogger::AimMyChron => &["Exhaust_Temp", "EGT", "Exhaust Temp"],
+            DataLogger::Alfano => &["T2 Max", "T2", "EGT"],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DataLoggerLap {
+    pub lap_number: u32,
+    pub laptime: String,
+    pub channels: LapChannels,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataLoggerSession {
+    pub data_logger: DataLogger,
+    pub track_name: String,
+    pub kart_name: String,
+    pub date: Option<RaceDate>,
+    pub session_time: String,
+    pub laps: Vec<DataLoggerLap>,
+}
+
+impl DataLoggerSession {
+    pub fn parse(contents: &str) -> Option<DataLoggerSession> {
+        let lines: Vec<&str> = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+
+        let data_logger = lines.iter().find_map(|line| DataLogger::detect(line))?;
+
+        let mut track_name = String::new();
+        let mut kart_name = String::new();
+        let mut date = None;
+        let mut session_time = String::new();
+
+        for (index, line) in lines.iter().enumerate() {
+            let delimiter = detect_delimiter(line);
+            let cells = parse_csv_line(line, delimiter);
+
+            if find_column(&cells, "Lap").is_some()
+                && Self::find_any_column(&cells, data_logger.laptime_columns()).is_some()
+            {
+                let laps = Self::parse_laps(data_logger, &cells, &lines[index + 1..], delimiter);
+
+                return Some(DataLoggerSession {
+                    data_logger,
+                    track_name,
+                    kart_name,
+                    date,
+                    session_time,
+                    laps,
+                });
+            }
+
+            let (key, value) = Self::split_key_value(&cells);
+
+            match key.as_str() {
+                "venue" | "track" => track_name = value,
+                "vehicle" | "kart" => kart_name = value,
+                "date" => {
+                    // Alfano writes the date and time of day in the same field
+                    let mut parts = value.split_whitespace();
+                    date = parts.next().and_then(parse_date);
+                    if let Some(time) = parts.next() {
+                        session_time = time.to_string();
+                    }
+                }
+                "time" => session_time = value,
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    pub fn session_id(&self) -> u32 {
+        convert_session_time_to_session_id(&self.session_time)
+    }
+
+    pub fn convert_to_laptimes(&self) -> Vec<String> {
+        self.laps.iter().map(|lap| lap.laptime.clone()).collect()
+    }
+
+    pub fn convert_to_lap_channels(&self) -> Vec<LapChannels> {
+        self.laps.iter().map(|lap| lap.channels).collect()
+    }
+
+    fn split_key_value(cells: &[String]) -> (String, String) {
+        let key = cells.first().cloned().unwrap_or_default();
+
+        match cells.get(1) {
+            Some(value) => (key.trim_end_matches(':').to_lowercase(), value.clone()),
+            None => match key.split_once(':') {
+                Some((key, value)) => (key.trim().to_lowercase(), value.trim().to_string()),
+                None => (key.to_lowercase(), String::new()),
+            },
+        }
+    }
+
+    fn find_any_column(headers: &[String], column_names: &[&str]) -> Option<usize> {
+        column_names
+            .iter()
+            .find_map(|column_name| find_column(headers, column_name))
+    }
+
+    fn parse_laps(
+        data_logger: DataLogger,
+        headers: &[String],
+        rows: &[&str],
+        delimiter: char,
+    ) -> Vec<DataLoggerLap> {
+        let (Some(lap_column), Some(laptime_column)) = (
+            find_column(headers, "Lap"),
+            Self::find_any_column(headers, data_logger.laptime_columns()),
+        ) else {
+            return vec![];
+        };
+
+        let max_rpm_column = Self::find_any_column(headers, data_logger.max_rpm_columns());
+        let max_speed_column = Self::find_any_column(headers, data_logger.max_speed_columns());
+        let water_temperature_column =
+            Self::find_any_column(headers, data_logger.water_temperature_columns());
+        let exhaust_gas_temperature_column =
+            Self::find_any_column(headers, data_logger.exhaust_gas_temperature_columns());
+
+        let mut laps = vec![];
+
+        for row in rows {
+            let cells = parse_csv_line(row, delimiter);
+            let cell = |column: usize| cells.get(column).cloned().unwrap_or_default();
+            let channel = |column: Option<usize>| {
+                column.and_then(|column| Self::parse_channel(&cell(column), delimiter))
+            };
+
+            let Ok(lap_number) = cell(lap_column).parse::<u32>() else {
+                continue;
+            };
+
+            // Loggers use a comma for the decimal point when the columns are semicolon separated
+            let laptime = match delimiter {
+                ';' => cell(laptime_column).replace(',', "."),
+                _ => cell(laptime_column),
+            };
+
+            if !laptime.chars().any(|char| char.is_ascii_digit()) {
+                continue;
+            }
+
+            laps.push(DataLoggerLap {
+                lap_number,
+                laptime,
+                channels: LapChannels {
+                    max_rpm: channel(max_rpm_column),
+                    max_speed: channel(max_speed_column),
+                    water_temperature: channel(water_temperature_column),
+                    exhaust_gas_temperature: channel(exhaust_gas_temperature_column),
+                },
+            });
+        }
+
+        laps.sort_by_key(|lap| lap.lap_number);
+
+        laps
+    }
+
+    fn parse_channel(value: &str, delimiter: char) -> Option<f32> {
+        let value = match delimiter {
+            ';' => value.replace(',', "."),
+            _ => value.to_string(),
+        };
+
+        value.trim().parse::<f32>().ok()
+    }
+}
+
+#[cfg(test)]
+mod data_logger_session_should {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("\"Format\",\"AiM CSV File\"", Some(DataLogger::AimMyChron))]
+    #[case("Race Studio 3 Lap Report", Some(DataLogger::AimMyChron))]
+    #[case("ALFANO 6 Session Export", Some(DataLogger::Alfano))]
+    #[case("Pos,Kart,Driver", None)]
+    fn test_detect_data_logger(
+        #[case] line: &str,
+        #[case] expected_data_logger: Option<DataLogger>,
+    ) {
+        // When
+        let data_logger = DataLogger::detect(line);
+
+        // Then
+        pretty_assertions::assert_eq!(expected_data_logger, data_logger);
+    }
+
+    #[test]
+    fn test_parse_aim_session() {
+        // Given
+        let contents = "\"Format\",\"AiM CSV File\"\n\"Venue\",\"Whilton Mill\"\n\"Vehicle\",\"Rotax Max\"\n\"Date\",\"15/03/2025\"\n\"Time\",\"10:32\"\n\n\"Lap\",\"Lap Time\",\"RPM\",\"GPS_Speed\",\"Water_Temp\",\"Exhaust_Temp\"\n\"2\",\"48.102\",\"14210\",\"101.4\",\"52.1\",\"612\"\n\"1\",\"49.870\",\"14050\",\"99.8\",\"50.3\",\"\"\n";
+        let expected_session = DataLoggerSession {
+            data_logger: DataLogger::AimMyChron,
+            track_name: "Whilton Mill".to_string(),
+            kart_name: "Rotax Max".to_string(),
+            date: Some(RaceDate::new(15, 3, 2025)),
+            session_time: "10:32".to_string(),
+            laps: vec![
+                DataLoggerLap {
+                    lap_number: 1,
+                    laptime: "49.870".to_string(),
+                    channels: LapChannels {
+                        max_rpm: Some(14050.0),
+                        max_speed: Some(99.8),
+                        water_temperature: Some(50.3),
+                        exhaust_gas_temperature: None,
+                    },
+                },
+                DataLoggerLap {
+                    lap_number: 2,
+                    laptime: "48.102".to_string(),
+                    channels: LapChannels {
+                        max_rpm: Some(14210.0),
+                        max_speed: Some(101.4),
+                        water_temperature: Some(52.1),
+                        exhaust_gas_temperature: Some(612.0),
+                    },
+                },
+            ],
+        };
+
+        // When
+        let session = DataLoggerSession::parse(contents);
+
+        // Then
+        pretty_assertions::assert_eq!(Some(expected_session), session);
+    }
+
+    #[test]
+    fn test_parse_alfano_session() {
+        // Given
+        let contents = "Alfano 6\nTrack: PFI\nDate: 02.08.2025 14:05\nLap;Time;RPM Max;Speed Max;T1 Max;T2 Max\n1;52,331;13890;95,2;48,5;598\n";
+
+        // When
+        let session = DataLoggerSession::parse(contents).unwrap();
+
+        // Then
+        pretty_assertions::assert_eq!(DataLogger::Alfano, session.data_logger);
+        pretty_assertions::assert_eq!("PFI", session.track_name);
+        pretty_assertions::assert_eq!(Some(RaceDate::new(2, 8, 2025)), session.date);
+        pretty_assertions::assert_eq!("14:05", session.session_time);
+        pretty_assertions::assert_eq!(vec!["52.331".to_string()], session.convert_to_laptimes());
+        pretty_assertions::assert_eq!(
+            vec![LapChannels {
+                max_rpm: Some(13890.0),
+                max_speed: Some(95.2),
+                water_temperature: Some(48.5),
+                exhaust_gas_temperature: Some(598.0),
+            }],
+            session.convert_to_lap_channels()
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_logger() {
+        // When
+        let session = DataLoggerSession::parse("Lap,Lap Time\n1,48.667\n");
+
+        // Then
+        assert!(session.is_none());
+    }
+
+    #[rstest]
+    #[case("10:32", 1032)]
+    #[case("14:05:59", 1405)]
+    #[case("", 1)]
+    fn test_session_id(#[case] session_time: &str, #[case] expected_session_id: u32) {
+        // Given
+        let session = DataLoggerSession {
+            data_logger: DataLogger::AimMyChron,
+            track_name: Default::default(),
+            kart_name: Default::default(),
+            date: None,
+            session_time: session_time.to_string(),
+            laps: vec![],
+        };
+
+        // Then
+        pretty_assertions::assert_eq!(expected_session_id, session.session_id());
+    }
+}
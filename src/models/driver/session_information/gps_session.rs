@@ -0,0 +1,51 @@
+use crate::models::date::RaceDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GpsSource {
+    RaceChrono,
+    HarrysLapTimer,
+    Vbo,
+    Gpx,
+}
+
+impl GpsSource {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GpsSource::RaceChrono => "RaceChrono",
+            GpsSource::HarrysLapTimer => "Harry's LapTimer",
+            GpsSource::Vbo => "VBOX",
+            GpsSource::Gpx => "GPX",
+        }
+    }
+}
+
+// Elapsed is seconds since the start of the session and speed is in km/h
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct GpsPoint {
+    pub elapsed: f64,
+    pub latitude: f64,
+    pub longitude: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f32>,
+    #[serde(default)]
+    pub lap_number: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GpsSession {
+    pub gps_source: GpsSource,
+    pub track_name: String,
+    pub date: Option<RaceDate>,
+    pub session_time: String,
+    pub points: Vec<GpsPoint>,
+}
+
+// Distances are in metres and speeds in km/h
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct GpsStatistics {
+    pub distance: f64,
+    pub lap_distance: f64,
+    pub max_speed: f32,
+    pub average_speed: f32,
+}
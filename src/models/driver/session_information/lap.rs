@@ -1,12 +1,21 @@
+use crate::models::driver::session_information::lap_channels::LapChannels;
+
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Lap {
     pub lap_number: u32,
     pub time: f32,
+    pub sectors: Vec<f32>,
+    pub channels: LapChannels,
 }
 
 impl Lap {
     #[allow(dead_code)]
     pub fn new(lap_number: u32, time: f32) -> Self {
-        Self { lap_number, time }
+        Self {
+            lap_number,
+            time,
+            sectors: Default::default(),
+            channels: Default::default(),
+        }
     }
 }
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct LapChannels {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_rpm: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_speed: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub water_temperature: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exhaust_gas_temperature: Option<f32>,
+}
+
+impl LapChannels {
+    pub fn is_empty(&self) -> bool {
+        *self == LapChannels::default()
+    }
+}
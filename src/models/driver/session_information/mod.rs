@@ -1,8 +1,14 @@
 pub mod acc_lap;
 pub mod acc_session_data;
+pub mod consistency_statistics;
+pub mod data_logger_session;
+pub mod gps_session;
 pub mod lap;
+pub mod lap_channels;
+pub mod pace_trend;
 pub mod race_information;
 pub mod race_metadata;
 pub mod race_result;
 pub mod race_statistics;
 pub mod session;
+pub mod timing_system_session;
@@ -0,0 +1,17 @@
+// Times are in seconds, a positive degradation means the laps got slower as the race went on
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PaceTrend {
+    pub degradation_per_lap: f32,
+    pub intercept: f32,
+    pub first_half_average: f32,
+    pub second_half_average: f32,
+    pub stints: Vec<StintSplit>,
+    pub outlier_laps: Vec<u32>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StintSplit {
+    pub first_lap: u32,
+    pub last_lap: u32,
+    pub average_lap: f32,
+}
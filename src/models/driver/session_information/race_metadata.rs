@@ -5,6 +5,7 @@ pub struct RaceMetadata {
     pub car_used: String,
     pub championship: String,
     pub notes: String,
+    pub session_time: String,
 }
 
 impl RaceMetadata {
@@ -29,6 +30,7 @@ impl RaceMetadata {
             car_used: car_used.to_string(),
             championship: championship.to_string(),
             notes: notes.to_string(),
+            session_time: Default::default(),
         }
     }
 }
@@ -41,6 +43,7 @@ impl Default for RaceMetadata {
             car_used: "N/A".to_string(),
             notes: Default::default(),
             championship: Default::default(),
+            session_time: Default::default(),
         }
     }
 }
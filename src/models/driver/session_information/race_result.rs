@@ -1,8 +1,12 @@
 use super::{lap::Lap, race_information::RaceInformation};
 use crate::{
     data_models::race_result_file::RaceResultFile,
-    models::driver::session_information::{
-        race_metadata::RaceMetadata, race_statistics::RaceStatistics, session::Session,
+    models::driver::{
+        session_information::{
+            gps_session::GpsPoint, lap_channels::LapChannels, race_metadata::RaceMetadata,
+            race_statistics::RaceStatistics, session::Session,
+        },
+        statistics_settings::StatisticsWindows,
     },
 };
 
@@ -12,7 +16,9 @@ pub struct RaceResult {
     pub race_metadata: RaceMetadata,
     pub race_statistics: RaceStatistics,
     pub laptimes: Vec<Lap>,
+    pub gps_trace: Vec<GpsPoint>,
     pub is_deleting: bool,
+    pub statistics_windows: StatisticsWindows,
 }
 
 impl RaceResult {
@@ -26,7 +32,9 @@ impl RaceResult {
             race_metadata,
             race_statistics: Default::default(),
             laptimes,
+            gps_trace: Default::default(),
             is_deleting: false,
+            statistics_windows: Default::default(),
         };
 
         race_result.race_statistics = RaceStatistics::new(&race_result);
@@ -39,7 +47,7 @@ impl RaceResult {
     }
 
     pub fn convert_to_race_file(&self) -> RaceResultFile {
-        RaceResultFile::new(
+        let mut race_file = RaceResultFile::new(
             &self.race_information.track_name,
             self.convert_laps_to_laptimes(),
             RaceMetadata::new(
@@ -54,7 +62,53 @@ impl RaceResult {
                 self.race_information.session.race_position,
             ),
             self.race_information.date.clone(),
-        )
+        );
+
+        race_file.sector_times = self.convert_laps_to_sector_times();
+        race_file.lap_channels = self.convert_laps_to_lap_channels();
+        race_file.gps_trace = self.gps_trace.clone();
+
+        if !self.race_metadata.session_time.is_empty() {
+            race_file.session_time = Some(self.race_metadata.session_time.clone());
+        }
+
+        race_file
+    }
+
+    fn convert_laps_to_sector_times(&self) -> Vec<Vec<String>> {
+        if self
+            .laptimes
+            .iter()
+            .all(|laptime| laptime.sectors.is_empty())
+        {
+            return vec![];
+        }
+
+        self.laptimes
+            .iter()
+            .map(|laptime| {
+                laptime
+                    .sectors
+                    .iter()
+                    .map(|sector| sector.to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn convert_laps_to_lap_channels(&self) -> Vec<LapChannels> {
+        if self
+            .laptimes
+            .iter()
+            .all(|laptime| laptime.channels.is_empty())
+        {
+            return vec![];
+        }
+
+        self.laptimes
+            .iter()
+            .map(|laptime| laptime.channels)
+            .collect()
     }
 
     fn convert_laps_to_laptimes(&self) -> Vec<String> {
@@ -1,16 +1,19 @@
-use crate::{
-    controllers::converters::time_parser::{convert_laps_to_string_laps, format_laptime},
-    models::driver::session_information::race_result::RaceResult,
+use crate::models::driver::session_information::{
+    consistency_statistics::ConsistencyStatistics, pace_trend::PaceTrend, race_result::RaceResult,
 };
 
+// Times are kept in seconds and only formatted when shown so they follow the time format
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct RaceStatistics {
-    pub average_times_table: Vec<(usize, String)>,
-    pub total_times_table: Vec<(usize, String)>,
-    pub formatted_laps: Vec<String>,
-    pub fastest_lap: String,
+    pub average_times_table: Vec<(usize, f32)>,
+    pub total_times_table: Vec<(usize, f32)>,
+    pub consecutive_times_table: Vec<(usize, f32)>,
+    pub laptimes: Vec<f32>,
+    pub fastest_lap: f32,
     pub number_of_laps: u32,
-    pub average_105_lap: String,
+    pub average_105_lap: f32,
+    pub consistency: ConsistencyStatistics,
+    pub pace_trend: PaceTrend,
 }
 
 impl RaceStatistics {
@@ -21,10 +24,15 @@ impl RaceStatistics {
         Self {
             average_times_table: RaceResult::convert_hash_map(average_times),
             total_times_table: RaceResult::convert_hash_map(total_times),
-            fastest_lap: format_laptime(race_result.get_fastest_lap()),
-            formatted_laps: convert_laps_to_string_laps(race_result.laptimes.clone()),
+            consecutive_times_table: RaceResult::convert_hash_map(
+                race_result.calculate_consecutive_times(),
+            ),
+            fastest_lap: race_result.get_fastest_lap(),
+            laptimes: race_result.laptimes.iter().map(|lap| lap.time).collect(),
             number_of_laps: race_result.get_number_of_laps(),
-            average_105_lap: format_laptime(race_result.get_average_lap()),
+            average_105_lap: race_result.get_average_lap(),
+            consistency: race_result.calculate_consistency(),
+            pace_trend: race_result.calculate_pace_trend(),
         }
     }
 }
@@ -0,0 +1,463 @@
+use crate::{
+    controllers::converters::{
+        csv_parser::{detect_delimiter, find_column, parse_csv_line},
+        time_parser::convert_session_time_to_session_id,
+    },
+    models::date::RaceDate,
+};
+use chrono::{Datelike, NaiveDate};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimingSystem {
+    AlphaTiming,
+    ApexTiming,
+    MyLapsSpeedhive,
+}
+
+impl TimingSystem {
+    pub fn detect(headers: &[String]) -> Option<TimingSystem> {
+        [
+            TimingSystem::AlphaTiming,
+            TimingSystem::ApexTiming,
+            TimingSystem::MyLapsSpeedhive,
+        ]
+        .into_iter()
+        .find(|timing_system| {
+            timing_system
+                .signature_columns()
+                .iter()
+                .all(|column| find_column(headers, column).is_some())
+        })
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimingSystem::AlphaTiming => "Alpha Timing",
+            TimingSystem::ApexTiming => "Apex Timing",
+            TimingSystem::MyLapsSpeedhive => "MyLaps Speedhive",
+        }
+    }
+
+    fn signature_columns(&self) -> Vec<&'static str> {
+        vec![
+            self.kart_column(),
+            self.driver_column(),
+            self.lap_column(),
+            self.laptime_column(),
+            self.position_column(),
+        ]
+    }
+
+    fn kart_column(&self) -> &'static str {
+        match self {
+            TimingSystem::AlphaTiming => "Kart",
+            TimingSystem::ApexTiming => "Kart",
+            TimingSystem::MyLapsSpeedhive => "No.",
+        }
+    }
+
+    fn driver_column(&self) -> &'static str {
+        match self {
+            TimingSystem::AlphaTiming => "Driver",
+            TimingSystem::ApexTiming => "Driver",
+            TimingSystem::MyLapsSpeedhive => "Name",
+        }
+    }
+
+    fn lap_column(&self) -> &'static str {
+        "Lap"
+    }
+
+    fn laptime_column(&self) -> &'static str {
+        match self {
+            TimingSystem::AlphaTiming => "Lap Time",
+            TimingSystem::ApexTiming => "Time",
+            TimingSystem::MyLapsSpeedhive => "Lap Time",
+        }
+    }
+
+    fn position_column(&self) -> &'static str {
+        match self {
+            TimingSystem::AlphaTiming => "Pos",
+            TimingSystem::ApexTiming => "Rank",
+            TimingSystem::MyLapsSpeedhive => "Position",
+        }
+    }
+
+    fn time_of_day_column(&self) -> Option<&'static str> {
+        match self {
+            TimingSystem::AlphaTiming => None,
+            TimingSystem::ApexTiming => None,
+            TimingSystem::MyLapsSpeedhive => Some("Time of Day"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TimingSystemLap {
+    pub kart_number: String,
+    pub driver_name: String,
+    pub lap_number: u32,
+    pub laptime: String,
+    pub race_position: u32,
+    pub time_of_day: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimingSystemSession {
+    pub timing_system: TimingSystem,
+    pub session_name: String,
+    pub track_name: String,
+    pub date: Option<RaceDate>,
+    pub session_time: String,
+    pub laps: Vec<TimingSystemLap>,
+}
+
+impl TimingSystemSession {
+    pub fn parse(contents: &str) -> Option<TimingSystemSession> {
+        let lines: Vec<&str> = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+
+        let mut session_name = String::new();
+        let mut track_name = String::new();
+        let mut date = None;
+        let mut session_time = String::new();
+
+        for (index, line) in lines.iter().enumerate() {
+            let delimiter = detect_delimiter(line);
+            let cells = parse_csv_line(line, delimiter);
+
+            if let Some(timing_system) = TimingSystem::detect(&cells) {
+                let laps = Self::parse_laps(timing_system, &cells, &lines[index + 1..], delimiter);
+
+                return Some(TimingSystemSession {
+                    timing_system,
+                    session_name,
+                    track_name,
+                    date,
+                    session_time,
+                    laps,
+                });
+            }
+
+            let Some(key) = cells.first() else { continue };
+            let value = cells.get(1).cloned().unwrap_or_default();
+
+            match key.to_lowercase().trim_end_matches(':') {
+                "session" => session_name = value,
+                "track" => track_name = value,
+                "date" => date = parse_date(&value),
+                "time" | "start" | "start time" => session_time = value,
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    // Heats on the same day are told apart by when they started, or by the number in their name
+    // when the lap chart has no times of day
+    pub fn session_id(&self) -> u32 {
+        if !self.session_time.trim().is_empty() {
+            return convert_session_time_to_session_id(&self.session_time);
+        }
+
+        if let Some(session_id) = self
+            .laps
+            .iter()
+            .filter(|lap| !lap.time_of_day.trim().is_empty())
+            .map(|lap| convert_session_time_to_session_id(&lap.time_of_day))
+            .min()
+        {
+            return session_id;
+        }
+
+        self.session_name
+            .chars()
+            .skip_while(|char| !char.is_ascii_digit())
+            .take_while(|char| char.is_ascii_digit())
+            .collect::<String>()
+            .parse::<u32>()
+            .ok()
+            .filter(|session_id| *session_id != 0)
+            .unwrap_or(1)
+    }
+
+    pub fn find_driver_laps(&self, driver_name: &str) -> Option<Vec<TimingSystemLap>> {
+        let driver_name = driver_name.trim().to_lowercase();
+
+        let laps: Vec<TimingSystemLap> = self
+            .laps
+            .iter()
+            .filter(|lap| lap.driver_name.trim().to_lowercase() == driver_name)
+            .cloned()
+            .collect();
+
+        match laps.is_empty() {
+            true => None,
+            false => Some(laps),
+        }
+    }
+
+    pub fn calculate_race_position(laps: &[TimingSystemLap]) -> u32 {
+        let mut laps = laps.to_vec();
+        laps.sort_by_key(|lap| lap.lap_number);
+
+        laps.iter()
+            .rev()
+            .map(|lap| lap.race_position)
+            .find(|race_position| *race_position != 0)
+            .unwrap_or(999)
+    }
+
+    pub fn convert_to_laptimes(laps: &[TimingSystemLap]) -> Vec<String> {
+        let mut laps = laps.to_vec();
+        laps.sort_by_key(|lap| lap.lap_number);
+
+        laps.into_iter().map(|lap| lap.laptime).collect()
+    }
+
+    fn parse_laps(
+        timing_system: TimingSystem,
+        headers: &[String],
+        rows: &[&str],
+        delimiter: char,
+    ) -> Vec<TimingSystemLap> {
+        let (
+            Some(kart_column),
+            Some(driver_column),
+            Some(lap_column),
+            Some(laptime_column),
+            Some(position_column),
+        ) = (
+            find_column(headers, timing_system.kart_column()),
+            find_column(headers, timing_system.driver_column()),
+            find_column(headers, timing_system.lap_column()),
+            find_column(headers, timing_system.laptime_column()),
+            find_column(headers, timing_system.position_column()),
+        )
+        else {
+            return vec![];
+        };
+
+        let time_of_day_column = timing_system
+            .time_of_day_column()
+            .and_then(|column| find_column(headers, column));
+
+        let mut laps = vec![];
+
+        for row in rows {
+            let cells = parse_csv_line(row, delimiter);
+            let cell = |column: usize| cells.get(column).cloned().unwrap_or_default();
+
+            let Ok(lap_number) = cell(lap_column).parse::<u32>() else {
+                continue;
+            };
+
+            let laptime = cell(laptime_column);
+            if !laptime.chars().any(|char| char.is_ascii_digit()) {
+                continue;
+            }
+
+            laps.push(TimingSystemLap {
+                kart_number: cell(kart_column),
+                driver_name: cell(driver_column),
+                lap_number,
+                laptime,
+                race_position: cell(position_column).parse::<u32>().unwrap_or_default(),
+                time_of_day: time_of_day_column.map(cell).unwrap_or_default(),
+            });
+        }
+
+        laps
+    }
+}
+
+pub fn parse_date(date: &str) -> Option<RaceDate> {
+    ["%d/%m/%Y", "%Y-%m-%d", "%d.%m.%Y"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(date.trim(), format).ok())
+        .map(|date| RaceDate::new(date.day(), date.month(), date.year()))
+}
+
+#[cfg(test)]
+mod timing_system_session_should {
+    use super::*;
+    use rstest::rstest;
+
+    fn headers(headers: &[&str]) -> Vec<String> {
+        headers.iter().map(|header| header.to_string()).collect()
+    }
+
+    #[rstest]
+    #[case(headers(&["Pos", "Kart", "Driver", "Lap", "Lap Time"]), Some(TimingSystem::AlphaTiming))]
+    #[case(headers(&["Rank", "Kart", "Driver", "Lap", "Time", "Gap"]), Some(TimingSystem::ApexTiming))]
+    #[case(headers(&["Position", "No.", "Name", "Lap", "Lap Time", "Time of Day"]), Some(TimingSystem::MyLapsSpeedhive))]
+    #[case(headers(&["Lap", "Lap Time"]), None)]
+    fn test_detect_timing_system(
+        #[case] headers: Vec<String>,
+        #[case] expected_timing_system: Option<TimingSystem>,
+    ) {
+        // When
+        let timing_system = TimingSystem::detect(&headers);
+
+        // Then
+        pretty_assertions::assert_eq!(expected_timing_system, timing_system);
+    }
+
+    #[test]
+    fn test_parse_timing_system_session() {
+        // Given
+        let contents = "Session,Heat 2\nTrack,Three Sisters\nDate,18/11/2023\n\nPos,Kart,Driver,Lap,Lap Time\n2,12,Jack Jackson,1,48.667\n1,7,Obi Wan Kenobi,1,47.102\n1,12,Jack Jackson,2,PIT\n1,12,Jack Jackson,3,\"1:02.345\"\n";
+        let expected_session = TimingSystemSession {
+            timing_system: TimingSystem::AlphaTiming,
+            session_name: "Heat 2".to_string(),
+            track_name: "Three Sisters".to_string(),
+            date: Some(RaceDate::new(18, 11, 2023)),
+            session_time: String::new(),
+            laps: vec![
+                TimingSystemLap {
+                    kart_number: "12".to_string(),
+                    driver_name: "Jack Jackson".to_string(),
+                    lap_number: 1,
+                    laptime: "48.667".to_string(),
+                    race_position: 2,
+                    time_of_day: String::new(),
+                },
+                TimingSystemLap {
+                    kart_number: "7".to_string(),
+                    driver_name: "Obi Wan Kenobi".to_string(),
+                    lap_number: 1,
+                    laptime: "47.102".to_string(),
+                    race_position: 1,
+                    time_of_day: String::new(),
+                },
+                TimingSystemLap {
+                    kart_number: "12".to_string(),
+                    driver_name: "Jack Jackson".to_string(),
+                    lap_number: 3,
+                    laptime: "1:02.345".to_string(),
+                    race_position: 1,
+                    time_of_day: String::new(),
+                },
+            ],
+        };
+
+        // When
+        let session = TimingSystemSession::parse(contents);
+
+        // Then
+        pretty_assertions::assert_eq!(Some(expected_session), session);
+    }
+
+    #[test]
+    fn test_parse_unknown_layout() {
+        // When
+        let session = TimingSystemSession::parse("Lap,Lap Time\n1,48.667\n");
+
+        // Then
+        assert!(session.is_none());
+    }
+
+    #[test]
+    fn test_find_driver_laps() {
+        // Given
+        let session = TimingSystemSession {
+            timing_system: TimingSystem::ApexTiming,
+            session_name: Default::default(),
+            track_name: Default::default(),
+            date: None,
+            session_time: Default::default(),
+            laps: vec![
+                TimingSystemLap {
+                    kart_number: "12".to_string(),
+                    driver_name: "Jack Jackson".to_string(),
+                    lap_number: 1,
+                    ..Default::default()
+                },
+                TimingSystemLap {
+                    kart_number: "7".to_string(),
+                    driver_name: "Obi Wan Kenobi".to_string(),
+                    lap_number: 1,
+                    ..Default::default()
+                },
+                TimingSystemLap {
+                    kart_number: "12".to_string(),
+                    driver_name: "Jack Jackson".to_string(),
+                    lap_number: 2,
+                    ..Default::default()
+                },
+            ],
+        };
+
+        // When
+        let driver_laps = session.find_driver_laps(" jack jackson ");
+
+        // Then
+        pretty_assertions::assert_eq!(2, driver_laps.unwrap().len());
+        assert!(session.find_driver_laps("Racer").is_none());
+    }
+
+    #[rstest]
+    #[case("Heat 2", "14:05", "", 1405)]
+    #[case("Heat 2", "", "14:02:09.537", 1402)]
+    #[case("Heat 2", "", "", 2)]
+    #[case("Final", "", "", 1)]
+    fn test_session_id(
+        #[case] session_name: &str,
+        #[case] session_time: &str,
+        #[case] time_of_day: &str,
+        #[case] expected_session_id: u32,
+    ) {
+        // Given
+        let session = TimingSystemSession {
+            timing_system: TimingSystem::MyLapsSpeedhive,
+            session_name: session_name.to_string(),
+            track_name: Default::default(),
+            date: None,
+            session_time: session_time.to_string(),
+            laps: vec![TimingSystemLap {
+                time_of_day: time_of_day.to_string(),
+                ..Default::default()
+            }],
+        };
+
+        // When
+        let session_id = session.session_id();
+
+        // Then
+        pretty_assertions::assert_eq!(expected_session_id, session_id);
+    }
+
+    #[test]
+    fn test_calculate_race_position_and_convert_to_laptimes() {
+        // Given
+        let laps = vec![
+            TimingSystemLap {
+                lap_number: 2,
+                laptime: "47.5".to_string(),
+                race_position: 3,
+                ..Default::default()
+            },
+            TimingSystemLap {
+                lap_number: 1,
+                laptime: "48.1".to_string(),
+                race_position: 4,
+                ..Default::default()
+            },
+        ];
+
+        // Then
+        pretty_assertions::assert_eq!(3, TimingSystemSession::calculate_race_position(&laps));
+        pretty_assertions::assert_eq!(
+            999,
+            TimingSystemSession::calculate_race_position(&[TimingSystemLap::default()])
+        );
+        pretty_assertions::assert_eq!(
+            vec!["48.1".to_string(), "47.5".to_string()],
+            TimingSystemSession::convert_to_laptimes(&laps)
+        );
+    }
+}
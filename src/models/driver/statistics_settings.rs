@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+// Totals and averages are bucketed every few laps and the average lap only counts laps within a
+// percentage of the fastest lap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatisticsWindows {
+    pub bucket_size: u32,
+    pub average_cutoff_percentage: u32,
+}
+
+impl Default for StatisticsWindows {
+    fn default() -> Self {
+        Self {
+            bucket_size: 5,
+            average_cutoff_percentage: 105,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SessionTypeWindows {
+    pub session_type: String,
+    pub statistics_windows: StatisticsWindows,
+}
+
+// The profile's windows apply to every race unless its session type has its own
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatisticsSettings {
+    pub statistics_windows: StatisticsWindows,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub session_type_windows: Vec<SessionTypeWindows>,
+}
@@ -1,18 +1,91 @@
 use crate::{
     commands::{messages::Message, tab_identifiers::TabIdentifier},
-    models::application::karting_time::KartingTime,
+    controllers::{
+        file::file_io::find_recovery_file, telemetry::udp_listener::listen_for_telemetry,
+    },
+    models::application::{karting_time::KartingTime, time_format::TimeFormat},
 };
-use iced::{Element, Task, Theme};
+use iced::{
+    Element, Subscription, Task, Theme,
+    keyboard::{self, Key},
+    time, window,
+};
+use std::time::Duration;
+
+const WATCHED_FOLDER_POLL_SECONDS: u64 = 5;
+const AUTOSAVE_SECONDS: u64 = 60;
 
 impl KartingTime {
     pub fn boot() -> (Self, Task<Message>) {
-        (Self::default(), Task::none())
+        let mut karting_time = Self::default();
+
+        karting_time
+            .application_state
+            .unsaved_changes
+            .recovery_file_path = find_recovery_file();
+
+        karting_time.load_settings();
+
+        let resize_task = match karting_time.application_state.settings.window_size {
+            Some(window_size) => {
+                window::oldest().and_then(move |window_id| window::resize(window_id, window_size))
+            }
+            None => Task::none(),
+        };
+
+        let reopen_task = match karting_time.last_profile_to_reopen() {
+            Some(profile_path) => Task::done(Message::OpenRecentProfilePressed(profile_path)),
+            None => Task::none(),
+        };
+
+        (karting_time, Task::batch([resize_task, reopen_task]))
     }
 
     pub fn view(&self) -> Element<'_, Message> {
         self.tab_bar_view().into()
     }
 
+    pub fn subscription(&self) -> Subscription<Message> {
+        let telemetry = &self.application_state.telemetry;
+
+        let telemetry_subscription = match telemetry.is_listening {
+            true => Subscription::run_with((telemetry.game, telemetry.port), listen_for_telemetry),
+            false => Subscription::none(),
+        };
+
+        let watched_folders_subscription =
+            match self.application_state.watch_folders.folders.is_empty() {
+                true => Subscription::none(),
+                false => time::every(Duration::from_secs(WATCHED_FOLDER_POLL_SECONDS))
+                    .map(|_| Message::WatchedFoldersPolled),
+            };
+
+        let unsaved_changes = &self.application_state.unsaved_changes;
+
+        let autosave_subscription =
+            match unsaved_changes.is_dirty && unsaved_changes.recovery_file_path.is_none() {
+                true => time::every(Duration::from_secs(AUTOSAVE_SECONDS))
+                    .map(|_| Message::AutosaveTick),
+                false => Subscription::none(),
+            };
+
+        let close_subscription = window::close_requests().map(|_| Message::CloseRequested);
+
+        let resize_subscription =
+            window::resize_events().map(|(_, window_size)| Message::WindowResized(window_size));
+
+        let shortcut_subscription = keyboard::listen().filter_map(shortcut_message);
+
+        Subscription::batch([
+            telemetry_subscription,
+            watched_folders_subscription,
+            autosave_subscription,
+            close_subscription,
+            resize_subscription,
+            shortcut_subscription,
+        ])
+    }
+
     pub fn switch_tab(&mut self, tab_identifier: TabIdentifier) {
         match tab_identifier {
             TabIdentifier::Race => {
@@ -44,6 +117,17 @@ impl KartingTime {
         }
     }
 
+    // Data issue descriptions include lap times so they're rebuilt in the new format
+    pub fn change_time_format(&mut self, time_format: TimeFormat) {
+        self.application_state.time_format = time_format;
+
+        if self.application_state.data_repair.is_visible {
+            self.application_state
+                .data_repair
+                .refresh(&self.driver_profile.races, time_format);
+        }
+    }
+
     pub fn toggle_filter(&mut self) {
         match self.driver_profile.filter.is_filter_visible {
             true => self.driver_profile.filter.is_filter_visible = false,
@@ -51,3 +135,20 @@ impl KartingTime {
         }
     }
 }
+
+fn shortcut_message(event: keyboard::Event) -> Option<Message> {
+    let keyboard::Event::KeyPressed { key, modifiers, .. } = event else {
+        return None;
+    };
+
+    if !modifiers.command() {
+        return None;
+    }
+
+    match key.as_ref() {
+        Key::Character("z") if modifiers.shift() => Some(Message::Redo),
+        Key::Character("z") => Some(Message::Undo),
+        Key::Character("y") => Some(Message::Redo),
+        _ => None,
+    }
+}
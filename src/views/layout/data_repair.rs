@@ -0,0 +1,48 @@
+use crate::commands::messages::Message;
+use crate::models::application::karting_time::KartingTime;
+use iced::widget::{button, column, row, scrollable, text};
+
+const DATA_REPAIR_HEIGHT: f32 = 200.0;
+
+impl KartingTime {
+    pub fn data_repair_view(&self) -> iced::widget::Column<'_, Message> {
+        let data_repair = &self.application_state.data_repair;
+
+        let data_repair_panel = column!().spacing(10);
+
+        if !data_repair.is_visible {
+            return data_repair_panel;
+        }
+
+        let issues = data_repair.issues.iter().enumerate().fold(
+            column!().spacing(5),
+            |issues, (index, issue)| {
+                issues.push(
+                    row!(
+                        text(issue.description.clone()),
+                        button("Repair").on_press(Message::RepairDataIssuePressed(index)),
+                        button("Keep").on_press(Message::KeepDataIssuePressed(index))
+                    )
+                    .spacing(10),
+                )
+            },
+        );
+
+        data_repair_panel.push(
+            column!(
+                text(format!(
+                    "Data Issues: {} found in the profile",
+                    data_repair.issues.len()
+                )),
+                scrollable(issues).height(DATA_REPAIR_HEIGHT),
+                row!(
+                    button("Repair All").on_press(Message::RepairAllDataIssuesPressed),
+                    button("Keep All").on_press(Message::KeepAllDataIssuesPressed)
+                )
+                .spacing(10)
+            )
+            .spacing(10)
+            .padding(10),
+        )
+    }
+}
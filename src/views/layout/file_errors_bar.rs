@@ -0,0 +1,32 @@
+use crate::commands::messages::Message;
+use crate::models::application::karting_time::KartingTime;
+use iced::widget::{button, column, row, text};
+
+impl KartingTime {
+    pub fn file_errors_bar_view(&self) -> iced::widget::Column<'_, Message> {
+        let file_errors = &self.application_state.file_errors;
+
+        let mut file_errors_bar = column!().spacing(10);
+
+        if file_errors.is_empty() {
+            return file_errors_bar;
+        }
+
+        for (index, file_error) in file_errors.iter().enumerate() {
+            file_errors_bar = file_errors_bar.push(
+                row!(
+                    text(file_error.to_string()),
+                    button("Dismiss").on_press(Message::DismissFileErrorPressed(index))
+                )
+                .spacing(10)
+                .padding(10),
+            );
+        }
+
+        file_errors_bar.push(
+            row!(button("Dismiss All").on_press(Message::ClearFileErrorsPressed))
+                .spacing(10)
+                .padding(10),
+        )
+    }
+}
@@ -0,0 +1,38 @@
+use crate::commands::messages::Message;
+use crate::models::application::karting_time::KartingTime;
+use iced::widget::{button, column, row, scrollable, text};
+
+const IMPORT_REPORT_HEIGHT: f32 = 200.0;
+
+impl KartingTime {
+    pub fn import_report_view(&self) -> iced::widget::Column<'_, Message> {
+        let import_report = &self.application_state.import_report;
+
+        let import_report_panel = column!().spacing(10);
+
+        if !import_report.is_visible {
+            return import_report_panel;
+        }
+
+        let entries = import_report
+            .entries
+            .iter()
+            .fold(column!().spacing(5), |entries, entry| {
+                entries.push(text(entry.convert_to_text()))
+            });
+
+        import_report_panel.push(
+            column!(
+                text(format!("Import Report: {}", import_report.summary())),
+                scrollable(entries).height(IMPORT_REPORT_HEIGHT),
+                row!(
+                    button("Save Report").on_press(Message::SaveImportReportRequested),
+                    button("Close").on_press(Message::CloseImportReportPressed)
+                )
+                .spacing(10)
+            )
+            .spacing(10)
+            .padding(10),
+        )
+    }
+}
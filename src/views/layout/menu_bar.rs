@@ -1,8 +1,9 @@
 use crate::commands::messages::Message;
 use crate::models::application::karting_time::KartingTime;
-use iced::Length;
-use iced::widget::{button, column};
-use iced_aw::menu::Menu;
+use crate::models::application::time_format::{DecimalPlaces, TimeStyle};
+use iced::widget::{button, checkbox, column, text};
+use iced::{Length, Renderer, Theme};
+use iced_aw::menu::{Item, Menu};
 use iced_aw::{menu_bar, menu_items};
 
 impl KartingTime {
@@ -20,6 +21,53 @@ impl KartingTime {
                     (button("Load Driver Profile")
                         .width(Length::Fill)
                         .on_press(Message::LoadApplicationRequested)),
+                    (
+                        button("Recent Profiles")
+                            .width(Length::Fill)
+                            .on_press(Message::MenuBar),
+                        menu_template(self.recent_profiles_menu_items())
+                    ),
+                    (button("Merge Driver Profile")
+                        .width(Length::Fill)
+                        .on_press(Message::MergeProfileRequested)),
+                    (button("Restore From Backup")
+                        .width(Length::Fill)
+                        .on_press(Message::RestoreBackupRequested)),
+                    (
+                        button("Profile Folder")
+                            .width(Length::Fill)
+                            .on_press(Message::MenuBar),
+                        menu_template(menu_items!(
+                            (button("Save Profile Folder")
+                                .width(Length::Fill)
+                                .on_press(Message::SaveProfileFolderRequested)),
+                            (button("Load Profile Folder")
+                                .width(Length::Fill)
+                                .on_press(Message::LoadProfileFolderRequested)),
+                            (button("Merge Profile Folder")
+                                .width(Length::Fill)
+                                .on_press(Message::MergeProfileFolderRequested)),
+                            (button("Convert Profile To Folder")
+                                .width(Length::Fill)
+                                .on_press(Message::ConvertProfileToFolderRequested)),
+                            (button("Convert Folder To Profile")
+                                .width(Length::Fill)
+                                .on_press(Message::ConvertFolderToProfileRequested)),
+                        ))
+                    ),
+                    (
+                        button("Database")
+                            .width(Length::Fill)
+                            .on_press(Message::MenuBar),
+                        menu_template(menu_items!(
+                            (button("Save To Database")
+                                .width(Length::Fill)
+                                .on_press(Message::SaveDatabaseRequested)),
+                            (button("Open Database")
+                                .width(Length::Fill)
+                                .on_press(Message::OpenDatabaseRequested)),
+                        ))
+                    ),
                     (
                         button("Import")
                             .width(Length::Fill)
@@ -31,9 +79,27 @@ impl KartingTime {
                             (button("Import ACC Race")
                                 .width(Length::Fill)
                                 .on_press(Message::ImportAccLaptimesFileRequested)),
+                            (button("Import Timing System Race")
+                                .width(Length::Fill)
+                                .on_press(Message::ImportTimingSystemFileRequested)),
+                            (button("Import Data Logger Session")
+                                .width(Length::Fill)
+                                .on_press(Message::ImportDataLoggerFileRequested)),
+                            (button("Import GPS Session")
+                                .width(Length::Fill)
+                                .on_press(Message::ImportGpsFileRequested)),
+                            (button("Import CSV Laptimes")
+                                .width(Length::Fill)
+                                .on_press(Message::ImportCsvFileRequested)),
                             (button("Import Laptimes")
                                 .width(Length::Fill)
                                 .on_press(Message::ImportLaptimesFileRequested)),
+                            (button("Import Timing Sheet")
+                                .width(Length::Fill)
+                                .on_press(Message::ImportTimingSheetFileRequested)),
+                            (button("Replay Telemetry")
+                                .width(Length::Fill)
+                                .on_press(Message::ReplayTelemetryRequested)),
                         ))
                     ),
                     (
@@ -51,6 +117,17 @@ impl KartingTime {
                     )
                 ))
             ),
+            (
+                button("Edit").on_press(Message::MenuBar),
+                menu_template(menu_items!(
+                    (button("Undo")
+                        .width(Length::Fill)
+                        .on_press_maybe(self.undo_message())),
+                    (button("Redo")
+                        .width(Length::Fill)
+                        .on_press_maybe(self.redo_message()))
+                ))
+            ),
             (
                 button("View").on_press(Message::MenuBar),
                 menu_template(menu_items!(
@@ -59,11 +136,113 @@ impl KartingTime {
                         .on_press(Message::ViewToggleTheme)),
                     (button("Toggle Filter")
                         .width(Length::Fill)
-                        .on_press(Message::ViewToggleFilter))
+                        .on_press(Message::ViewToggleFilter)),
+                    (
+                        button("Time Format")
+                            .width(Length::Fill)
+                            .on_press(Message::MenuBar),
+                        menu_template(menu_items!(
+                            (button(text(TimeStyle::Seconds.to_string()))
+                                .width(Length::Fill)
+                                .on_press(Message::TimeStyleSelected(TimeStyle::Seconds))),
+                            (button(text(TimeStyle::Minutes.to_string()))
+                                .width(Length::Fill)
+                                .on_press(Message::TimeStyleSelected(TimeStyle::Minutes))),
+                            (button(text(DecimalPlaces::One.to_string()))
+                                .width(Length::Fill)
+                                .on_press(Message::DecimalPlacesSelected(DecimalPlaces::One))),
+                            (button(text(DecimalPlaces::Two.to_string()))
+                                .width(Length::Fill)
+                                .on_press(Message::DecimalPlacesSelected(DecimalPlaces::Two))),
+                            (button(text(DecimalPlaces::Three.to_string()))
+                                .width(Length::Fill)
+                                .on_press(Message::DecimalPlacesSelected(DecimalPlaces::Three))),
+                        ))
+                    ),
+                    (
+                        button("Races Per Page")
+                            .width(Length::Fill)
+                            .on_press(Message::MenuBar),
+                        menu_template(menu_items!(
+                            (button("5")
+                                .width(Length::Fill)
+                                .on_press(Message::PageSizeSelected(5))),
+                            (button("10")
+                                .width(Length::Fill)
+                                .on_press(Message::PageSizeSelected(10))),
+                            (button("20")
+                                .width(Length::Fill)
+                                .on_press(Message::PageSizeSelected(20))),
+                            (button("50")
+                                .width(Length::Fill)
+                                .on_press(Message::PageSizeSelected(50))),
+                        ))
+                    )
                 ))
             )
         );
 
-        column!().push(menu_bar)
+        column!()
+            .push(menu_bar)
+            .push(self.unsaved_changes_bar_view())
+            .push(self.file_errors_bar_view())
+            .push(self.import_report_view())
+            .push(self.data_repair_view())
+    }
+
+    fn recent_profiles_menu_items(&self) -> Vec<Item<'_, Message, Theme, Renderer>> {
+        let settings = &self.application_state.settings;
+
+        let mut items: Vec<Item<'_, Message, Theme, Renderer>> = settings
+            .recent_profiles
+            .iter()
+            .map(|recent_profile| {
+                Item::new(
+                    button(text(recent_profile.as_str()))
+                        .width(Length::Fill)
+                        .on_press(Message::OpenRecentProfilePressed(recent_profile.clone())),
+                )
+            })
+            .collect();
+
+        items.push(Item::new(
+            button("Clear Recent Profiles")
+                .width(Length::Fill)
+                .on_press_maybe(
+                    (!settings.recent_profiles.is_empty())
+                        .then_some(Message::ClearRecentProfilesPressed),
+                ),
+        ));
+        items.push(Item::new(
+            checkbox(settings.is_reopening_last_profile)
+                .label("Reopen Last Profile On Start")
+                .on_toggle(|_| Message::ReopenLastProfileToggled),
+        ));
+
+        items
+    }
+
+    fn undo_message(&self) -> Option<Message> {
+        match self
+            .application_state
+            .undo_history
+            .undo_snapshots
+            .is_empty()
+        {
+            true => None,
+            false => Some(Message::Undo),
+        }
+    }
+
+    fn redo_message(&self) -> Option<Message> {
+        match self
+            .application_state
+            .undo_history
+            .redo_snapshots
+            .is_empty()
+        {
+            true => None,
+            false => Some(Message::Redo),
+        }
     }
 }
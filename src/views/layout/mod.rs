@@ -1,3 +1,7 @@
+pub mod data_repair;
+pub mod file_errors_bar;
+pub mod import_report;
 pub mod menu_bar;
 pub mod pagination_bar;
 pub mod tab_bar;
+pub mod unsaved_changes_bar;
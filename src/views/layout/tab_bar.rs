@@ -17,8 +17,12 @@ impl KartingTime {
 
                 let contents = Scrollable::new(
                     column!()
+                        .push(self.profile_merge_view())
+                        .push(self.csv_import_view())
                         .push(self.edit_driver_profile_view())
-                        .push(self.upsert_race_view()),
+                        .push(self.upsert_race_view())
+                        .push(self.live_telemetry_view())
+                        .push(self.watched_folders_view()),
                 );
 
                 column!(self.menu_bar_view(), tab_bar, contents)
@@ -48,9 +52,14 @@ impl KartingTime {
 
                 let contents = Scrollable::new(column!().push(self.overview_race_results_view()));
 
-                match self.driver_profile.filter.is_filter_visible {
+                let overview = match self.driver_profile.filter.is_filter_visible {
                     true => column!(self.menu_bar_view(), tab_bar, scrollable_filter, contents),
                     false => column!(self.menu_bar_view(), tab_bar, contents),
+                };
+
+                match self.application_state.database {
+                    Some(_) => overview.push(self.pagination_bar_view()),
+                    None => overview,
                 }
             }
             TabIdentifier::Results => {
@@ -0,0 +1,37 @@
+use crate::commands::messages::Message;
+use crate::models::application::karting_time::KartingTime;
+use iced::widget::{button, column, row, text};
+
+impl KartingTime {
+    pub fn unsaved_changes_bar_view(&self) -> iced::widget::Column<'_, Message> {
+        let unsaved_changes = &self.application_state.unsaved_changes;
+
+        let mut unsaved_changes_bar = column!().spacing(10);
+
+        if unsaved_changes.recovery_file_path.is_some() {
+            unsaved_changes_bar = unsaved_changes_bar.push(
+                row!(
+                    text("A driver profile with unsaved changes was recovered"),
+                    button("Restore").on_press(Message::RestoreRecoveryPressed),
+                    button("Discard").on_press(Message::DiscardRecoveryPressed)
+                )
+                .spacing(10)
+                .padding(10),
+            );
+        }
+
+        if unsaved_changes.pending_message.is_some() {
+            unsaved_changes_bar = unsaved_changes_bar.push(
+                row!(
+                    text("The driver profile has unsaved changes"),
+                    button("Discard Changes").on_press(Message::DiscardChangesPressed),
+                    button("Cancel").on_press(Message::KeepChangesPressed)
+                )
+                .spacing(10)
+                .padding(10),
+            );
+        }
+
+        unsaved_changes_bar
+    }
+}
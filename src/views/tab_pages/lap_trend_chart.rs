@@ -0,0 +1,85 @@
+use iced::{
+    Color, Point, Rectangle, Renderer, Theme, mouse,
+    widget::canvas::{self, Frame, Geometry, Path, Stroke},
+};
+
+const PADDING: f32 = 10.0;
+
+// Laps are (lap number, time) and the trend is the slope and intercept of the fitted line,
+// outliers are pinned to the edge of the chart so they don't squash the rest of the laps
+pub struct LapTrendChart {
+    pub laps: Vec<(f32, f32)>,
+    pub outliers: Vec<(f32, f32)>,
+    pub trend: (f32, f32),
+    pub color: Color,
+    pub outlier_color: Color,
+}
+
+impl<Message> canvas::Program<Message> for LapTrendChart {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        if self.laps.is_empty() {
+            return vec![frame.into_geometry()];
+        }
+
+        let lap_numbers = self.laps.iter().chain(&self.outliers).map(|(lap, _)| *lap);
+        let first_lap = lap_numbers.clone().fold(f32::MAX, f32::min);
+        let last_lap = lap_numbers.fold(f32::MIN, f32::max);
+        let fastest_time = self
+            .laps
+            .iter()
+            .map(|(_, time)| *time)
+            .fold(f32::MAX, f32::min);
+        let slowest_time = self
+            .laps
+            .iter()
+            .map(|(_, time)| *time)
+            .fold(f32::MIN, f32::max);
+
+        let width = (bounds.width - PADDING * 2.0).max(0.0);
+        let height = (bounds.height - PADDING * 2.0).max(0.0);
+        let lap_range = (last_lap - first_lap).max(1.0);
+        let time_range = (slowest_time - fastest_time).max(0.1);
+
+        // Slower laps are drawn higher up
+        let to_point = |(lap, time): (f32, f32)| {
+            let time = time.clamp(fastest_time, slowest_time);
+
+            Point::new(
+                PADDING + (lap - first_lap) / lap_range * width,
+                PADDING + (slowest_time - time) / time_range * height,
+            )
+        };
+
+        for lap in &self.laps {
+            frame.fill(&Path::circle(to_point(*lap), 3.0), self.color);
+        }
+
+        for outlier in &self.outliers {
+            frame.fill(&Path::circle(to_point(*outlier), 3.0), self.outlier_color);
+        }
+
+        let (degradation_per_lap, intercept) = self.trend;
+        let trend_line = Path::line(
+            to_point((first_lap, intercept + degradation_per_lap * first_lap)),
+            to_point((last_lap, intercept + degradation_per_lap * last_lap)),
+        );
+
+        frame.stroke(
+            &trend_line,
+            Stroke::default().with_color(self.color).with_width(2.0),
+        );
+
+        vec![frame.into_geometry()]
+    }
+}
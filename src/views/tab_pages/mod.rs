@@ -1,4 +1,6 @@
+pub mod lap_trend_chart;
 pub mod profile_overview_page;
 pub mod race_editor_page;
 pub mod race_results_overview_page;
 pub mod race_results_page;
+pub mod track_outline;
@@ -1,4 +1,8 @@
-use crate::{commands::messages::Message, models::application::karting_time::KartingTime};
+use crate::{
+    commands::messages::Message,
+    controllers::converters::time_parser::{format_laptime, format_seconds, format_time_gap},
+    models::application::karting_time::KartingTime,
+};
 use iced::{
     Element,
     widget::{Column, column, text},
@@ -12,7 +16,13 @@ impl KartingTime {
             .push(text(self.driver_profile.name.to_string()).size(24))
             .padding(10)
             .spacing(10)
-            .push(self.overview_driver_profile_table());
+            .push(self.overview_driver_profile_table())
+            .push(text("Personal Bests"))
+            .push(self.personal_bests_table())
+            .push(text("Personal Best Progression"))
+            .push(self.personal_best_progression_table())
+            .push(text("Consistency by Track"))
+            .push(self.consistency_summary_table());
 
         column!()
             .push(Card::new(
@@ -75,4 +85,109 @@ impl KartingTime {
             None,
         )
     }
+
+    fn personal_bests_table(&self) -> Element<'_, Message> {
+        let mut table = Table::default();
+
+        table.add_headers(vec!["Track", "Car Used", "Personal Best", "Time", "Date"]);
+
+        let time_format = self.application_state.time_format;
+
+        for track in &self.driver_profile.personal_bests.tracks {
+            for personal_best in &track.personal_bests {
+                table.add_row(vec![
+                    track.track_name.clone(),
+                    track.car_used.clone(),
+                    personal_best.category.to_string(),
+                    format_laptime(personal_best.time, time_format),
+                    personal_best.date.to_string(),
+                ]);
+            }
+        }
+
+        Table::build(
+            table,
+            Some(self.theme().palette().text),
+            Some(500.0),
+            None,
+            None,
+        )
+    }
+
+    fn personal_best_progression_table(&self) -> Element<'_, Message> {
+        let mut table = Table::default();
+
+        table.add_headers(vec![
+            "Track",
+            "Car Used",
+            "Personal Best",
+            "Time",
+            "Improvement",
+            "Date",
+        ]);
+
+        let time_format = self.application_state.time_format;
+
+        for track in &self.driver_profile.personal_bests.tracks {
+            for personal_best in &track.progression {
+                table.add_row(vec![
+                    track.track_name.clone(),
+                    track.car_used.clone(),
+                    personal_best.category.to_string(),
+                    format_laptime(personal_best.time, time_format),
+                    personal_best
+                        .improvement
+                        .map(|improvement| format_time_gap(improvement, time_format))
+                        .unwrap_or_else(|| "First".to_string()),
+                    personal_best.date.to_string(),
+                ]);
+            }
+        }
+
+        Table::build(
+            table,
+            Some(self.theme().palette().text),
+            Some(500.0),
+            None,
+            None,
+        )
+    }
+
+    fn consistency_summary_table(&self) -> Element<'_, Message> {
+        let mut table = Table::default();
+
+        table.add_headers(vec![
+            "Track",
+            "Races",
+            "Std Dev (s)",
+            "CV",
+            "IQR (s)",
+            "Within 1%",
+            "Within 2%",
+            "Longest Run",
+        ]);
+
+        let time_format = self.application_state.time_format;
+
+        for track in &self.driver_profile.consistency_summary.tracks {
+            table.add_row(vec![
+                track.track_name.clone(),
+                track.races.to_string(),
+                format_seconds(track.standard_deviation, time_format),
+                format!("{:.1}%", track.coefficient_of_variation),
+                format_seconds(track.interquartile_range, time_format),
+                format!("{:.0}%", track.laps_within_1_percent),
+                format!("{:.0}%", track.laps_within_2_percent),
+                track.longest_consistent_run.to_string(),
+            ]);
+        }
+
+        Table::build(
+            table,
+            Some(self.theme().palette().text),
+            Some(500.0),
+            None,
+            None,
+        )
+    }
 }
@@ -0,0 +1,108 @@
+use crate::{
+    commands::messages::Message,
+    models::application::{
+        csv_import::{CsvDelimiter, DecimalSeparator},
+        karting_time::KartingTime,
+    },
+};
+use iced::widget::{Column, button, checkbox, column, pick_list, row, text, text_input};
+use iced_aw::Card;
+
+impl KartingTime {
+    pub fn csv_import_view(&self) -> Column<'_, Message> {
+        let csv_import = &self.application_state.csv_import;
+
+        let Some(file_path) = &csv_import.file_path else {
+            return column!();
+        };
+
+        let mut preview = column!().push(text("Preview:")).spacing(10).padding(10);
+        for line in &csv_import.preview {
+            preview = preview.push(text(line));
+        }
+
+        let mut saved_mappings = row!().spacing(10);
+        for saved_mapping in &csv_import.saved_mappings {
+            saved_mappings =
+                saved_mappings.push(button(text(&saved_mapping.source_name)).on_press(
+                    Message::CsvSavedMappingSelected(saved_mapping.source_name.clone()),
+                ));
+        }
+
+        let csv_import_contents = column!()
+            .push(text(format!("File: {file_path}")))
+            .spacing(10)
+            .padding(10)
+            .push(preview)
+            .push(text("Saved Mappings:"))
+            .push(saved_mappings)
+            .push(text("Source Name:"))
+            .push(
+                text_input(
+                    "Source Name: e.g Club Timing",
+                    &csv_import.mapping.source_name,
+                )
+                .on_input(Message::CsvSourceNameChanged),
+            )
+            .push(text("Delimiter:"))
+            .push(pick_list(
+                CsvDelimiter::ALL,
+                Some(csv_import.mapping.delimiter),
+                Message::CsvDelimiterSelected,
+            ))
+            .push(text("Decimal Separator:"))
+            .push(pick_list(
+                DecimalSeparator::ALL,
+                Some(csv_import.mapping.decimal_separator),
+                Message::CsvDecimalSeparatorSelected,
+            ))
+            .push(
+                checkbox(csv_import.mapping.has_header_row)
+                    .label("First row is a header")
+                    .on_toggle(Message::CsvHeaderRowToggled),
+            )
+            .push(text("Lap Number Column:"))
+            .push(
+                text_input(
+                    "(Optional) Lap Number Column",
+                    &csv_import.mapping.lap_number_column.to_string(),
+                )
+                .on_input(Message::CsvLapNumberColumnChanged),
+            )
+            .push(text("Laptime Column:"))
+            .push(
+                text_input(
+                    "Laptime Column",
+                    &csv_import.mapping.laptime_column.to_string(),
+                )
+                .on_input(Message::CsvLaptimeColumnChanged),
+            )
+            .push(text("Sector Columns:"))
+            .push(
+                text_input(
+                    "(Optional) Sector Columns: e.g 3, 4, 5",
+                    &csv_import.sector_columns_query,
+                )
+                .on_input(Message::CsvSectorColumnsChanged),
+            )
+            .push(text("Validity Column:"))
+            .push(
+                text_input(
+                    "(Optional) Validity Column",
+                    &csv_import.mapping.validity_column.to_string(),
+                )
+                .on_input(Message::CsvValidityColumnChanged),
+            )
+            .push(
+                row!()
+                    .push(button("Import").on_press(Message::CsvImportPressed))
+                    .spacing(10)
+                    .push(button("Cancel").on_press(Message::CsvImportCancelledPressed)),
+            );
+
+        column!()
+            .push(Card::new("Import CSV Laptimes", csv_import_contents))
+            .spacing(10)
+            .padding(10)
+    }
+}
@@ -1,4 +1,4 @@
-use iced::widget::{Column, column, text, text_input};
+use iced::widget::{Column, button, column, row, text, text_input};
 use iced_aw::Card;
 
 use crate::{commands::messages::Message, models::application::karting_time::KartingTime};
@@ -18,5 +18,87 @@ impl KartingTime {
             .push(Card::new("Edit Driver Profile", edit_profile_contents))
             .spacing(10)
             .padding(10)
+            .push(Card::new(
+                "Statistics Windows",
+                self.statistics_windows_view(),
+            ))
+    }
+
+    fn statistics_windows_view(&self) -> Column<'_, Message> {
+        let statistics_settings = &self.driver_profile.statistics_settings;
+
+        let mut statistics_windows_contents = column!()
+            .spacing(10)
+            .padding(10)
+            .push(text("Laps Per Bucket:"))
+            .push(
+                text_input(
+                    "Laps Per Bucket",
+                    &statistics_settings
+                        .statistics_windows
+                        .bucket_size
+                        .to_string(),
+                )
+                .on_input(Message::BucketSizeChanged),
+            )
+            .push(text("Average Lap Cutoff (%):"))
+            .push(
+                text_input(
+                    "Average Lap Cutoff (%)",
+                    &statistics_settings
+                        .statistics_windows
+                        .average_cutoff_percentage
+                        .to_string(),
+                )
+                .on_input(Message::AverageCutoffChanged),
+            )
+            .push(text("Session Types:"));
+
+        for (index, session_type_windows) in
+            statistics_settings.session_type_windows.iter().enumerate()
+        {
+            statistics_windows_contents = statistics_windows_contents.push(
+                row!()
+                    .spacing(10)
+                    .push(
+                        text_input("Session Type", &session_type_windows.session_type).on_input(
+                            move |session_type| {
+                                Message::SessionTypeWindowsSessionTypeChanged(index, session_type)
+                            },
+                        ),
+                    )
+                    .push(
+                        text_input(
+                            "Laps Per Bucket",
+                            &session_type_windows
+                                .statistics_windows
+                                .bucket_size
+                                .to_string(),
+                        )
+                        .on_input(move |bucket_size| {
+                            Message::SessionTypeWindowsBucketSizeChanged(index, bucket_size)
+                        }),
+                    )
+                    .push(
+                        text_input(
+                            "Average Lap Cutoff (%)",
+                            &session_type_windows
+                                .statistics_windows
+                                .average_cutoff_percentage
+                                .to_string(),
+                        )
+                        .on_input(move |average_cutoff_percentage| {
+                            Message::SessionTypeWindowsAverageCutoffChanged(
+                                index,
+                                average_cutoff_percentage,
+                            )
+                        }),
+                    )
+                    .push(button("Remove").on_press(Message::SessionTypeWindowsRemoved(index))),
+            );
+        }
+
+        statistics_windows_contents
+            .push(button("Add Session Type").on_press(Message::SessionTypeWindowsAdded))
     }
 }
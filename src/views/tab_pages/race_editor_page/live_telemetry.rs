@@ -0,0 +1,73 @@
+use crate::{
+    commands::messages::Message,
+    controllers::converters::time_parser::format_laptime,
+    models::application::{karting_time::KartingTime, telemetry::TelemetryGame},
+};
+use iced::widget::{Column, button, checkbox, column, pick_list, row, text, text_input};
+use iced_aw::Card;
+
+impl KartingTime {
+    pub fn live_telemetry_view(&self) -> Column<'_, Message> {
+        let telemetry = &self.application_state.telemetry;
+
+        let listen_button = match telemetry.is_listening {
+            true => button("Stop Listening"),
+            false => button("Start Listening"),
+        }
+        .on_press(Message::TelemetryListenToggled);
+
+        let status = match &telemetry.recorder.live_race {
+            Some(live_race) => format!(
+                "Recording: {} - {} laps - Best lap {}",
+                live_race.race_information.track_name,
+                live_race.laptimes.len(),
+                format_laptime(
+                    live_race.race_statistics.fastest_lap,
+                    self.application_state.time_format
+                )
+            ),
+            None if telemetry.is_listening => "Waiting for a session to start".to_string(),
+            None => "Not listening".to_string(),
+        };
+
+        let mut live_telemetry_contents = column!()
+            .push(text("Game:"))
+            .spacing(10)
+            .padding(10)
+            .push(pick_list(
+                TelemetryGame::ALL,
+                Some(telemetry.game),
+                Message::TelemetryGameSelected,
+            ))
+            .push(text("UDP Port:"))
+            .push(
+                text_input("UDP Port", &telemetry.port.to_string())
+                    .on_input(Message::TelemetryPortChanged),
+            )
+            .push(
+                checkbox(telemetry.is_recording_packets)
+                    .label("Record packets for replay")
+                    .on_toggle(Message::TelemetryRecordPacketsToggled),
+            )
+            .push(text(status));
+
+        let mut buttons = row!().push(listen_button).spacing(10);
+
+        if !telemetry.recorded_packets.is_empty() {
+            buttons = buttons.push(
+                button(text(format!(
+                    "Save Replay ({} packets)",
+                    telemetry.recorded_packets.len()
+                )))
+                .on_press(Message::SaveTelemetryReplayRequested),
+            );
+        }
+
+        live_telemetry_contents = live_telemetry_contents.push(buttons);
+
+        column!()
+            .push(Card::new("Live Telemetry", live_telemetry_contents))
+            .spacing(10)
+            .padding(10)
+    }
+}
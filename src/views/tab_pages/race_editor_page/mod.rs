@@ -1,2 +1,6 @@
+pub mod csv_import;
 pub mod edit_driver_profile;
+pub mod live_telemetry;
+pub mod profile_merge;
 pub mod upsert_race;
+pub mod watched_folders;
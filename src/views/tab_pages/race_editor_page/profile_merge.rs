@@ -0,0 +1,91 @@
+use crate::{
+    commands::messages::Message,
+    controllers::converters::time_parser::format_laptime,
+    models::{
+        application::{
+            karting_time::KartingTime, profile_merge::MergeChoice, time_format::TimeFormat,
+        },
+        driver::session_information::race_result::RaceResult,
+    },
+};
+use iced::widget::{Column, button, column, pick_list, row, text};
+use iced_aw::Card;
+
+impl KartingTime {
+    pub fn profile_merge_view(&self) -> Column<'_, Message> {
+        let profile_merge = &self.application_state.profile_merge;
+        let time_format = self.application_state.time_format;
+
+        let Some(file_path) = &profile_merge.file_path else {
+            return column!();
+        };
+
+        let mut profile_merge_contents = column!()
+            .push(text(format!(
+                "File: {file_path} ({})",
+                profile_merge.driver_name
+            )))
+            .spacing(10)
+            .padding(10);
+
+        if profile_merge.race_merges.is_empty() {
+            profile_merge_contents =
+                profile_merge_contents.push(text("Every race already matches this profile"));
+        }
+
+        for race_merge in &profile_merge.race_merges {
+            let identifier = race_merge
+                .incoming
+                .race_information
+                .unique_race_identifier
+                .clone();
+
+            let mut race_merge_contents = column!()
+                .push(text(format!("{}: {}", race_merge.status, identifier)))
+                .spacing(5);
+
+            if let Some(current) = &race_merge.current {
+                race_merge_contents = race_merge_contents.push(text(format!(
+                    "Current: {}",
+                    race_summary(current, time_format)
+                )));
+            }
+
+            race_merge_contents = race_merge_contents
+                .push(text(format!(
+                    "Incoming: {}",
+                    race_summary(&race_merge.incoming, time_format)
+                )))
+                .push(pick_list(
+                    MergeChoice::ALL,
+                    Some(race_merge.choice),
+                    move |choice| Message::MergeChoiceSelected(identifier.clone(), choice),
+                ));
+
+            profile_merge_contents = profile_merge_contents.push(race_merge_contents);
+        }
+
+        profile_merge_contents = profile_merge_contents.push(
+            row!()
+                .push(button("Merge").on_press(Message::MergeProfilePressed))
+                .spacing(10)
+                .push(button("Cancel").on_press(Message::MergeProfileCancelledPressed)),
+        );
+
+        column!()
+            .push(Card::new("Merge Driver Profile", profile_merge_contents))
+            .spacing(10)
+            .padding(10)
+    }
+}
+
+fn race_summary(race: &RaceResult, time_format: TimeFormat) -> String {
+    format!(
+        "{} laps, fastest {}, {}, {}, {}",
+        race.laptimes.len(),
+        format_laptime(race.race_statistics.fastest_lap, time_format),
+        race.race_metadata.session_type,
+        race.race_metadata.car_used,
+        race.race_metadata.notes
+    )
+}
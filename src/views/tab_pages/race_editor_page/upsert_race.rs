@@ -1,5 +1,8 @@
-use crate::{commands::messages::Message, models::application::karting_time::KartingTime};
-use iced::widget::{Column, button, column, text, text_editor, text_input};
+use crate::{
+    commands::messages::Message,
+    models::application::{karting_time::KartingTime, race_editor::LapEntryMode},
+};
+use iced::widget::{Column, button, column, pick_list, text, text_editor, text_input};
 use iced_aw::Card;
 
 impl KartingTime {
@@ -171,6 +174,14 @@ impl KartingTime {
                 )
                 .on_input(Message::NotesChanged),
             )
+            .push(text("Lap Entry Mode:"))
+            .spacing(10)
+            .padding(10)
+            .push(pick_list(
+                LapEntryMode::ALL,
+                Some(self.application_state.race_editor.lap_entry_mode),
+                Message::LapEntryModeSelected,
+            ))
             .push(text("Laps:"))
             .spacing(10)
             .padding(10)
@@ -180,7 +191,8 @@ impl KartingTime {
                     .on_action(Message::LaptimeEditor),
             )
             .spacing(10)
-            .padding(10);
+            .padding(10)
+            .push(self.timing_warnings_view());
 
         let body = column!()
             .push(add_race_contents)
@@ -192,6 +204,16 @@ impl KartingTime {
             .padding(10)
     }
 
+    fn timing_warnings_view(&self) -> Column<'_, Message> {
+        let mut timing_warnings = column!().spacing(10);
+
+        for timing_warning in &self.application_state.race_editor.timing_warnings {
+            timing_warnings = timing_warnings.push(text(timing_warning));
+        }
+
+        timing_warnings
+    }
+
     fn determine_add_race_button(&self) -> Column<'_, Message> {
         let add_race_button = column!();
 
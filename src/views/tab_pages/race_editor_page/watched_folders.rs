@@ -0,0 +1,39 @@
+use crate::{commands::messages::Message, models::application::karting_time::KartingTime};
+use iced::widget::{Column, button, column, row, text};
+use iced_aw::Card;
+
+impl KartingTime {
+    pub fn watched_folders_view(&self) -> Column<'_, Message> {
+        let watch_folders = &self.application_state.watch_folders;
+
+        let mut watched_folders_contents = column!().spacing(10).padding(10);
+
+        for folder in &watch_folders.folders {
+            watched_folders_contents = watched_folders_contents.push(
+                row!().push(text(folder)).spacing(10).push(
+                    button("Remove")
+                        .on_press(Message::RemoveWatchedFolderPressed(folder.to_string())),
+                ),
+            );
+        }
+
+        watched_folders_contents = watched_folders_contents
+            .push(button("Add Watched Folder").on_press(Message::AddWatchedFolderRequested));
+
+        if !watch_folders.announcements.is_empty() {
+            watched_folders_contents = watched_folders_contents.push(text("Recently Imported:"));
+
+            for announcement in &watch_folders.announcements {
+                watched_folders_contents = watched_folders_contents.push(text(announcement));
+            }
+
+            watched_folders_contents = watched_folders_contents
+                .push(button("Clear").on_press(Message::ClearWatchedFolderAnnouncementsPressed));
+        }
+
+        column!()
+            .push(Card::new("Watched Folders", watched_folders_contents))
+            .spacing(10)
+            .padding(10)
+    }
+}
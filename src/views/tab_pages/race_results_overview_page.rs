@@ -1,5 +1,6 @@
 use crate::{
     commands::messages::Message,
+    controllers::converters::time_parser::format_laptime,
     models::{
         application::karting_time::KartingTime,
         driver::session_information::race_result::RaceResult,
@@ -25,7 +26,7 @@ impl KartingTime {
             column = column
                 .push(Card::new(
                     text("Results Overview"),
-                    self.race_results_overview_table(&self.driver_profile.filter.filtered_races),
+                    self.race_results_overview_table(self.overview_races()),
                 ))
                 .padding(10)
                 .spacing(10);
@@ -34,41 +35,68 @@ impl KartingTime {
         }
     }
 
+    // An open database only loads the page of races being shown
+    fn overview_races(&self) -> &Vec<RaceResult> {
+        match self.application_state.database {
+            Some(_) => &self.driver_profile.filter.pagination.paginated_races,
+            None => &self.driver_profile.filter.filtered_races,
+        }
+    }
+
     fn race_results_overview_table(&self, races: &Vec<RaceResult>) -> Element<'_, Message> {
         let mut table = Table::default();
 
-        table.add_headers(vec![
-            "Track Name",
-            "Date",
-            "Session",
-            "Car Used",
-            "Race Position",
-            "Fastest Lap",
-            "Average Lap 5",
-            "Average Lap 10",
-            "Average Lap 15",
-            "Total Lap 5",
-            "Total Lap 10",
-            "Total Lap 15",
-            "Total Time",
-        ]);
+        let lap_counts = self.driver_profile.statistics_settings.summary_lap_counts();
+
+        let mut headers: Vec<String> = vec![
+            "Track Name".to_string(),
+            "Date".to_string(),
+            "Session".to_string(),
+            "Car Used".to_string(),
+            "Race Position".to_string(),
+            "Fastest Lap".to_string(),
+        ];
+        for lap_count in &lap_counts {
+            headers.push(format!("Average Lap {lap_count}"));
+        }
+        for lap_count in &lap_counts {
+            headers.push(format!("Total Lap {lap_count}"));
+        }
+        headers.push("Total Time".to_string());
+
+        table.add_headers(headers);
+
+        let time_format = self.application_state.time_format;
 
         for race in races {
-            table.add_row(vec![
-                &race.race_information.track_name,
-                &race.race_information.date.to_string(),
-                &race.race_information.session.session_id.to_string(),
-                &race.race_metadata.car_used,
-                &race.race_information.session.race_position.to_string(),
-                &race.race_statistics.fastest_lap,
-                &RaceResult::get_time_by_key(&race.race_statistics.average_times_table, 5),
-                &RaceResult::get_time_by_key(&race.race_statistics.average_times_table, 10),
-                &RaceResult::get_time_by_key(&race.race_statistics.average_times_table, 15),
-                &RaceResult::get_time_by_key(&race.race_statistics.total_times_table, 15),
-                &RaceResult::get_time_by_key(&race.race_statistics.total_times_table, 10),
-                &RaceResult::get_time_by_key(&race.race_statistics.total_times_table, 15),
-                &RaceResult::get_last_time(&race.race_statistics.total_times_table),
-            ]);
+            let average_times = &race.race_statistics.average_times_table;
+            let total_times = &race.race_statistics.total_times_table;
+
+            let mut row = vec![
+                race.race_information.track_name.clone(),
+                race.race_information.date.to_string(),
+                race.race_information.session.session_id.to_string(),
+                race.race_metadata.car_used.clone(),
+                race.race_information.session.race_position.to_string(),
+                format_laptime(race.race_statistics.fastest_lap, time_format),
+            ];
+            for lap_count in &lap_counts {
+                row.push(RaceResult::get_time_by_key(
+                    average_times,
+                    *lap_count,
+                    time_format,
+                ));
+            }
+            for lap_count in &lap_counts {
+                row.push(RaceResult::get_time_by_key(
+                    total_times,
+                    *lap_count,
+                    time_format,
+                ));
+            }
+            row.push(RaceResult::get_last_time(total_times, time_format));
+
+            table.add_row(row);
         }
 
         Table::build(table, Some(self.theme().palette().text), None, None, None)
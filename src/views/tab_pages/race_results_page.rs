@@ -1,18 +1,22 @@
 use crate::{
     commands::messages::Message,
+    controllers::converters::time_parser::{format_laptime, format_seconds, format_time_gap},
     models::{
         application::karting_time::KartingTime,
-        driver::session_information::race_result::RaceResult,
+        driver::session_information::{lap_channels::LapChannels, race_result::RaceResult},
     },
+    views::tab_pages::{lap_trend_chart::LapTrendChart, track_outline::TrackOutline},
 };
 use iced::{
     Element, Renderer, Theme,
-    widget::{button, column, row, text},
+    widget::{Column, button, canvas, column, row, text},
 };
 use iced_aw::widgets::Card;
 use iced_table::Table;
 
 const TABLE_WIDTH: f32 = 500.0;
+const TRACK_OUTLINE_SIZE: f32 = 300.0;
+const LAP_TREND_CHART_HEIGHT: f32 = 200.0;
 
 impl KartingTime {
     pub fn race_results_view(&self) -> iced::widget::Column<'_, Message> {
@@ -54,14 +58,21 @@ impl KartingTime {
             Card::new(
                 text(header),
                 column!(
+                    self.personal_best_badge(race),
                     text!("Laptimes"),
                     self.race_result_table(race),
                     text!("Total Times"),
                     self.total_time_table(race),
+                    text!("Consecutive Times"),
+                    self.consecutive_time_table(race),
                     text!("Average Times"),
                     self.average_time_table(race),
                     text!("Race Summary"),
                     self.race_summary_table(race),
+                    text!("Consistency"),
+                    self.consistency_table(race),
+                    self.pace_trend_view(race),
+                    self.gps_view(race),
                 ),
             )
             .foot(create_footer(race))
@@ -69,29 +80,95 @@ impl KartingTime {
             Card::new(
                 text(header),
                 column!(
+                    self.personal_best_badge(race),
                     text!("Laptimes"),
                     self.race_result_table(race),
                     text!("Total Times"),
                     self.total_time_table(race),
+                    text!("Consecutive Times"),
+                    self.consecutive_time_table(race),
                     text!("Average Times"),
                     self.average_time_table(race),
                     text!("Race Summary"),
                     self.race_summary_table(race),
+                    text!("Consistency"),
+                    self.consistency_table(race),
+                    self.pace_trend_view(race),
                     text!("Metadata"),
                     self.metadata_table(race),
+                    self.gps_view(race),
                 ),
             )
             .foot(create_footer(race))
         }
     }
 
+    fn personal_best_badge(&self, race: &RaceResult) -> Column<'_, Message> {
+        match self
+            .driver_profile
+            .personal_bests
+            .badge(&race.race_information.unique_race_identifier)
+        {
+            Some(badge) => column!().push(text(badge).style(text::success)),
+            None => column!(),
+        }
+    }
+
     fn race_result_table(&self, race: &RaceResult) -> Element<'_, Message> {
         let mut table = Table::default();
 
-        table.add_headers(vec!["Lap", "Time (s)"]);
+        let number_of_sectors = race
+            .laptimes
+            .iter()
+            .map(|lap| lap.sectors.len())
+            .max()
+            .unwrap_or_default();
+
+        let mut headers = vec!["Lap".to_string(), "Time".to_string()];
+        for sector_number in 1..=number_of_sectors {
+            headers.push(format!("S{sector_number}"));
+        }
+
+        let channel_columns = lap_channel_columns(race);
+        for (channel_name, _) in &channel_columns {
+            headers.push(channel_name.to_string());
+        }
+
+        table.add_headers(headers);
+
+        let time_format = self.application_state.time_format;
+
+        for (lap_number, time) in (1..).zip(race.race_statistics.laptimes.iter()) {
+            let mut row = vec![lap_number.to_string(), format_laptime(*time, time_format)];
+
+            if number_of_sectors > 0 {
+                let sectors = race
+                    .laptimes
+                    .get(lap_number - 1)
+                    .map(|lap| lap.sectors.clone())
+                    .unwrap_or_default();
+
+                for sector_index in 0..number_of_sectors {
+                    row.push(
+                        sectors
+                            .get(sector_index)
+                            .map(|sector| format_laptime(*sector, time_format))
+                            .unwrap_or_default(),
+                    );
+                }
+            }
+
+            if let Some(lap) = race.laptimes.get(lap_number - 1) {
+                for (_, channel) in &channel_columns {
+                    row.push(
+                        channel(&lap.channels)
+                            .map(|value| value.to_string())
+                            .unwrap_or_default(),
+                    );
+                }
+            }
 
-        for (lap_number, time) in (1..).zip(race.race_statistics.formatted_laps.iter()) {
-            table.add_row(vec![&lap_number.to_string(), &time.to_string()]);
+            table.add_row(row);
         }
 
         Table::build(
@@ -103,11 +180,65 @@ impl KartingTime {
         )
     }
 
+    fn gps_view(&self, race: &RaceResult) -> Column<'_, Message> {
+        let Some(gps_statistics) = race.calculate_gps_statistics() else {
+            return column!();
+        };
+
+        let mut table = Table::default();
+
+        table.add_headers(vec!["GPS", "Value"]);
+
+        table.add_rows(vec![
+            vec![
+                "Distance (km)".to_string(),
+                format!("{:.2}", gps_statistics.distance / 1000.0),
+            ],
+            vec![
+                "Lap Distance (m)".to_string(),
+                format!("{:.0}", gps_statistics.lap_distance),
+            ],
+            vec![
+                "Max Speed (km/h)".to_string(),
+                format!("{:.1}", gps_statistics.max_speed),
+            ],
+            vec![
+                "Average Speed (km/h)".to_string(),
+                format!("{:.1}", gps_statistics.average_speed),
+            ],
+        ]);
+
+        column!()
+            .push(text!("GPS"))
+            .spacing(10)
+            .push(Table::build(
+                table,
+                Some(self.theme().palette().text),
+                Some(TABLE_WIDTH),
+                None,
+                None,
+            ))
+            .push(
+                canvas(TrackOutline {
+                    points: race.calculate_track_outline(),
+                    color: self.theme().palette().text,
+                })
+                .width(TRACK_OUTLINE_SIZE)
+                .height(TRACK_OUTLINE_SIZE),
+            )
+    }
+
     fn race_summary_table(&self, race: &RaceResult) -> Element<'_, Message> {
         let mut table = Table::default();
 
         table.add_headers(vec!["Race Summary", "Race Statistic"]);
 
+        let time_format = self.application_state.time_format;
+        let average_lap_label = format!(
+            "Average Lap ({}%)",
+            race.statistics_windows.cutoff_percentage()
+        );
+
         table.add_rows(vec![
             vec![
                 "Race Position",
@@ -117,8 +248,62 @@ impl KartingTime {
                 "Number of Laps",
                 &race.race_statistics.number_of_laps.to_string(),
             ],
-            vec!["Fastest Lap", &race.race_statistics.fastest_lap],
-            vec!["Average Lap", &race.race_statistics.average_105_lap],
+            vec![
+                "Fastest Lap",
+                &format_laptime(race.race_statistics.fastest_lap, time_format),
+            ],
+            vec![
+                average_lap_label.as_str(),
+                &format_laptime(race.race_statistics.average_105_lap, time_format),
+            ],
+        ]);
+
+        Table::build(
+            table,
+            Some(self.theme().palette().text),
+            Some(TABLE_WIDTH),
+            None,
+            None,
+        )
+    }
+
+    fn consistency_table(&self, race: &RaceResult) -> Element<'_, Message> {
+        let mut table = Table::default();
+
+        table.add_headers(vec!["Consistency", "Race Statistic"]);
+
+        let time_format = self.application_state.time_format;
+        let consistency = &race.race_statistics.consistency;
+
+        table.add_rows(vec![
+            vec![
+                "Standard Deviation (s)".to_string(),
+                format_seconds(consistency.standard_deviation, time_format),
+            ],
+            vec![
+                "Coefficient of Variation".to_string(),
+                format!("{:.1}%", consistency.coefficient_of_variation),
+            ],
+            vec![
+                "Median Lap".to_string(),
+                format_laptime(consistency.median_lap, time_format),
+            ],
+            vec![
+                "Interquartile Range (s)".to_string(),
+                format_seconds(consistency.interquartile_range, time_format),
+            ],
+            vec![
+                "Laps Within 1%".to_string(),
+                format!("{:.0}%", consistency.laps_within_1_percent),
+            ],
+            vec![
+                "Laps Within 2%".to_string(),
+                format!("{:.0}%", consistency.laps_within_2_percent),
+            ],
+            vec![
+                "Longest Consistent Run".to_string(),
+                consistency.longest_consistent_run.to_string(),
+            ],
         ]);
 
         Table::build(
@@ -130,13 +315,114 @@ impl KartingTime {
         )
     }
 
+    fn pace_trend_view(&self, race: &RaceResult) -> Column<'_, Message> {
+        let pace_trend = &race.race_statistics.pace_trend;
+
+        if pace_trend.stints.is_empty() {
+            return column!();
+        }
+
+        let mut table = Table::default();
+
+        table.add_headers(vec!["Pace Trend", "Race Statistic"]);
+
+        let time_format = self.application_state.time_format;
+
+        table.add_rows(vec![
+            vec![
+                "Degradation (s/lap)".to_string(),
+                format_time_gap(pace_trend.degradation_per_lap, time_format),
+            ],
+            vec![
+                "First Half Average".to_string(),
+                format_laptime(pace_trend.first_half_average, time_format),
+            ],
+            vec![
+                "Second Half Average".to_string(),
+                format_laptime(pace_trend.second_half_average, time_format),
+            ],
+            vec![
+                "Second Half Fade (s)".to_string(),
+                format_time_gap(
+                    pace_trend.second_half_average - pace_trend.first_half_average,
+                    time_format,
+                ),
+            ],
+            vec![
+                "Outlier Laps".to_string(),
+                pace_trend.outlier_laps.len().to_string(),
+            ],
+        ]);
+
+        for stint in &pace_trend.stints {
+            table.add_row(vec![
+                format!("Stint Laps {}-{}", stint.first_lap, stint.last_lap),
+                format_laptime(stint.average_lap, time_format),
+            ]);
+        }
+
+        let (laps, outliers): (Vec<(f32, f32)>, Vec<(f32, f32)>) = race
+            .laptimes
+            .iter()
+            .map(|lap| (lap.lap_number as f32, lap.time))
+            .partition(|(lap_number, _)| !pace_trend.outlier_laps.contains(&(*lap_number as u32)));
+
+        column!()
+            .push(text!("Pace Trend"))
+            .spacing(10)
+            .push(Table::build(
+                table,
+                Some(self.theme().palette().text),
+                Some(TABLE_WIDTH),
+                None,
+                None,
+            ))
+            .push(
+                canvas(LapTrendChart {
+                    laps,
+                    outliers,
+                    trend: (pace_trend.degradation_per_lap, pace_trend.intercept),
+                    color: self.theme().palette().text,
+                    outlier_color: self.theme().palette().danger,
+                })
+                .width(TABLE_WIDTH)
+                .height(LAP_TREND_CHART_HEIGHT),
+            )
+    }
+
     fn total_time_table(&self, race: &RaceResult) -> Element<'_, Message> {
         let mut table = Table::default();
 
-        table.add_headers(vec!["Lap", "Time (s)"]);
+        table.add_headers(vec!["Lap", "Time"]);
 
         for (total_time_key, total_time_value) in &race.race_statistics.total_times_table {
-            table.add_row(vec![total_time_key.to_string(), total_time_value.clone()]);
+            table.add_row(vec![
+                total_time_key.to_string(),
+                format_laptime(*total_time_value, self.application_state.time_format),
+            ]);
+        }
+
+        Table::build(
+            table,
+            Some(self.theme().palette().text),
+            Some(TABLE_WIDTH),
+            None,
+            None,
+        )
+    }
+
+    fn consecutive_time_table(&self, race: &RaceResult) -> Element<'_, Message> {
+        let mut table = Table::default();
+
+        table.add_headers(vec!["Lap", "Time"]);
+
+        for (consecutive_time_key, consecutive_time_value) in
+            &race.race_statistics.consecutive_times_table
+        {
+            table.add_row(vec![
+                consecutive_time_key.to_string(),
+                format_laptime(*consecutive_time_value, self.application_state.time_format),
+            ]);
         }
 
         Table::build(
@@ -151,12 +437,12 @@ impl KartingTime {
     fn average_time_table(&self, race: &RaceResult) -> Element<'_, Message> {
         let mut table = Table::default();
 
-        table.add_headers(vec!["Lap", "Time (s)"]);
+        table.add_headers(vec!["Lap", "Time"]);
 
         for (average_time_key, average_time_value) in &race.race_statistics.average_times_table {
             table.add_row(vec![
                 average_time_key.to_string(),
-                average_time_value.clone(),
+                format_laptime(*average_time_value, self.application_state.time_format),
             ]);
         }
 
@@ -189,6 +475,9 @@ impl KartingTime {
         if !race.race_metadata.championship.is_empty() {
             table.add_row(vec!["Championship", &race.race_metadata.championship]);
         }
+        if !race.race_metadata.session_time.is_empty() {
+            table.add_row(vec!["Session Time", &race.race_metadata.session_time]);
+        }
 
         Table::build(
             table,
@@ -205,9 +494,30 @@ impl KartingTime {
             && race.race_metadata.car_used.is_empty()
             && race.race_metadata.session_type.is_empty()
             && race.race_metadata.track_conditions.is_empty()
+            && race.race_metadata.session_time.is_empty()
     }
 }
 
+type LapChannel = fn(&LapChannels) -> Option<f32>;
+
+fn lap_channel_columns(race: &RaceResult) -> Vec<(&'static str, LapChannel)> {
+    let channel_columns: [(&'static str, LapChannel); 4] = [
+        ("Max RPM", |channels| channels.max_rpm),
+        ("Max Speed", |channels| channels.max_speed),
+        ("Water Temp", |channels| channels.water_temperature),
+        ("EGT", |channels| channels.exhaust_gas_temperature),
+    ];
+
+    channel_columns
+        .into_iter()
+        .filter(|(_, channel)| {
+            race.laptimes
+                .iter()
+                .any(|lap| channel(&lap.channels).is_some())
+        })
+        .collect()
+}
+
 fn create_footer(race: &RaceResult) -> iced::widget::Column<'_, Message> {
     let notes = if !race.race_metadata.notes.is_empty() {
         format!("Notes: {}", race.race_metadata.notes)
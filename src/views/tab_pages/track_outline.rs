@@ -0,0 +1,52 @@
+use iced::{
+    Color, Point, Rectangle, Renderer, Theme, mouse,
+    widget::canvas::{self, Frame, Geometry, Path, Stroke},
+};
+
+const PADDING: f32 = 10.0;
+
+pub struct TrackOutline {
+    pub points: Vec<(f32, f32)>,
+    pub color: Color,
+}
+
+impl<Message> canvas::Program<Message> for TrackOutline {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        let scale = (bounds.width.min(bounds.height) - PADDING * 2.0).max(0.0);
+        let to_point = |(x, y): (f32, f32)| Point::new(PADDING + x * scale, PADDING + y * scale);
+
+        let track = Path::new(|builder| {
+            let mut points = self.points.iter().copied().map(to_point);
+
+            if let Some(start) = points.next() {
+                builder.move_to(start);
+            }
+
+            for point in points {
+                builder.line_to(point);
+            }
+        });
+
+        frame.stroke(
+            &track,
+            Stroke::default().with_color(self.color).with_width(2.0),
+        );
+
+        if let Some(start_finish) = self.points.first().copied().map(to_point) {
+            frame.fill(&Path::circle(start_finish, 4.0), self.color);
+        }
+
+        vec![frame.into_geometry()]
+    }
+}